@@ -216,6 +216,9 @@ fn parser_for_type(name: &Option<String>, ty: &Type) -> Option<proc_macro2::Toke
         Type::Path(tp) if tp.path.is_ident("usize") => {
             Some(quote!(crate::textual::attributes_member(#name, crate::textual::parse_int)))
         }
+        Type::Path(tp) if tp.path.is_ident("f64") => Some(quote!(
+            crate::textual::attributes_member(#name, nom::number::complete::double)
+        )),
         Type::Path(tp) if tp.path.is_ident("SliceInfo") => Some(
             quote!(crate::textual::attributes_member(#name, crate::textual::slice_info_literal)),
         ),
@@ -0,0 +1,46 @@
+use std::convert::TryInto;
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use moose::{computation::Computation, execution::SymbolicExecutor};
+
+/// Computation with many independent replicated multiplications
+fn wide_computation(width: usize) -> Computation {
+    let mut source = String::new();
+    for i in 0..width {
+        source.push_str(&format!(
+            r#"x{i} = Constant{{value = HostFloat64Tensor([[1.0, 2.0], [3.0, 4.0]])}}: () -> Tensor<Float64> () @Host(alice)
+            x{i}_fixed = Cast: (Tensor<Float64>) -> Tensor<Fixed128(24, 40)> (x{i}) @Host(alice)
+            y{i} = Mul: (Tensor<Fixed128(24, 40)>, Tensor<Fixed128(24, 40)>) -> Tensor<Fixed128(24, 40)> (x{i}_fixed, x{i}_fixed) @Replicated(alice, bob, carole)
+            z{i} = Cast: (Tensor<Fixed128(24, 40)>) -> Tensor<Float64> (y{i}) @Host(bob)
+            output{i} = Output: (Tensor<Float64>) -> Tensor<Float64> (z{i}) @Host(bob)
+            "#,
+            i = i
+        ));
+    }
+    source.as_str().try_into().unwrap()
+}
+
+fn lowering_wide_computation(c: &mut Criterion) {
+    let computation = wide_computation(200);
+
+    c.bench_function("lowering/sequential", |b| {
+        b.iter(|| {
+            let lowered = SymbolicExecutor::default()
+                .run_computation(&computation)
+                .unwrap();
+            black_box(lowered);
+        })
+    });
+
+    c.bench_function("lowering/parallel", |b| {
+        b.iter(|| {
+            let lowered = SymbolicExecutor::default()
+                .run_computation_parallel(&computation)
+                .unwrap();
+            black_box(lowered);
+        })
+    });
+}
+
+criterion_group!(lowering, lowering_wide_computation);
+criterion_main!(lowering);
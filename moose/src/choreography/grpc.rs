@@ -7,7 +7,8 @@ pub(crate) mod gen {
 use self::gen::choreography_server::{Choreography, ChoreographyServer};
 use self::gen::{
     AbortComputationRequest, AbortComputationResponse, LaunchComputationRequest,
-    LaunchComputationResponse, RetrieveResultsRequest, RetrieveResultsResponse,
+    LaunchComputationResponse, RetrieveResultsChunk, RetrieveResultsRequest,
+    RetrieveResultsResponse,
 };
 use super::{NetworkingStrategy, StorageStrategy};
 use crate::computation::{SessionId, Value};
@@ -19,6 +20,7 @@ use dashmap::mapref::entry::Entry;
 use dashmap::DashMap;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::pin::Pin;
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 
@@ -28,7 +30,31 @@ pub struct ComputationOutputs {
     pub elapsed_time: Option<Duration>,
 }
 
-type ResultStores = DashMap<SessionId, Arc<AsyncCell<ComputationOutputs>>>;
+/// Final state of a launched session
+#[derive(Clone, Debug)]
+enum SessionResult {
+    Completed(ComputationOutputs),
+    TimedOut(Duration),
+}
+
+/// Maximum number of bytes sent per chunk when streaming results
+const RESULTS_CHUNK_SIZE: usize = 1 << 20;
+
+/// Results of a launched session together with the time at which they became available
+struct ResultStore {
+    cell: Arc<AsyncCell<SessionResult>>,
+    completed_at: Option<Instant>,
+}
+
+type ResultStores = DashMap<SessionId, ResultStore>;
+
+/// Remove the results of sessions that completed more than `ttl` ago
+fn evict_expired(result_stores: &ResultStores, ttl: Duration) {
+    result_stores.retain(|_, store| match store.completed_at {
+        Some(completed_at) => completed_at.elapsed() < ttl,
+        None => true,
+    });
+}
 
 pub struct GrpcChoreography {
     own_identity: Identity,
@@ -36,6 +62,8 @@ pub struct GrpcChoreography {
     result_stores: Arc<ResultStores>,
     networking_strategy: NetworkingStrategy,
     storage_strategy: StorageStrategy,
+    launch_timeout: Option<Duration>,
+    result_ttl: Option<Duration>,
 }
 
 impl GrpcChoreography {
@@ -51,10 +79,41 @@ impl GrpcChoreography {
             result_stores: Arc::new(ResultStores::default()),
             networking_strategy,
             storage_strategy,
+            launch_timeout: None,
+            result_ttl: None,
         }
     }
 
+    /// Abort sessions whose outputs are not all available within `timeout` of being launched
+    pub fn with_launch_timeout(mut self, timeout: Duration) -> GrpcChoreography {
+        self.launch_timeout = Some(timeout);
+        self
+    }
+
+    /// Discard the results of sessions once they have been available for longer than `ttl`
+    ///
+    /// Expired results are removed by a background task started in `into_server`, which must
+    /// hence be called from within a Tokio runtime.
+    pub fn with_result_ttl(mut self, ttl: Duration) -> GrpcChoreography {
+        self.result_ttl = Some(ttl);
+        self
+    }
+
     pub fn into_server(self) -> ChoreographyServer<impl Choreography> {
+        if let Some(ttl) = self.result_ttl {
+            // The sweeper stops once the server, and with it the result stores, is dropped
+            let result_stores = Arc::downgrade(&self.result_stores);
+            tokio::spawn(async move {
+                let mut interval = tokio::time::interval(ttl.max(Duration::from_millis(1)));
+                loop {
+                    interval.tick().await;
+                    match result_stores.upgrade() {
+                        Some(result_stores) => evict_expired(&result_stores, ttl),
+                        None => break,
+                    }
+                }
+            });
+        }
         ChoreographyServer::new(self)
     }
 }
@@ -90,6 +149,41 @@ impl GrpcChoreography {
             }
         }
     }
+
+    /// Wait for the results of the session named in `request` to become available
+    async fn wait_for_results(
+        &self,
+        request: tonic::Request<RetrieveResultsRequest>,
+    ) -> Result<ComputationOutputs, tonic::Status> {
+        self.check_choreographer(&request)?;
+        let request = request.into_inner();
+
+        let session_id = bincode::deserialize::<SessionId>(&request.session_id).map_err(|_e| {
+            tonic::Status::new(
+                tonic::Code::Aborted,
+                "failed to parse session id".to_string(),
+            )
+        })?;
+
+        // Release the entry before waiting, since completion needs to update it
+        let result_cell = self
+            .result_stores
+            .get(&session_id)
+            .map(|store| Arc::clone(&store.cell));
+        match result_cell {
+            Some(result_cell) => match result_cell.get().await {
+                SessionResult::Completed(results) => Ok(results),
+                SessionResult::TimedOut(timeout) => Err(tonic::Status::new(
+                    tonic::Code::DeadlineExceeded,
+                    format!("computation timed out after {:?}", timeout),
+                )),
+            },
+            None => Err(tonic::Status::new(
+                tonic::Code::NotFound,
+                "unknown session id".to_string(),
+            )),
+        }
+    }
 }
 
 #[async_trait]
@@ -116,8 +210,10 @@ impl Choreography for GrpcChoreography {
                 "session id exists already or inconsistent metric and result map".to_string(),
             )),
             Entry::Vacant(result_stores_entry) => {
-                let result_cell = AsyncCell::shared();
-                result_stores_entry.insert(result_cell);
+                result_stores_entry.insert(ResultStore {
+                    cell: AsyncCell::shared(),
+                    completed_at: None,
+                });
 
                 let computation = bincode::deserialize(&request.computation).map_err(|_e| {
                     tonic::Status::new(
@@ -148,7 +244,7 @@ impl Choreography for GrpcChoreography {
 
                 let execution_start_timer = Instant::now();
 
-                let (_handle, outputs) = context
+                let (handle, outputs) = context
                     .execute_computation(
                         session_id.clone(),
                         &computation,
@@ -164,25 +260,52 @@ impl Choreography for GrpcChoreography {
                     })?;
 
                 let result_stores = Arc::clone(&self.result_stores);
+                let launch_timeout = self.launch_timeout;
 
                 tokio::spawn(async move {
-                    let mut results = HashMap::with_capacity(outputs.len());
-                    for (output_name, output_value) in outputs {
-                        let value = output_value.await.unwrap();
-                        results.insert(output_name, value);
+                    let collect_results = async {
+                        let mut results = HashMap::with_capacity(outputs.len());
+                        for (output_name, output_value) in outputs {
+                            let value = output_value.await.unwrap();
+                            results.insert(output_name, value);
+                        }
+                        results
+                    };
+                    let results = match launch_timeout {
+                        Some(timeout) => tokio::time::timeout(timeout, collect_results)
+                            .await
+                            .map_err(|_| timeout),
+                        None => Ok(collect_results.await),
+                    };
+
+                    let result_cell = Arc::clone(
+                        &result_stores
+                            .get(&session_id)
+                            .expect("session disappeared unexpectedly")
+                            .cell,
+                    );
+
+                    match results {
+                        Ok(results) => {
+                            tracing::info!("Results ready, {:?}", results.keys());
+                            let execution_stop_timer = Instant::now();
+                            let elapsed_time =
+                                execution_stop_timer.duration_since(execution_start_timer);
+                            result_cell.set(SessionResult::Completed(ComputationOutputs {
+                                outputs: results,
+                                elapsed_time: Some(elapsed_time),
+                            }));
+                        }
+                        Err(timeout) => {
+                            tracing::warn!("Session {} timed out after {:?}", session_id, timeout);
+                            handle.abort();
+                            result_cell.set(SessionResult::TimedOut(timeout));
+                        }
+                    }
+
+                    if let Some(mut store) = result_stores.get_mut(&session_id) {
+                        store.completed_at = Some(Instant::now());
                     }
-                    tracing::info!("Results ready, {:?}", results.keys());
-
-                    let result_cell = result_stores
-                        .get(&session_id)
-                        .expect("session disappeared unexpectedly");
-
-                    let execution_stop_timer = Instant::now();
-                    let elapsed_time = execution_stop_timer.duration_since(execution_start_timer);
-                    result_cell.set(ComputationOutputs {
-                        outputs: results,
-                        elapsed_time: Some(elapsed_time),
-                    });
                 });
 
                 Ok(tonic::Response::new(LaunchComputationResponse::default()))
@@ -201,27 +324,164 @@ impl Choreography for GrpcChoreography {
         &self,
         request: tonic::Request<RetrieveResultsRequest>,
     ) -> Result<tonic::Response<RetrieveResultsResponse>, tonic::Status> {
-        self.check_choreographer(&request)?;
-        let request = request.into_inner();
+        let results = self.wait_for_results(request).await?;
+        let values = bincode::serialize(&results).expect("failed to serialize results");
+        Ok(tonic::Response::new(RetrieveResultsResponse { values }))
+    }
 
-        let session_id = bincode::deserialize::<SessionId>(&request.session_id).map_err(|_e| {
-            tonic::Status::new(
-                tonic::Code::Aborted,
-                "failed to parse session id".to_string(),
-            )
-        })?;
+    type RetrieveResultsStreamStream = Pin<
+        Box<dyn futures::Stream<Item = Result<RetrieveResultsChunk, tonic::Status>> + Send + Sync>,
+    >;
 
-        match self.result_stores.get(&session_id) {
-            Some(results) => {
-                let results = results.value().get().await;
-                let values = bincode::serialize(&results).expect("failed to serialize results");
+    async fn retrieve_results_stream(
+        &self,
+        request: tonic::Request<RetrieveResultsRequest>,
+    ) -> Result<tonic::Response<Self::RetrieveResultsStreamStream>, tonic::Status> {
+        let results = self.wait_for_results(request).await?;
+        let values = bincode::serialize(&results).expect("failed to serialize results");
+        let chunks: Vec<_> = values
+            .chunks(RESULTS_CHUNK_SIZE)
+            .map(|data| {
+                Ok(RetrieveResultsChunk {
+                    data: data.to_vec(),
+                })
+            })
+            .collect();
+        Ok(tonic::Response::new(Box::pin(futures::stream::iter(
+            chunks,
+        ))))
+    }
+}
 
-                Ok(tonic::Response::new(RetrieveResultsResponse { values }))
-            }
-            None => Err(tonic::Status::new(
-                tonic::Code::NotFound,
-                "unknown session id".to_string(),
-            )),
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::computation::{Computation, Role};
+    use crate::networking::local::LocalAsyncNetworking;
+    use crate::storage::local::LocalAsyncStorage;
+    use maplit::hashmap;
+    use std::convert::{TryFrom, TryInto};
+
+    #[tokio::test]
+    async fn test_launch_timeout() -> std::result::Result<(), Box<dyn std::error::Error>> {
+        // the value to receive is never sent, so the computation blocks indefinitely
+        let source = r#"
+        x = Receive {rendezvous_key=30303030303030303030303030303030, sender="bob"} : () -> HostFloat32Tensor () @Host(alice)
+        output = Output: (HostFloat32Tensor) -> HostFloat32Tensor (x) @Host(alice)"#;
+        let computation: Computation = source.try_into()?;
+        let session_id = SessionId::try_from("timeout")?;
+        let role_assignment: HashMap<Role, Identity> = hashmap!(
+            Role::from("alice") => Identity::from("alice"),
+            Role::from("bob") => Identity::from("bob"),
+        );
+
+        let choreography = GrpcChoreography::new(
+            Identity::from("alice"),
+            None,
+            Box::new(|_| Arc::new(LocalAsyncNetworking::default())),
+            Box::new(|| Arc::new(LocalAsyncStorage::default())),
+        )
+        .with_launch_timeout(Duration::from_millis(100));
+
+        choreography
+            .launch_computation(tonic::Request::new(LaunchComputationRequest {
+                session_id: bincode::serialize(&session_id)?,
+                computation: bincode::serialize(&computation)?,
+                arguments: bincode::serialize(&HashMap::<String, Value>::new())?,
+                role_assignment: bincode::serialize(&role_assignment)?,
+            }))
+            .await?;
+
+        let status = choreography
+            .retrieve_results(tonic::Request::new(RetrieveResultsRequest {
+                session_id: bincode::serialize(&session_id)?,
+            }))
+            .await
+            .unwrap_err();
+        assert_eq!(status.code(), tonic::Code::DeadlineExceeded);
+        Ok(())
+    }
+
+    #[test]
+    fn test_evict_expired() -> std::result::Result<(), Box<dyn std::error::Error>> {
+        let completed = SessionId::try_from("completed")?;
+        let running = SessionId::try_from("running")?;
+
+        let result_stores = ResultStores::default();
+        result_stores.insert(
+            completed.clone(),
+            ResultStore {
+                cell: AsyncCell::shared(),
+                completed_at: Some(Instant::now()),
+            },
+        );
+        result_stores.insert(
+            running.clone(),
+            ResultStore {
+                cell: AsyncCell::shared(),
+                completed_at: None,
+            },
+        );
+
+        evict_expired(&result_stores, Duration::from_secs(3600));
+        assert!(result_stores.contains_key(&completed));
+
+        evict_expired(&result_stores, Duration::ZERO);
+        assert!(!result_stores.contains_key(&completed));
+        assert!(result_stores.contains_key(&running));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_retrieve_results_stream() -> std::result::Result<(), Box<dyn std::error::Error>> {
+        use crate::host::{FromRaw, HostPlacement};
+        use crate::types::HostFloat64Tensor;
+        use futures::StreamExt;
+
+        let session_id = SessionId::try_from("large")?;
+        let choreography = GrpcChoreography::new(
+            Identity::from("alice"),
+            None,
+            Box::new(|_| Arc::new(LocalAsyncNetworking::default())),
+            Box::new(|| Arc::new(LocalAsyncStorage::default())),
+        );
+
+        // a tensor of 8 MiB, exceeding the default maximum gRPC message size
+        let plc = HostPlacement::from("alice");
+        let tensor: HostFloat64Tensor =
+            plc.from_raw(ndarray::Array::range(0.0, (1 << 20) as f64, 1.0));
+        let results = ComputationOutputs {
+            outputs: hashmap!("output".to_string() => Value::from(tensor)),
+            elapsed_time: None,
+        };
+        let cell = AsyncCell::shared();
+        cell.set(SessionResult::Completed(results.clone()));
+        choreography.result_stores.insert(
+            session_id.clone(),
+            ResultStore {
+                cell,
+                completed_at: Some(Instant::now()),
+            },
+        );
+
+        let chunks: Vec<_> = choreography
+            .retrieve_results_stream(tonic::Request::new(RetrieveResultsRequest {
+                session_id: bincode::serialize(&session_id)?,
+            }))
+            .await?
+            .into_inner()
+            .collect()
+            .await;
+        assert!(chunks.len() > 1);
+
+        let mut values = Vec::new();
+        for chunk in chunks {
+            let chunk = chunk?;
+            assert!(chunk.data.len() <= RESULTS_CHUNK_SIZE);
+            values.extend_from_slice(&chunk.data);
         }
+        let read_back = bincode::deserialize::<ComputationOutputs>(&values)?;
+        assert_eq!(read_back, results);
+        Ok(())
     }
 }
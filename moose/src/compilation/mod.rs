@@ -8,7 +8,7 @@ mod deprecated_shape;
 mod lowering;
 mod networking;
 mod print;
-mod pruning;
+pub(crate) mod pruning;
 pub mod toposort;
 mod typing;
 mod well_formed;
@@ -2,20 +2,43 @@ use crate::computation::{Computation, Operator};
 use bitvec::prelude::*;
 use petgraph::visit::{depth_first_search, DfsEvent};
 
-/// Prunes the computation from anything not relevant for the output
-pub fn prune_graph(mut comp: Computation) -> anyhow::Result<Computation> {
-    // Need to reverse the graph, because we will be traversing it from the outputs
+/// Prunes the computation from anything not relevant for the outputs or saved values
+pub fn prune_graph(comp: Computation) -> anyhow::Result<Computation> {
+    // Saves are included since they are only used for their side effect
+    prune_from(comp, |op| {
+        matches!(op, Operator::Output(_) | Operator::Save(_))
+    })
+}
+
+/// Prunes the computation from anything not relevant for the outputs, saved values or sends
+///
+/// Used after lowering, where sends of an already networked computation must survive even if
+/// nothing on the receiving side uses the value.
+pub(crate) fn prune_unreachable(comp: Computation) -> anyhow::Result<Computation> {
+    prune_from(comp, |op| {
+        matches!(
+            op,
+            Operator::Output(_) | Operator::Save(_) | Operator::Send(_)
+        )
+    })
+}
+
+/// Keeps the operations that the roots transitively depend on, in their original order
+fn prune_from<F>(mut comp: Computation, is_root: F) -> anyhow::Result<Computation>
+where
+    F: Fn(&Operator) -> bool,
+{
+    // Need to reverse the graph, because we will be traversing it from the roots
     let mut graph = comp.as_graph();
     graph.reverse();
     // Operations to keep
     let mut keep: BitVec<u8, Lsb0> = BitVec::repeat(false, comp.operations.len());
-    // Identify all the output nodes
-    let outputs = graph
+    let roots = graph
         .node_indices()
-        .filter(|i| matches!(comp.operations[graph[*i].index].kind, Operator::Output(_)));
+        .filter(|i| is_root(&comp.operations[graph[*i].index].kind));
 
     // Perform a DFS
-    depth_first_search(&graph, outputs, |event| {
+    depth_first_search(&graph, roots, |event| {
         if let DfsEvent::Discover(visited, _) = event {
             keep.set(graph[visited].index, true);
         };
@@ -161,4 +184,40 @@ mod tests {
             .contains("z2 = Output: (HostFloat32Tensor) -> HostFloat32Tensor (add) @Host(alice)"));
         Ok(())
     }
+
+    #[test]
+    fn test_save_prune() -> std::result::Result<(), anyhow::Error> {
+        let source = r#"
+        x = Constant{value=HostFloat32Tensor([1.0, 2.0])}: () -> HostFloat32Tensor @Host(alice)
+        key = Constant{value=HostString("saved")}: () -> HostString @Host(alice)
+        shape = Constant{value=HostShape([2])}: () -> HostShape @Host(alice)
+        r = Sample{}: (HostShape) -> HostRing64Tensor (shape) @Host(alice)
+        y = Neg: (HostFloat32Tensor) -> HostFloat32Tensor (x) @Host(alice)
+        save = Save: (HostString, HostFloat32Tensor) -> HostUnit (key, y) @Host(alice)"#;
+
+        let comp = prune_graph(source.try_into()?)?;
+        // Pruning should remove `shape` and `r` but keep everything the save depends on
+        let names: Vec<_> = comp.operations.iter().map(|op| op.name.as_str()).collect();
+        assert_eq!(names, vec!["x", "key", "y", "save"]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_prune_unreachable_keeps_sends() -> std::result::Result<(), anyhow::Error> {
+        let source = r#"
+        x = Constant{value=HostFloat32Tensor([1.0, 2.0])}: () -> HostFloat32Tensor @Host(alice)
+        y = Constant{value=HostFloat32Tensor([1.0, 2.0])}: () -> HostFloat32Tensor @Host(bob)
+        shape = Constant{value=HostShape([2])}: () -> HostShape @Host(alice)
+        r = Sample{}: (HostShape) -> HostRing64Tensor (shape) @Host(alice)
+        send = Send{rendezvous_key=30303030303030303030303030303031, receiver="alice"}: (HostFloat32Tensor) -> HostUnit (y) @Host(bob)
+        recv = Receive{rendezvous_key=30303030303030303030303030303031, sender="bob"}: () -> HostFloat32Tensor () @Host(alice)
+        add = Add: (HostFloat32Tensor, HostFloat32Tensor) -> HostFloat32Tensor (x, recv) @Host(alice)
+        z = Output: (HostFloat32Tensor) -> HostFloat32Tensor (x) @Host(alice)"#;
+
+        let comp = prune_unreachable(source.try_into()?)?;
+        // Pruning should remove `shape`, `r`, `recv` and `add` but keep the send
+        let names: Vec<_> = comp.operations.iter().map(|op| op.name.as_str()).collect();
+        assert_eq!(names, vec!["x", "y", "send", "z"]);
+        Ok(())
+    }
 }
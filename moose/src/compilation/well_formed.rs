@@ -112,6 +112,88 @@ pub fn well_formed(comp: Computation) -> anyhow::Result<Computation> {
             Diag(op) => DispatchKernel::<SymbolicSession, _>::compile(op, plc).err(),
             Zeros(op) => DispatchKernel::<SymbolicSession, _>::compile(op, plc).err(),
             Relu(op) => DispatchKernel::<SymbolicSession, _>::compile(op, plc).err(),
+            CosineSimilarity(op) => DispatchKernel::<SymbolicSession, _>::compile(op, plc).err(),
+            Minimum(op) => DispatchKernel::<SymbolicSession, _>::compile(op, plc).err(),
+            Prod(op) => DispatchKernel::<SymbolicSession, _>::compile(op, plc).err(),
+            LookupTable(op) => DispatchKernel::<SymbolicSession, _>::compile(op, plc).err(),
+            CountDistinct(op) => DispatchKernel::<SymbolicSession, _>::compile(op, plc).err(),
+            Var(op) => DispatchKernel::<SymbolicSession, _>::compile(op, plc).err(),
+            Std(op) => DispatchKernel::<SymbolicSession, _>::compile(op, plc).err(),
+            Jaccard(op) => DispatchKernel::<SymbolicSession, _>::compile(op, plc).err(),
+            Clip(op) => DispatchKernel::<SymbolicSession, _>::compile(op, plc).err(),
+            PrecisionRecall(op) => DispatchKernel::<SymbolicSession, _>::compile(op, plc).err(),
+            Pow(op) => DispatchKernel::<SymbolicSession, _>::compile(op, plc).err(),
+            F1(op) => DispatchKernel::<SymbolicSession, _>::compile(op, plc).err(),
+            Ceil(op) => DispatchKernel::<SymbolicSession, _>::compile(op, plc).err(),
+            Floor(op) => DispatchKernel::<SymbolicSession, _>::compile(op, plc).err(),
+            Round(op) => DispatchKernel::<SymbolicSession, _>::compile(op, plc).err(),
+            Mcc(op) => DispatchKernel::<SymbolicSession, _>::compile(op, plc).err(),
+            Reciprocal(op) => DispatchKernel::<SymbolicSession, _>::compile(op, plc).err(),
+            RocAuc(op) => DispatchKernel::<SymbolicSession, _>::compile(op, plc).err(),
+            Cumsum(op) => DispatchKernel::<SymbolicSession, _>::compile(op, plc).err(),
+            BrierScore(op) => DispatchKernel::<SymbolicSession, _>::compile(op, plc).err(),
+            Tile(op) => DispatchKernel::<SymbolicSession, _>::compile(op, plc).err(),
+            LogLoss(op) => DispatchKernel::<SymbolicSession, _>::compile(op, plc).err(),
+            Flip(op) => DispatchKernel::<SymbolicSession, _>::compile(op, plc).err(),
+            Calibration(op) => DispatchKernel::<SymbolicSession, _>::compile(op, plc).err(),
+            Pad(op) => DispatchKernel::<SymbolicSession, _>::compile(op, plc).err(),
+            WeightedCrossEntropy(op) => {
+                DispatchKernel::<SymbolicSession, _>::compile(op, plc).err()
+            }
+            Gather(op) => DispatchKernel::<SymbolicSession, _>::compile(op, plc).err(),
+            FocalLoss(op) => DispatchKernel::<SymbolicSession, _>::compile(op, plc).err(),
+            Where(op) => DispatchKernel::<SymbolicSession, _>::compile(op, plc).err(),
+            HuberLoss(op) => DispatchKernel::<SymbolicSession, _>::compile(op, plc).err(),
+            GreaterEqual(op) => DispatchKernel::<SymbolicSession, _>::compile(op, plc).err(),
+            NotEqual(op) => DispatchKernel::<SymbolicSession, _>::compile(op, plc).err(),
+            Mse(op) => DispatchKernel::<SymbolicSession, _>::compile(op, plc).err(),
+            Mae(op) => DispatchKernel::<SymbolicSession, _>::compile(op, plc).err(),
+            Stack(op) => DispatchKernel::<SymbolicSession, _>::compile(op, plc).err(),
+            R2(op) => DispatchKernel::<SymbolicSession, _>::compile(op, plc).err(),
+            ExplainedVariance(op) => DispatchKernel::<SymbolicSession, _>::compile(op, plc).err(),
+            Not(op) => DispatchKernel::<SymbolicSession, _>::compile(op, plc).err(),
+            NormalizeProb(op) => DispatchKernel::<SymbolicSession, _>::compile(op, plc).err(),
+            Rotate(op) => DispatchKernel::<SymbolicSession, _>::compile(op, plc).err(),
+            TemperatureScale(op) => DispatchKernel::<SymbolicSession, _>::compile(op, plc).err(),
+            LabelSmoothing(op) => DispatchKernel::<SymbolicSession, _>::compile(op, plc).err(),
+            Mixup(op) => DispatchKernel::<SymbolicSession, _>::compile(op, plc).err(),
+            ShrDim(op) => DispatchKernel::<SymbolicSession, _>::compile(op, plc).err(),
+            StratifiedSample(op) => DispatchKernel::<SymbolicSession, _>::compile(op, plc).err(),
+            Bootstrap(op) => DispatchKernel::<SymbolicSession, _>::compile(op, plc).err(),
+            Popcount(op) => DispatchKernel::<SymbolicSession, _>::compile(op, plc).err(),
+            KFold(op) => DispatchKernel::<SymbolicSession, _>::compile(op, plc).err(),
+            MovingStd(op) => DispatchKernel::<SymbolicSession, _>::compile(op, plc).err(),
+            RankNorm(op) => DispatchKernel::<SymbolicSession, _>::compile(op, plc).err(),
+            PolyFeatures(op) => DispatchKernel::<SymbolicSession, _>::compile(op, plc).err(),
+            StandardScalerFit(op) => DispatchKernel::<SymbolicSession, _>::compile(op, plc).err(),
+            MinMaxScalerFit(op) => DispatchKernel::<SymbolicSession, _>::compile(op, plc).err(),
+            PcaProject(op) => DispatchKernel::<SymbolicSession, _>::compile(op, plc).err(),
+            Whiten(op) => DispatchKernel::<SymbolicSession, _>::compile(op, plc).err(),
+            CentroidDist(op) => DispatchKernel::<SymbolicSession, _>::compile(op, plc).err(),
+            AssignClusters(op) => DispatchKernel::<SymbolicSession, _>::compile(op, plc).err(),
+            Silhouette(op) => DispatchKernel::<SymbolicSession, _>::compile(op, plc).err(),
+            Knn(op) => DispatchKernel::<SymbolicSession, _>::compile(op, plc).err(),
+            KnnVote(op) => DispatchKernel::<SymbolicSession, _>::compile(op, plc).err(),
+            LinearPredict(op) => DispatchKernel::<SymbolicSession, _>::compile(op, plc).err(),
+            LogisticPredict(op) => DispatchKernel::<SymbolicSession, _>::compile(op, plc).err(),
+            TreeApply(op) => DispatchKernel::<SymbolicSession, _>::compile(op, plc).err(),
+            Square(op) => DispatchKernel::<SymbolicSession, _>::compile(op, plc).err(),
+            ForestApply(op) => DispatchKernel::<SymbolicSession, _>::compile(op, plc).err(),
+            Mod(op) => DispatchKernel::<SymbolicSession, _>::compile(op, plc).err(),
+            GbdtApply(op) => DispatchKernel::<SymbolicSession, _>::compile(op, plc).err(),
+            GaussianNb(op) => DispatchKernel::<SymbolicSession, _>::compile(op, plc).err(),
+            IsolationScore(op) => DispatchKernel::<SymbolicSession, _>::compile(op, plc).err(),
+            Mahalanobis(op) => DispatchKernel::<SymbolicSession, _>::compile(op, plc).err(),
+            Dbscan(op) => DispatchKernel::<SymbolicSession, _>::compile(op, plc).err(),
+            ClosestPair(op) => DispatchKernel::<SymbolicSession, _>::compile(op, plc).err(),
+            Tfidf(op) => DispatchKernel::<SymbolicSession, _>::compile(op, plc).err(),
+            NgramHash(op) => DispatchKernel::<SymbolicSession, _>::compile(op, plc).err(),
+            BagOfWords(op) => DispatchKernel::<SymbolicSession, _>::compile(op, plc).err(),
+            SparseToDense(op) => DispatchKernel::<SymbolicSession, _>::compile(op, plc).err(),
+            DenseToSparseIndices(op) => {
+                DispatchKernel::<SymbolicSession, _>::compile(op, plc).err()
+            }
+            DenseToSparseValues(op) => DispatchKernel::<SymbolicSession, _>::compile(op, plc).err(),
         };
         if let Some(e) = compile_error {
             return Err(e.into());
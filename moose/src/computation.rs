@@ -807,6 +807,29 @@ macro_rules! operators {
                 }
             }
 
+            /// Placement types for which the operator has at least one kernel.
+            pub fn kernel_placements(&self) -> &'static [PlacementTy] {
+                use crate::kernels::KernelPlacements;
+                match self {
+                    $(Operator::$t(_) => paste! {[<$t Op>]::KERNEL_PLACEMENTS},)+
+                }
+            }
+
+            /// Short names of all operators with at least one kernel for the given placement.
+            pub fn supported_on(plc: &Placement) -> Vec<&'static str> {
+                use crate::kernels::KernelPlacements;
+                let ty = plc.ty();
+                let mut names = Vec::new();
+                $(
+                    paste! {
+                        if [<$t Op>]::KERNEL_PLACEMENTS.contains(&ty) {
+                            names.push([<$t Op>]::SHORT_NAME);
+                        }
+                    }
+                )+
+                names
+            }
+
             pub(crate) fn get_from_textual<'a, E: 'a + nom::error::ParseError<&'a str> + nom::error::ContextError<&'a str>>(name: &'a str) -> impl FnMut(&'a str) -> std::result::Result<(&str, Operator), nom::Err<E>> {
                 use crate::textual::{FromTextual, parse_operator_error};
                 match name {
@@ -829,29 +852,85 @@ operators![
     Abs,
     Add,
     And,
+    AssignClusters,
     AtLeast2D,
+    BagOfWords,
     BitExtract,
+    Bootstrap,
+    BrierScore,
     Broadcast,
+    Calibration,
     Cast,
+    Ceil,
+    CentroidDist,
+    Clip,
+    ClosestPair,
     Concat,
     Constant,
+    CosineSimilarity,
+    CountDistinct,
+    Cumsum,
+    Dbscan,
     Decrypt,
+    DenseToSparseIndices,
+    DenseToSparseValues,
     DeriveSeed,
     Div,
     Diag,
     Dot,
     ExpandDims,
+    ExplainedVariance,
+    F1,
+    Flip,
+    Floor,
+    FocalLoss,
+    ForestApply,
+    Gather,
+    GaussianNb,
+    GbdtApply,
+    HuberLoss,
     Identity,
     IndexAxis,
     Inverse,
     Input,
+    IsolationScore,
+    Jaccard,
+    KFold,
+    Knn,
+    KnnVote,
+    LabelSmoothing,
+    LinearPredict,
     Load,
+    LogLoss,
+    LogisticPredict,
+    LookupTable,
+    Mae,
+    Mahalanobis,
+    Mcc,
+    MinMaxScalerFit,
+    Mixup,
+    Mod,
+    MovingStd,
+    Mse,
     Mul,
     Mean,
+    NgramHash,
+    NormalizeProb,
+    Not,
     Output,
     Ones,
     Or,
+    Pad,
+    PcaProject,
+    PolyFeatures,
+    Popcount,
+    Pow,
+    PrecisionRecall,
     PrfKeyGen,
+    Prod,
+    R2,
+    RankNorm,
+    Reciprocal,
     Reshape,
     Receive,
     Relu,
@@ -860,6 +939,9 @@ operators![
     RingFixedpointEncode,
     RingInject,
     RingFixedpointMean,
+    RocAuc,
+    Rotate,
+    Round,
     Sample,
     SampleSeeded,
     Send,
@@ -868,12 +950,27 @@ operators![
     Shl,
     Shr,
     Sign,
+    Silhouette,
     Slice,
+    SparseToDense,
     Sqrt,
+    Square,
     Squeeze,
+    Stack,
+    StandardScalerFit,
+    Std,
+    StratifiedSample,
     Sub,
     Sum,
+    TemperatureScale,
+    Tfidf,
+    Tile,
     Transpose,
+    TreeApply,
+    Var,
+    WeightedCrossEntropy,
+    Where,
+    Whiten,
     Xor,
     Zeros,
     // Fixed-point operators
@@ -883,7 +980,9 @@ operators![
     FixedpointEncode,
     FixedpointDecode,
     Greater,
+    GreaterEqual,
     Less,
+    NotEqual,
     Neg,
     Pow2,
     Sigmoid,
@@ -899,6 +998,7 @@ operators![
     Log2,
     Log,
     Maximum,
+    Minimum,
     Msb,
     Mux,
     RepToAdt,
@@ -906,6 +1006,7 @@ operators![
     Share,
     Softmax,
     ShlDim,
+    ShrDim,
     TruncPr,
     // Mirrored Operators
     Demirror,
@@ -1010,6 +1111,13 @@ pub struct IndexAxisOp {
     pub index: usize,
 }
 
+#[derive(
+    Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
+)]
+pub struct LookupTableOp {
+    pub sig: Signature,
+}
+
 #[derive(
     Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
 )]
@@ -1062,477 +1170,1192 @@ pub struct SqueezeOp {
 #[derive(
     Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
 )]
-pub struct TransposeOp {
+pub struct TransposeOp {
+    pub sig: Signature,
+}
+
+#[derive(
+    Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
+)]
+pub struct InverseOp {
+    pub sig: Signature,
+}
+
+// TODO(Morten) rename to LogicalAddOp?
+#[derive(
+    Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
+)]
+pub struct AddOp {
+    pub sig: Signature,
+}
+
+// TODO(Morten) rename to LogicalSubOp?
+#[derive(
+    Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
+)]
+pub struct SubOp {
+    pub sig: Signature,
+}
+
+#[derive(
+    Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
+)]
+pub struct MulOp {
+    pub sig: Signature,
+}
+
+#[derive(
+    Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
+)]
+pub struct DivOp {
+    pub sig: Signature,
+}
+
+#[derive(
+    Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
+)]
+pub struct DotOp {
+    pub sig: Signature,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, FromTextual)]
+pub struct MeanOp {
+    pub sig: Signature,
+    pub axis: Option<u32>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, FromTextual)]
+pub struct VarOp {
+    pub sig: Signature,
+    pub axis: Option<u32>,
+    pub ddof: u32,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, FromTextual)]
+pub struct StdOp {
+    pub sig: Signature,
+    pub axis: Option<u32>,
+    pub ddof: u32,
+}
+
+#[derive(
+    Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
+)]
+pub struct MovingStdOp {
+    pub sig: Signature,
+    pub axis: usize,
+    pub window: usize,
+    pub ddof: u32,
+}
+
+#[derive(
+    Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
+)]
+pub struct SigmoidOp {
+    pub sig: Signature,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, FromTextual)]
+pub struct SumOp {
+    pub sig: Signature,
+    pub axis: Option<usize>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, FromTextual)]
+pub struct ProdOp {
+    pub sig: Signature,
+    pub axis: Option<usize>,
+}
+
+#[derive(
+    Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
+)]
+pub struct CountDistinctOp {
+    pub sig: Signature,
+    pub axis: usize,
+}
+
+#[derive(
+    Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
+)]
+pub struct SignOp {
+    pub sig: Signature,
+}
+
+#[derive(
+    Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
+)]
+pub struct SqrtOp {
+    pub sig: Signature,
+}
+
+#[derive(
+    Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
+)]
+pub struct FloorOp {
+    pub sig: Signature,
+}
+
+#[derive(
+    Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
+)]
+pub struct CeilOp {
+    pub sig: Signature,
+}
+
+#[derive(
+    Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
+)]
+pub struct RoundOp {
+    pub sig: Signature,
+}
+
+#[derive(
+    Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
+)]
+pub struct ReciprocalOp {
+    pub sig: Signature,
+}
+
+#[derive(
+    Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
+)]
+
+pub struct ShapeOp {
+    pub sig: Signature,
+}
+
+#[derive(
+    Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
+)]
+pub struct DiagOp {
+    pub sig: Signature,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual)]
+pub struct BitToRingOp {
+    pub sig: Signature,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual)]
+pub struct DeriveSeedOp {
+    pub sig: Signature,
+    pub sync_key: SyncKey,
+}
+
+#[derive(
+    Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
+)]
+pub struct PrfKeyGenOp {
+    pub sig: Signature,
+}
+
+#[derive(
+    Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
+)]
+pub struct DecryptOp {
+    pub sig: Signature,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, FromTextual)]
+pub struct RingFixedpointMeanOp {
+    pub sig: Signature,
+    pub axis: Option<u32>,
+    pub scaling_base: u64,
+    pub scaling_exp: u32,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, FromTextual)]
+pub struct SampleOp {
+    pub sig: Signature,
+    pub max_value: Option<u64>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, FromTextual)]
+pub struct SampleSeededOp {
+    pub sig: Signature,
+    pub max_value: Option<u64>,
+}
+
+#[derive(
+    Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
+)]
+pub struct ShlOp {
+    pub sig: Signature,
+    pub amount: usize,
+}
+
+#[derive(
+    Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
+)]
+pub struct ShrOp {
+    pub sig: Signature,
+    pub amount: usize,
+}
+
+#[derive(
+    Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
+)]
+pub struct RotateOp {
+    pub sig: Signature,
+    pub amount: usize,
+    pub left: bool,
+}
+
+#[derive(
+    Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
+)]
+pub struct RingInjectOp {
+    pub sig: Signature,
+    pub bit_idx: usize,
+}
+
+#[derive(
+    Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
+)]
+pub struct BitExtractOp {
+    pub sig: Signature,
+    pub bit_idx: usize,
+}
+
+#[derive(
+    Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
+)]
+pub struct XorOp {
+    pub sig: Signature,
+}
+
+#[derive(
+    Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
+)]
+pub struct AndOp {
+    pub sig: Signature,
+}
+
+#[derive(
+    Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
+)]
+pub struct OrOp {
+    pub sig: Signature,
+}
+
+#[derive(
+    Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
+)]
+pub struct NotOp {
+    pub sig: Signature,
+}
+
+#[derive(
+    Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
+)]
+pub struct PopcountOp {
+    pub sig: Signature,
+}
+
+#[derive(
+    Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
+)]
+pub struct Pow2Op {
+    pub sig: Signature,
+}
+
+#[derive(
+    Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
+)]
+pub struct ExpOp {
+    pub sig: Signature,
+}
+
+#[derive(
+    Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
+)]
+pub struct EqualOp {
+    pub sig: Signature,
+}
+
+#[derive(
+    Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
+)]
+pub struct EqualZeroOp {
+    pub sig: Signature,
+}
+
+#[derive(
+    Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
+)]
+pub struct LessOp {
+    pub sig: Signature,
+}
+
+#[derive(
+    Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
+)]
+pub struct GreaterOp {
+    pub sig: Signature,
+}
+
+#[derive(
+    Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
+)]
+pub struct GreaterEqualOp {
+    pub sig: Signature,
+}
+
+#[derive(
+    Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
+)]
+pub struct NotEqualOp {
+    pub sig: Signature,
+}
+
+#[derive(
+    Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
+)]
+pub struct FixedpointEncodeOp {
+    pub sig: Signature,
+    pub fractional_precision: u32,
+    pub integral_precision: u32,
+}
+
+#[derive(
+    Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
+)]
+pub struct FixedpointDecodeOp {
+    pub sig: Signature,
+    pub fractional_precision: u32,
+}
+
+#[derive(
+    Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
+)]
+pub struct NegOp {
+    pub sig: Signature,
+}
+
+#[derive(
+    Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
+)]
+pub struct RingFixedpointEncodeOp {
+    pub sig: Signature,
+    pub scaling_base: u64,
+    pub scaling_exp: u32,
+}
+
+#[derive(
+    Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
+)]
+pub struct RingFixedpointDecodeOp {
+    pub sig: Signature,
+    pub scaling_base: u64,
+    pub scaling_exp: u32,
+}
+
+#[derive(
+    Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
+)]
+pub struct RingFixedpointArgmaxOp {
+    pub sig: Signature,
+    // axis can be optional (in which case we need to do an argmax over axis 0)
+    // TODO(Dragos) once we have shape inference we can make axis optional
+    // since we can automatically get the upmost index arg
+    pub axis: usize,
+    pub upmost_index: usize,
+}
+
+#[derive(
+    Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
+)]
+pub struct AdtToRepOp {
+    pub sig: Signature,
+}
+
+#[derive(
+    Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
+)]
+pub struct AbsOp {
+    pub sig: Signature,
+}
+
+#[derive(
+    Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
+)]
+pub struct ReluOp {
+    pub sig: Signature,
+}
+
+#[derive(
+    Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
+)]
+pub struct MsbOp {
+    pub sig: Signature,
+}
+
+#[derive(
+    Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
+)]
+pub struct ShareOp {
+    pub sig: Signature,
+}
+
+#[derive(
+    Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
+)]
+pub struct RevealOp {
+    pub sig: Signature,
+}
+
+#[derive(
+    Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
+)]
+pub struct AddNOp {
+    pub sig: Signature,
+}
+
+#[derive(
+    Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
+)]
+pub struct TruncPrOp {
+    pub sig: Signature,
+    pub amount: u32,
+}
+
+#[derive(
+    Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
+)]
+pub struct RepToAdtOp {
+    pub sig: Signature,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug, ShortName, ToTextual, FromTextual)]
+pub struct FillOp {
+    pub sig: Signature,
+    pub value: Constant,
+}
+
+impl std::cmp::Eq for FillOp {}
+
+#[allow(clippy::derive_hash_xor_eq)]
+impl Hash for FillOp {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.sig.hash(state);
+        self.value.to_textual().hash(state);
+        // TODO(Morten) we must also take `self.value` into account!
+        // self.value.hash(state);
+    }
+}
+
+#[derive(
+    Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
+)]
+pub struct ShlDimOp {
+    pub sig: Signature,
+    pub amount: usize,
+    pub bit_length: usize,
+}
+
+#[derive(
+    Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
+)]
+pub struct ShrDimOp {
+    pub sig: Signature,
+    pub amount: usize,
+    pub bit_length: usize,
+}
+
+#[derive(
+    Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
+)]
+pub struct IndexOp {
+    pub sig: Signature,
+    pub index: usize,
+}
+
+#[derive(
+    Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
+)]
+pub struct BitDecomposeOp {
+    pub sig: Signature,
+}
+
+#[derive(
+    Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
+)]
+pub struct BitComposeOp {
+    pub sig: Signature,
+}
+
+#[derive(
+    Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
+)]
+pub struct MuxOp {
+    pub sig: Signature,
+}
+
+#[derive(
+    Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
+)]
+pub struct DemirrorOp {
+    pub sig: Signature,
+}
+
+#[derive(
+    Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
+)]
+pub struct MirrorOp {
+    pub sig: Signature,
+}
+
+#[derive(
+    Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
+)]
+pub struct MaximumOp {
+    pub sig: Signature,
+}
+
+#[derive(
+    Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
+)]
+pub struct MinimumOp {
+    pub sig: Signature,
+}
+
+#[derive(
+    Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
+)]
+pub struct SoftmaxOp {
+    pub sig: Signature,
+    // axis can be optional (in which case we need to do a softmax over every entry)
+    pub axis: usize,
+    pub upmost_index: usize,
+}
+
+#[derive(
+    Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
+)]
+pub struct ArgmaxOp {
+    pub sig: Signature,
+    // axis can be optional (in which case we need to do an argmax over axis 0)
+    // TODO(Dragos) once we have shape inference we can make axis optional
+    // since we can automatically get the upmost index arg
+    pub axis: usize,
+    pub upmost_index: usize,
+}
+
+#[derive(
+    Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
+)]
+pub struct BroadcastOp {
+    pub sig: Signature,
+}
+
+#[derive(
+    Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
+)]
+pub struct LogOp {
+    pub sig: Signature,
+}
+
+#[derive(
+    Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
+)]
+pub struct Log2Op {
+    pub sig: Signature,
+}
+
+#[derive(
+    Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
+)]
+pub struct CosineSimilarityOp {
+    pub sig: Signature,
+    pub axis: usize,
+}
+
+#[derive(
+    Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
+)]
+pub struct JaccardOp {
+    pub sig: Signature,
+    pub axis: usize,
+    pub zero_if_empty: bool,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug, ShortName, ToTextual, FromTextual)]
+pub struct ClipOp {
+    pub sig: Signature,
+    pub min: f64,
+    pub max: f64,
+}
+
+impl std::cmp::Eq for ClipOp {}
+
+#[allow(clippy::derive_hash_xor_eq)]
+impl Hash for ClipOp {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.sig.hash(state);
+        self.min.to_bits().hash(state);
+        self.max.to_bits().hash(state);
+    }
+}
+
+#[derive(
+    Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
+)]
+pub struct PrecisionRecallOp {
     pub sig: Signature,
 }
 
-#[derive(
-    Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
-)]
-pub struct InverseOp {
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug, ShortName, ToTextual, FromTextual)]
+pub struct PowOp {
     pub sig: Signature,
+    pub exponent: f64,
+}
+
+impl std::cmp::Eq for PowOp {}
+
+#[allow(clippy::derive_hash_xor_eq)]
+impl Hash for PowOp {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.sig.hash(state);
+        self.exponent.to_bits().hash(state);
+    }
 }
 
-// TODO(Morten) rename to LogicalAddOp?
 #[derive(
     Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
 )]
-pub struct AddOp {
+pub struct F1Op {
     pub sig: Signature,
 }
 
-// TODO(Morten) rename to LogicalSubOp?
 #[derive(
     Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
 )]
-pub struct SubOp {
+pub struct MccOp {
     pub sig: Signature,
 }
 
 #[derive(
     Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
 )]
-pub struct MulOp {
+pub struct RocAucOp {
     pub sig: Signature,
 }
 
 #[derive(
     Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
 )]
-pub struct DivOp {
+pub struct CumsumOp {
     pub sig: Signature,
+    pub axis: usize,
 }
 
 #[derive(
     Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
 )]
-pub struct DotOp {
-    pub sig: Signature,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, FromTextual)]
-pub struct MeanOp {
+pub struct BrierScoreOp {
     pub sig: Signature,
-    pub axis: Option<u32>,
 }
 
 #[derive(
     Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
 )]
-pub struct SigmoidOp {
+pub struct TileOp {
     pub sig: Signature,
+    pub axis: usize,
+    pub reps: usize,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, FromTextual)]
-pub struct SumOp {
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug, ShortName, ToTextual, FromTextual)]
+pub struct LogLossOp {
     pub sig: Signature,
-    pub axis: Option<usize>,
+    pub eps: f64,
+}
+
+impl std::cmp::Eq for LogLossOp {}
+
+#[allow(clippy::derive_hash_xor_eq)]
+impl Hash for LogLossOp {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.sig.hash(state);
+        self.eps.to_bits().hash(state);
+    }
 }
 
 #[derive(
     Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
 )]
-pub struct SignOp {
+pub struct FlipOp {
     pub sig: Signature,
+    pub axis: usize,
 }
 
 #[derive(
     Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
 )]
-pub struct SqrtOp {
+pub struct CalibrationOp {
     pub sig: Signature,
+    pub bins: usize,
 }
 
-#[derive(
-    Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
-)]
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug, ShortName, ToTextual)]
+pub struct PadOp {
+    pub sig: Signature,
+    /// Pairs of `(before, after)` widths, one for each axis.
+    pub pad_width: Vec<usize>,
+    pub pad_value: f64,
+}
 
-pub struct ShapeOp {
+impl std::cmp::Eq for PadOp {}
+
+#[allow(clippy::derive_hash_xor_eq)]
+impl Hash for PadOp {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.sig.hash(state);
+        self.pad_width.hash(state);
+        self.pad_value.to_bits().hash(state);
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug, ShortName, ToTextual)]
+pub struct WeightedCrossEntropyOp {
     pub sig: Signature,
+    pub weights: Vec<f64>,
+}
+
+impl std::cmp::Eq for WeightedCrossEntropyOp {}
+
+#[allow(clippy::derive_hash_xor_eq)]
+impl Hash for WeightedCrossEntropyOp {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.sig.hash(state);
+        for w in &self.weights {
+            w.to_bits().hash(state);
+        }
+    }
 }
 
 #[derive(
     Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
 )]
-pub struct DiagOp {
+pub struct GatherOp {
     pub sig: Signature,
+    pub axis: usize,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual)]
-pub struct BitToRingOp {
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug, ShortName, ToTextual, FromTextual)]
+pub struct FocalLossOp {
     pub sig: Signature,
+    pub gamma: f64,
+    pub eps: f64,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual)]
-pub struct DeriveSeedOp {
-    pub sig: Signature,
-    pub sync_key: SyncKey,
+impl std::cmp::Eq for FocalLossOp {}
+
+#[allow(clippy::derive_hash_xor_eq)]
+impl Hash for FocalLossOp {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.sig.hash(state);
+        self.gamma.to_bits().hash(state);
+        self.eps.to_bits().hash(state);
+    }
 }
 
 #[derive(
     Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
 )]
-pub struct PrfKeyGenOp {
+pub struct WhereOp {
     pub sig: Signature,
 }
 
-#[derive(
-    Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
-)]
-pub struct DecryptOp {
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug, ShortName, ToTextual, FromTextual)]
+pub struct HuberLossOp {
     pub sig: Signature,
+    pub axis: usize,
+    pub delta: f64,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, FromTextual)]
-pub struct RingFixedpointMeanOp {
-    pub sig: Signature,
-    pub axis: Option<u32>,
-    pub scaling_base: u64,
-    pub scaling_exp: u32,
+impl std::cmp::Eq for HuberLossOp {}
+
+#[allow(clippy::derive_hash_xor_eq)]
+impl Hash for HuberLossOp {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.sig.hash(state);
+        self.axis.hash(state);
+        self.delta.to_bits().hash(state);
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, FromTextual)]
-pub struct SampleOp {
+pub struct MseOp {
     pub sig: Signature,
-    pub max_value: Option<u64>,
+    pub axis: Option<usize>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, FromTextual)]
-pub struct SampleSeededOp {
+pub struct MaeOp {
     pub sig: Signature,
-    pub max_value: Option<u64>,
+    pub axis: Option<usize>,
 }
 
 #[derive(
     Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
 )]
-pub struct ShlOp {
+pub struct StackOp {
     pub sig: Signature,
-    pub amount: usize,
+    pub axis: usize,
 }
 
 #[derive(
     Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
 )]
-pub struct ShrOp {
+pub struct R2Op {
     pub sig: Signature,
-    pub amount: usize,
 }
 
 #[derive(
     Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
 )]
-pub struct RingInjectOp {
+pub struct ExplainedVarianceOp {
     pub sig: Signature,
-    pub bit_idx: usize,
 }
 
 #[derive(
     Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
 )]
-pub struct BitExtractOp {
+pub struct NormalizeProbOp {
     pub sig: Signature,
-    pub bit_idx: usize,
+    pub axis: usize,
+    pub uniform_if_zero: bool,
 }
 
-#[derive(
-    Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
-)]
-pub struct XorOp {
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug, ShortName, ToTextual, FromTextual)]
+pub struct TemperatureScaleOp {
     pub sig: Signature,
+    pub axis: usize,
+    pub temperature: f64,
 }
 
-#[derive(
-    Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
-)]
-pub struct AndOp {
-    pub sig: Signature,
+impl std::cmp::Eq for TemperatureScaleOp {}
+
+#[allow(clippy::derive_hash_xor_eq)]
+impl Hash for TemperatureScaleOp {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.sig.hash(state);
+        self.axis.hash(state);
+        self.temperature.to_bits().hash(state);
+    }
 }
 
-#[derive(
-    Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
-)]
-pub struct OrOp {
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug, ShortName, ToTextual, FromTextual)]
+pub struct LabelSmoothingOp {
     pub sig: Signature,
+    pub classes: usize,
+    pub eps: f64,
 }
 
-#[derive(
-    Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
-)]
-pub struct Pow2Op {
+impl std::cmp::Eq for LabelSmoothingOp {}
+
+#[allow(clippy::derive_hash_xor_eq)]
+impl Hash for LabelSmoothingOp {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.sig.hash(state);
+        self.classes.hash(state);
+        self.eps.to_bits().hash(state);
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug, ShortName, ToTextual, FromTextual)]
+pub struct MixupOp {
     pub sig: Signature,
+    pub lambda: f64,
+}
+
+impl std::cmp::Eq for MixupOp {}
+
+#[allow(clippy::derive_hash_xor_eq)]
+impl Hash for MixupOp {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.sig.hash(state);
+        self.lambda.to_bits().hash(state);
+    }
 }
 
 #[derive(
     Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
 )]
-pub struct ExpOp {
+pub struct StratifiedSampleOp {
     pub sig: Signature,
+    pub count: usize,
 }
 
 #[derive(
     Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
 )]
-pub struct EqualOp {
+pub struct BootstrapOp {
     pub sig: Signature,
+    pub size: usize,
 }
 
 #[derive(
     Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
 )]
-pub struct EqualZeroOp {
+pub struct KFoldOp {
     pub sig: Signature,
+    pub k: usize,
+    pub fold: usize,
+    pub test: bool,
 }
 
 #[derive(
     Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
 )]
-pub struct LessOp {
+pub struct RankNormOp {
     pub sig: Signature,
+    pub axis: usize,
+    pub gaussianize: bool,
 }
 
 #[derive(
     Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
 )]
-pub struct GreaterOp {
+pub struct PolyFeaturesOp {
     pub sig: Signature,
+    pub degree: usize,
+    pub include_bias: bool,
 }
 
 #[derive(
     Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
 )]
-pub struct FixedpointEncodeOp {
+pub struct StandardScalerFitOp {
     pub sig: Signature,
-    pub fractional_precision: u32,
-    pub integral_precision: u32,
+    pub ddof: u32,
 }
 
 #[derive(
     Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
 )]
-pub struct FixedpointDecodeOp {
+pub struct MinMaxScalerFitOp {
     pub sig: Signature,
-    pub fractional_precision: u32,
 }
 
 #[derive(
     Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
 )]
-pub struct NegOp {
+pub struct PcaProjectOp {
     pub sig: Signature,
 }
 
 #[derive(
     Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
 )]
-pub struct RingFixedpointEncodeOp {
+pub struct WhitenOp {
     pub sig: Signature,
-    pub scaling_base: u64,
-    pub scaling_exp: u32,
+    pub zca: bool,
 }
 
 #[derive(
     Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
 )]
-pub struct RingFixedpointDecodeOp {
+pub struct CentroidDistOp {
     pub sig: Signature,
-    pub scaling_base: u64,
-    pub scaling_exp: u32,
 }
 
 #[derive(
     Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
 )]
-pub struct RingFixedpointArgmaxOp {
+pub struct AssignClustersOp {
     pub sig: Signature,
-    // axis can be optional (in which case we need to do an argmax over axis 0)
-    // TODO(Dragos) once we have shape inference we can make axis optional
-    // since we can automatically get the upmost index arg
-    pub axis: usize,
-    pub upmost_index: usize,
 }
 
 #[derive(
     Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
 )]
-pub struct AdtToRepOp {
+pub struct SilhouetteOp {
     pub sig: Signature,
+    pub average: bool,
 }
 
 #[derive(
     Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
 )]
-pub struct AbsOp {
+pub struct KnnOp {
     pub sig: Signature,
+    pub k: usize,
 }
 
 #[derive(
     Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
 )]
-pub struct ReluOp {
+pub struct KnnVoteOp {
     pub sig: Signature,
+    pub distance: bool,
 }
 
 #[derive(
     Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
 )]
-pub struct MsbOp {
+pub struct LinearPredictOp {
     pub sig: Signature,
 }
 
 #[derive(
     Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
 )]
-pub struct ShareOp {
+pub struct LogisticPredictOp {
     pub sig: Signature,
+    pub threshold: bool,
 }
 
 #[derive(
     Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
 )]
-pub struct RevealOp {
+pub struct TreeApplyOp {
     pub sig: Signature,
 }
 
 #[derive(
     Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
 )]
-pub struct AddNOp {
+pub struct SquareOp {
     pub sig: Signature,
 }
 
 #[derive(
     Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
 )]
-pub struct TruncPrOp {
+pub struct ForestApplyOp {
     pub sig: Signature,
-    pub amount: u32,
+    pub classify: bool,
 }
 
 #[derive(
     Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
 )]
-pub struct RepToAdtOp {
+pub struct ModOp {
     pub sig: Signature,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Clone, Debug, ShortName, ToTextual, FromTextual)]
-pub struct FillOp {
+pub struct GbdtApplyOp {
     pub sig: Signature,
-    pub value: Constant,
+    pub learning_rate: f64,
+    pub base_score: f64,
 }
 
-impl std::cmp::Eq for FillOp {}
+impl std::cmp::Eq for GbdtApplyOp {}
 
 #[allow(clippy::derive_hash_xor_eq)]
-impl Hash for FillOp {
+impl Hash for GbdtApplyOp {
     fn hash<H: Hasher>(&self, state: &mut H) {
         self.sig.hash(state);
-        self.value.to_textual().hash(state);
-        // TODO(Morten) we must also take `self.value` into account!
-        // self.value.hash(state);
+        self.learning_rate.to_bits().hash(state);
+        self.base_score.to_bits().hash(state);
     }
 }
 
-#[derive(
-    Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
-)]
-pub struct ShlDimOp {
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug, ShortName, ToTextual, FromTextual)]
+pub struct GaussianNbOp {
     pub sig: Signature,
-    pub amount: usize,
-    pub bit_length: usize,
+    pub eps: f64,
 }
 
-#[derive(
-    Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
-)]
-pub struct IndexOp {
-    pub sig: Signature,
-    pub index: usize,
+impl std::cmp::Eq for GaussianNbOp {}
+
+#[allow(clippy::derive_hash_xor_eq)]
+impl Hash for GaussianNbOp {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.sig.hash(state);
+        self.eps.to_bits().hash(state);
+    }
 }
 
 #[derive(
     Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
 )]
-pub struct BitDecomposeOp {
+pub struct IsolationScoreOp {
     pub sig: Signature,
+    pub sample_size: usize,
 }
 
 #[derive(
     Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
 )]
-pub struct BitComposeOp {
+pub struct MahalanobisOp {
     pub sig: Signature,
 }
 
-#[derive(
-    Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
-)]
-pub struct MuxOp {
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug, ShortName, ToTextual, FromTextual)]
+pub struct DbscanOp {
     pub sig: Signature,
+    pub eps: f64,
+    pub min_samples: usize,
 }
 
-#[derive(
-    Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
-)]
-pub struct DemirrorOp {
-    pub sig: Signature,
+impl std::cmp::Eq for DbscanOp {}
+
+#[allow(clippy::derive_hash_xor_eq)]
+impl Hash for DbscanOp {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.sig.hash(state);
+        self.eps.to_bits().hash(state);
+        self.min_samples.hash(state);
+    }
 }
 
 #[derive(
     Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
 )]
-pub struct MirrorOp {
+pub struct ClosestPairOp {
     pub sig: Signature,
 }
 
 #[derive(
     Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
 )]
-pub struct MaximumOp {
+pub struct TfidfOp {
     pub sig: Signature,
+    pub num_docs: usize,
+    pub smooth: bool,
 }
 
 #[derive(
     Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
 )]
-pub struct SoftmaxOp {
+pub struct NgramHashOp {
     pub sig: Signature,
-    // axis can be optional (in which case we need to do a softmax over every entry)
-    pub axis: usize,
-    pub upmost_index: usize,
+    pub n: usize,
+    pub num_buckets: usize,
 }
 
 #[derive(
     Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
 )]
-pub struct ArgmaxOp {
+pub struct BagOfWordsOp {
     pub sig: Signature,
-    // axis can be optional (in which case we need to do an argmax over axis 0)
-    // TODO(Dragos) once we have shape inference we can make axis optional
-    // since we can automatically get the upmost index arg
-    pub axis: usize,
-    pub upmost_index: usize,
+    pub vocab_size: usize,
+    pub padding: u64,
 }
 
 #[derive(
     Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
 )]
-pub struct BroadcastOp {
+pub struct SparseToDenseOp {
     pub sig: Signature,
 }
 
 #[derive(
     Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
 )]
-pub struct LogOp {
+pub struct DenseToSparseIndicesOp {
     pub sig: Signature,
 }
 
 #[derive(
     Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug, ShortName, ToTextual, FromTextual,
 )]
-pub struct Log2Op {
+pub struct DenseToSparseValuesOp {
     pub sig: Signature,
 }
 
@@ -1788,7 +2611,43 @@ pub struct OperationIndex {
     pub index: usize,
 }
 
+/// Summary of the operations in a computation
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct GraphStats {
+    /// Number of operations per operator name, e.g. `"Add"`
+    pub by_operator: HashMap<String, usize>,
+    /// Number of operations per placement type, e.g. `"Host"`
+    pub by_placement: HashMap<String, usize>,
+    /// Total number of operations
+    pub total: usize,
+}
+
+impl GraphStats {
+    pub(crate) fn from_operations(operations: &[Operation]) -> Self {
+        let mut stats = GraphStats {
+            total: operations.len(),
+            ..Default::default()
+        };
+        for op in operations.iter() {
+            *stats
+                .by_operator
+                .entry(op.kind.short_name().to_string())
+                .or_insert(0) += 1;
+            *stats
+                .by_placement
+                .entry(format!("{:?}", op.placement.ty()))
+                .or_insert(0) += 1;
+        }
+        stats
+    }
+}
+
 impl NamedComputation {
+    /// Count the operations, grouped by operator and by placement type.
+    pub fn graph_stats(&self) -> GraphStats {
+        GraphStats::from_operations(&self.operations)
+    }
+
     #[tracing::instrument(skip(bytes))]
     pub fn from_msgpack<B: AsRef<[u8]>>(bytes: B) -> Result<Self> {
         rmp_serde::from_read_ref(&bytes).map_err(|e| Error::SerializationError(e.to_string()))
@@ -2004,4 +2863,16 @@ mod tests {
         let read_back = Computation::from_textual(&source).unwrap();
         assert_eq!(original.operations, read_back.operations);
     }
+
+    #[test]
+    fn test_supported_operators() {
+        let host: Placement = HostPlacement::from("alice").into();
+        let host_ops = Operator::supported_on(&host);
+        assert!(host_ops.contains(&AddOp::SHORT_NAME));
+        assert!(host_ops.contains(&SendOp::SHORT_NAME));
+
+        let mir: Placement = Mirrored3Placement::from(["alice", "bob", "carole"]).into();
+        let mir_ops = Operator::supported_on(&mir);
+        assert!(!mir_ops.contains(&SigmoidOp::SHORT_NAME));
+    }
 }
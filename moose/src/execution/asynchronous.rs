@@ -29,6 +29,13 @@ pub struct AsyncSessionHandle {
 }
 
 impl AsyncSessionHandle {
+    /// Abort all tasks of the session that are still running
+    pub fn abort(&self) {
+        for task in self.tasks.iter() {
+            task.abort();
+        }
+    }
+
     pub async fn join_on_first_error(mut self) -> anyhow::Result<()> {
         use crate::error::Error::{OperandUnavailable, ResultUnused};
 
@@ -377,16 +384,31 @@ impl DispatchKernel<AsyncSession, Value> for Operator {
             AddN(op) => DispatchKernel::compile(op, plc),
             And(op) => DispatchKernel::compile(op, plc),
             Argmax(op) => DispatchKernel::compile(op, plc),
+            AssignClusters(op) => DispatchKernel::compile(op, plc),
             AtLeast2D(op) => DispatchKernel::compile(op, plc),
+            BagOfWords(op) => DispatchKernel::compile(op, plc),
             BitCompose(op) => DispatchKernel::compile(op, plc),
             BitDecompose(op) => DispatchKernel::compile(op, plc),
             BitExtract(op) => DispatchKernel::compile(op, plc),
+            Bootstrap(op) => DispatchKernel::compile(op, plc),
+            BrierScore(op) => DispatchKernel::compile(op, plc),
             Broadcast(op) => DispatchKernel::compile(op, plc),
+            Calibration(op) => DispatchKernel::compile(op, plc),
             Cast(op) => DispatchKernel::compile(op, plc),
+            Ceil(op) => DispatchKernel::compile(op, plc),
+            CentroidDist(op) => DispatchKernel::compile(op, plc),
+            Clip(op) => DispatchKernel::compile(op, plc),
+            ClosestPair(op) => DispatchKernel::compile(op, plc),
             Concat(op) => DispatchKernel::compile(op, plc),
             Constant(op) => DispatchKernel::compile(op, plc),
+            CosineSimilarity(op) => DispatchKernel::compile(op, plc),
+            CountDistinct(op) => DispatchKernel::compile(op, plc),
+            Cumsum(op) => DispatchKernel::compile(op, plc),
+            Dbscan(op) => DispatchKernel::compile(op, plc),
             Decrypt(op) => DispatchKernel::compile(op, plc),
             Demirror(op) => DispatchKernel::compile(op, plc),
+            DenseToSparseIndices(op) => DispatchKernel::compile(op, plc),
+            DenseToSparseValues(op) => DispatchKernel::compile(op, plc),
             DeriveSeed(op) => DispatchKernel::compile(op, plc),
             Dot(op) => DispatchKernel::compile(op, plc),
             Diag(op) => DispatchKernel::compile(op, plc),
@@ -395,29 +417,73 @@ impl DispatchKernel<AsyncSession, Value> for Operator {
             EqualZero(op) => DispatchKernel::compile(op, plc),
             Exp(op) => DispatchKernel::compile(op, plc),
             ExpandDims(op) => DispatchKernel::compile(op, plc),
+            ExplainedVariance(op) => DispatchKernel::compile(op, plc),
+            F1(op) => DispatchKernel::compile(op, plc),
             Fill(op) => DispatchKernel::compile(op, plc),
             FixedpointDecode(op) => DispatchKernel::compile(op, plc),
             FixedpointEncode(op) => DispatchKernel::compile(op, plc),
+            Flip(op) => DispatchKernel::compile(op, plc),
+            Floor(op) => DispatchKernel::compile(op, plc),
+            FocalLoss(op) => DispatchKernel::compile(op, plc),
+            ForestApply(op) => DispatchKernel::compile(op, plc),
+            Gather(op) => DispatchKernel::compile(op, plc),
+            GaussianNb(op) => DispatchKernel::compile(op, plc),
+            GbdtApply(op) => DispatchKernel::compile(op, plc),
             Greater(op) => DispatchKernel::compile(op, plc),
+            GreaterEqual(op) => DispatchKernel::compile(op, plc),
+            HuberLoss(op) => DispatchKernel::compile(op, plc),
             Identity(op) => DispatchKernel::compile(op, plc),
             Index(op) => DispatchKernel::compile(op, plc),
             IndexAxis(op) => DispatchKernel::compile(op, plc),
             Input(op) => DispatchKernel::compile(op, plc),
             Inverse(op) => DispatchKernel::compile(op, plc),
+            IsolationScore(op) => DispatchKernel::compile(op, plc),
+            Jaccard(op) => DispatchKernel::compile(op, plc),
+            KFold(op) => DispatchKernel::compile(op, plc),
+            Knn(op) => DispatchKernel::compile(op, plc),
+            KnnVote(op) => DispatchKernel::compile(op, plc),
+            LabelSmoothing(op) => DispatchKernel::compile(op, plc),
             Less(op) => DispatchKernel::compile(op, plc),
+            LinearPredict(op) => DispatchKernel::compile(op, plc),
             Log(op) => DispatchKernel::compile(op, plc),
             Log2(op) => DispatchKernel::compile(op, plc),
+            LogLoss(op) => DispatchKernel::compile(op, plc),
+            LogisticPredict(op) => DispatchKernel::compile(op, plc),
+            LookupTable(op) => DispatchKernel::compile(op, plc),
+            Mae(op) => DispatchKernel::compile(op, plc),
+            Mahalanobis(op) => DispatchKernel::compile(op, plc),
             Maximum(op) => DispatchKernel::compile(op, plc),
+            Mcc(op) => DispatchKernel::compile(op, plc),
             Mean(op) => DispatchKernel::compile(op, plc),
+            MinMaxScalerFit(op) => DispatchKernel::compile(op, plc),
+            Minimum(op) => DispatchKernel::compile(op, plc),
             Mirror(op) => DispatchKernel::compile(op, plc),
+            Mixup(op) => DispatchKernel::compile(op, plc),
+            Mod(op) => DispatchKernel::compile(op, plc),
+            MovingStd(op) => DispatchKernel::compile(op, plc),
             Msb(op) => DispatchKernel::compile(op, plc),
+            Mse(op) => DispatchKernel::compile(op, plc),
             Mul(op) => DispatchKernel::compile(op, plc),
             Mux(op) => DispatchKernel::compile(op, plc),
             Neg(op) => DispatchKernel::compile(op, plc),
+            NgramHash(op) => DispatchKernel::compile(op, plc),
+            NormalizeProb(op) => DispatchKernel::compile(op, plc),
+            Not(op) => DispatchKernel::compile(op, plc),
+            NotEqual(op) => DispatchKernel::compile(op, plc),
             Ones(op) => DispatchKernel::compile(op, plc),
             Or(op) => DispatchKernel::compile(op, plc),
+            Pad(op) => DispatchKernel::compile(op, plc),
+            PcaProject(op) => DispatchKernel::compile(op, plc),
+            PolyFeatures(op) => DispatchKernel::compile(op, plc),
+            Popcount(op) => DispatchKernel::compile(op, plc),
+            Pow(op) => DispatchKernel::compile(op, plc),
             Pow2(op) => DispatchKernel::compile(op, plc),
+            PrecisionRecall(op) => DispatchKernel::compile(op, plc),
             PrfKeyGen(op) => DispatchKernel::compile(op, plc),
+            Prod(op) => DispatchKernel::compile(op, plc),
+            R2(op) => DispatchKernel::compile(op, plc),
+            RankNorm(op) => DispatchKernel::compile(op, plc),
+            Reciprocal(op) => DispatchKernel::compile(op, plc),
             Relu(op) => DispatchKernel::compile(op, plc),
             Reshape(op) => DispatchKernel::compile(op, plc),
             Reveal(op) => DispatchKernel::compile(op, plc),
@@ -427,6 +493,9 @@ impl DispatchKernel<AsyncSession, Value> for Operator {
             RingFixedpointEncode(op) => DispatchKernel::compile(op, plc),
             RingFixedpointMean(op) => DispatchKernel::compile(op, plc),
             RingInject(op) => DispatchKernel::compile(op, plc),
+            RocAuc(op) => DispatchKernel::compile(op, plc),
+            Rotate(op) => DispatchKernel::compile(op, plc),
+            Round(op) => DispatchKernel::compile(op, plc),
             Sample(op) => DispatchKernel::compile(op, plc),
             SampleSeeded(op) => DispatchKernel::compile(op, plc),
             Shape(op) => DispatchKernel::compile(op, plc),
@@ -434,17 +503,33 @@ impl DispatchKernel<AsyncSession, Value> for Operator {
             Shl(op) => DispatchKernel::compile(op, plc),
             ShlDim(op) => DispatchKernel::compile(op, plc),
             Shr(op) => DispatchKernel::compile(op, plc),
+            ShrDim(op) => DispatchKernel::compile(op, plc),
             Sigmoid(op) => DispatchKernel::compile(op, plc),
             Sign(op) => DispatchKernel::compile(op, plc),
+            Silhouette(op) => DispatchKernel::compile(op, plc),
             Slice(op) => DispatchKernel::compile(op, plc),
             Softmax(op) => DispatchKernel::compile(op, plc),
+            SparseToDense(op) => DispatchKernel::compile(op, plc),
             Sqrt(op) => DispatchKernel::compile(op, plc),
+            Square(op) => DispatchKernel::compile(op, plc),
             Squeeze(op) => DispatchKernel::compile(op, plc),
+            Stack(op) => DispatchKernel::compile(op, plc),
+            StandardScalerFit(op) => DispatchKernel::compile(op, plc),
+            Std(op) => DispatchKernel::compile(op, plc),
+            StratifiedSample(op) => DispatchKernel::compile(op, plc),
             Sub(op) => DispatchKernel::compile(op, plc),
             Sum(op) => DispatchKernel::compile(op, plc),
+            TemperatureScale(op) => DispatchKernel::compile(op, plc),
+            Tfidf(op) => DispatchKernel::compile(op, plc),
+            Tile(op) => DispatchKernel::compile(op, plc),
             Transpose(op) => DispatchKernel::compile(op, plc),
+            TreeApply(op) => DispatchKernel::compile(op, plc),
             TruncPr(op) => DispatchKernel::compile(op, plc),
             Output(op) => DispatchKernel::compile(op, plc),
+            Var(op) => DispatchKernel::compile(op, plc),
+            WeightedCrossEntropy(op) => DispatchKernel::compile(op, plc),
+            Where(op) => DispatchKernel::compile(op, plc),
+            Whiten(op) => DispatchKernel::compile(op, plc),
             Xor(op) => DispatchKernel::compile(op, plc),
             Zeros(op) => DispatchKernel::compile(op, plc),
         }
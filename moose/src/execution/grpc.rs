@@ -118,12 +118,17 @@ impl GrpcMooseRuntime {
                 session_id: session_id.clone(),
             };
 
-            let response = client.retrieve_results(request).await?;
+            // Results are streamed in chunks to stay below the maximum gRPC message size
+            let mut chunks = client.retrieve_results_stream(request).await?.into_inner();
+            let mut values = Vec::new();
+            while let Some(chunk) = chunks.message().await? {
+                values.extend_from_slice(&chunk.data);
+            }
 
             let ComputationOutputs {
                 outputs,
                 elapsed_time,
-            } = bincode::deserialize::<ComputationOutputs>(&response.get_ref().values)?;
+            } = bincode::deserialize::<ComputationOutputs>(&values)?;
             combined_outputs.extend(outputs);
 
             if let Some(time) = elapsed_time {
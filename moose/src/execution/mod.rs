@@ -244,6 +244,70 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_send_receive() -> std::result::Result<(), anyhow::Error> {
+        use crate::networking::local::LocalSyncNetworking;
+
+        let source = r#"x = Constant{value = HostFloat64Tensor([1.0, 2.0])}: () -> HostFloat64Tensor () @Host(alice)
+        send = Send{rendezvous_key = 30313233343536373839616263646566, receiver = "bob"}: (HostFloat64Tensor) -> HostUnit (x) @Host(alice)
+        receive = Receive{rendezvous_key = 30313233343536373839616263646566, sender = "alice"}: () -> HostFloat64Tensor () @Host(bob)
+        output = Output: (HostFloat64Tensor) -> HostFloat64Tensor (receive) @Host(bob)
+        "#;
+
+        let networking = Rc::new(LocalSyncNetworking::default());
+        let session = SyncSession::from_networking(
+            SessionId::try_from("foobar").unwrap(),
+            hashmap!(),
+            hashmap!(
+                Role::from("alice") => Identity::from("alice"),
+                Role::from("bob") => Identity::from("bob"),
+            ),
+            networking.clone(),
+        );
+        let executor = TestSyncExecutor::default();
+        let outputs = executor.run_computation(&source.try_into()?, &session)?;
+
+        let received: HostFloat64Tensor = outputs.get("output").unwrap().clone().try_into()?;
+        assert_eq!(received.0, array![1.0, 2.0].into_dyn().into_shared());
+        assert_eq!(received.1, HostPlacement::from("bob"));
+
+        // the value went through the session networking layer
+        use crate::networking::SyncNetworking;
+        let sent = networking.receive(
+            &Identity::from("alice"),
+            &"0123456789abcdef".try_into()?,
+            &SessionId::try_from("foobar").unwrap(),
+        )?;
+        let expected: Value = "HostFloat64Tensor([1.0, 2.0]) @Host(alice)".try_into()?;
+        assert_eq!(sent, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_receive_type_mismatch() -> std::result::Result<(), anyhow::Error> {
+        let source = r#"x = Constant{value = HostFloat64Tensor([1.0, 2.0])}: () -> HostFloat64Tensor () @Host(alice)
+        send = Send{rendezvous_key = 30313233343536373839616263646566, receiver = "bob"}: (HostFloat64Tensor) -> HostUnit (x) @Host(alice)
+        receive = Receive{rendezvous_key = 30313233343536373839616263646566, sender = "alice"}: () -> HostInt64Tensor () @Host(bob)
+        "#;
+        let computation: Computation = source.try_into()?;
+
+        let session = SyncSession::from_roles([Role::from("alice"), Role::from("bob")].iter());
+        let send = &computation.operations[1];
+        let x: Value = "HostFloat64Tensor([1.0, 2.0]) @Host(alice)".try_into()?;
+        session.execute(&send.kind, &send.placement, vec![x])?;
+
+        let receive = &computation.operations[2];
+        let res = session.execute(&receive.kind, &receive.placement, vec![]);
+        match res {
+            Err(Error::TypeMismatch { expected, found }) => {
+                assert_eq!(expected, Ty::HostInt64Tensor.to_string());
+                assert_eq!(found, Ty::HostFloat64Tensor);
+            }
+            _ => panic!("expected a type mismatch"),
+        }
+        Ok(())
+    }
+
     #[rstest]
     #[case("HostInt64Tensor([8]) @Host(alice)", true)]
     #[case("HostInt32Tensor([8]) @Host(alice)", true)]
@@ -310,6 +374,37 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_save_overwrite() -> std::result::Result<(), anyhow::Error> {
+        let source = r#"key = Constant{value = HostString("saved_data")}: () -> HostString () @Host(alice)
+        x = Constant{value = HostFloat64Tensor([1.0, 2.0])}: () -> HostFloat64Tensor () @Host(alice)
+        y = Constant{value = HostFloat64Tensor([3.0])}: () -> HostFloat64Tensor () @Host(alice)
+        save_x = Save: (HostString, HostFloat64Tensor) -> HostUnit (key, x) @Host(alice)
+        save_y = Save: (HostString, HostFloat64Tensor) -> HostUnit (key, y) @Host(alice)
+        "#;
+        let computation: Computation = source.try_into()?;
+
+        let session = SyncSession::from_storage(
+            SessionId::try_from("foobar").unwrap(),
+            hashmap!(),
+            hashmap!(),
+            Rc::new(LocalSyncStorage::default()),
+        );
+        let executor = TestSyncExecutor::default();
+        executor.run_computation(&computation, &session)?;
+
+        // the second save replaced the first
+        let load: Computation = r#"load = Load: (HostString, HostString) -> HostFloat64Tensor (key, query) @Host(alice)"#.try_into()?;
+        let load = &load.operations[0];
+        let plc = HostPlacement::from("alice");
+        let key: Value = HostString("saved_data".to_string(), plc.clone()).into();
+        let query: Value = HostString("".to_string(), plc).into();
+        let loaded = session.execute(&load.kind, &load.placement, vec![key, query])?;
+        let expected: Value = "HostFloat64Tensor([3.0]) @Host(alice)".try_into()?;
+        assert_eq!(loaded, expected);
+        Ok(())
+    }
+
     #[rstest]
     #[case(
         "0",
@@ -8,11 +8,13 @@
 use super::{Operands, Session, SetupGeneration};
 use crate::computation::*;
 use crate::error::{Error, Result};
-use crate::host::HostPrfKey;
+use crate::host::{HostPrfKey, SyncKey};
 use crate::kernels::{DispatchKernel, Kernel, PlacementPlace};
 use crate::replicated::{RepSetup, ReplicatedPlacement};
 use crate::{MirroredCounterpart, Ring, TensorLike, Underlying};
-use parking_lot::RwLock;
+use parking_lot::{Mutex, RwLock};
+use rayon::prelude::*;
+use std::collections::hash_map::Entry;
 use std::collections::HashMap;
 use std::sync::Arc;
 
@@ -136,28 +138,82 @@ where
     }
 }
 
+/// Key identifying identical operations for common subexpression elimination
+type OperationKey = (Operator, Vec<String>, Placement);
+
 #[derive(Default)]
 struct SymbolicSessionState {
     pub ops: Vec<Operation>,
     pub replicated_keys: HashMap<ReplicatedPlacement, Arc<RepSetup<Symbolic<HostPrfKey>>>>,
+    pub recorded_ops: HashMap<OperationKey, String>,
 }
 
 /// Session object in which symbolic execution is happening
 pub struct SymbolicSession {
     pub(crate) strategy: Box<dyn SymbolicStrategy>,
+    /// Reuse previously recorded identical operations instead of adding duplicates
+    pub(crate) cse: bool,
     state: Arc<RwLock<SymbolicSessionState>>,
+    /// Held while generating replicated setups so that their operations are recorded one setup at a time
+    setup_lock: Mutex<()>,
+    /// Prefix of the names given to recorded operations
+    op_prefix: String,
 }
 
 impl Default for SymbolicSession {
     fn default() -> Self {
         SymbolicSession {
             strategy: Box::new(DefaultSymbolicStrategy),
+            cse: false,
             state: Default::default(),
+            setup_lock: Default::default(),
+            op_prefix: "op_".to_string(),
         }
     }
 }
 
+/// Whether an operator may be merged with an identical one by common subexpression elimination
+///
+/// Operators with side effects, or producing fresh randomness, must be kept as separate operations.
+fn is_pure(operator: &Operator) -> bool {
+    !matches!(
+        operator,
+        Operator::Send(_)
+            | Operator::Receive(_)
+            | Operator::Save(_)
+            | Operator::Output(_)
+            | Operator::Sample(_)
+            | Operator::SampleSeeded(_)
+            | Operator::PrfKeyGen(_)
+            | Operator::DeriveSeed(_)
+    )
+}
+
+/// Sync key of a `DeriveSeed` operation recorded under the given name
+///
+/// Operation names are unique within a computation and seeds are also derived from the session
+/// id at runtime, so deriving the key from the name keeps seeds distinct while making lowering
+/// reproducible.
+fn derive_sync_key(op_name: &str) -> SyncKey {
+    let mut hasher = blake3::Hasher::new();
+    hasher.update(op_name.as_bytes());
+    let mut raw_sync_key = [0u8; TAG_BYTES];
+    hasher.finalize_xof().fill(&mut raw_sync_key);
+    SyncKey::from_bytes(raw_sync_key)
+}
+
 impl SymbolicSession {
+    /// Create a session that eliminates common subexpressions
+    ///
+    /// Adding an operation identical to one already recorded, meaning that it has the same
+    /// operator, inputs, and placement, returns a handle to the existing operation.
+    pub fn with_cse() -> Self {
+        SymbolicSession {
+            cse: true,
+            ..Default::default()
+        }
+    }
+
     /// Add operation to the session's underlying computation
     pub(crate) fn add_operation<'s, O, P, Q>(
         &'s self,
@@ -172,12 +228,36 @@ impl SymbolicSession {
         Placement: From<P>,
     {
         let mut state = self.state.write();
-        let op_name: String = format!("op_{}", state.ops.len());
+        let mut kind = Operator::from(operator.clone());
+        let inputs: Vec<String> = operands.iter().map(|op| op.to_string()).collect();
+        let placement = Placement::from(plc.clone());
+
+        let key = if self.cse && is_pure(&kind) {
+            let key = (kind.clone(), inputs.clone(), placement.clone());
+            if let Some(op_name) = state.recorded_ops.get(&key) {
+                return SymbolicHandle {
+                    op: op_name.clone(),
+                    plc: plc.clone().into(),
+                };
+            }
+            Some(key)
+        } else {
+            None
+        };
+
+        let op_name: String = format!("{}{}", self.op_prefix, state.ops.len());
+        if let Some(key) = key {
+            state.recorded_ops.insert(key, op_name.clone());
+        }
+        // Kernels draw random sync keys, which would make every lowering differ
+        if let Operator::DeriveSeed(op) = &mut kind {
+            op.sync_key = derive_sync_key(&op_name);
+        }
         let op = Operation {
             name: op_name.clone(),
-            kind: Operator::from(operator.clone()),
-            inputs: operands.iter().map(|op| op.to_string()).collect(),
-            placement: Placement::from(plc.clone()),
+            kind,
+            inputs,
+            placement,
         };
         state.ops.push(op);
 
@@ -187,6 +267,11 @@ impl SymbolicSession {
         }
     }
 
+    /// Count the operations recorded so far, grouped by operator and by placement type.
+    pub fn graph_stats(&self) -> GraphStats {
+        GraphStats::from_operations(&self.state.read().ops)
+    }
+
     /// Apply a given closure to the iterator over the ops.
     ///
     /// The "ops" vector is locked for READ for the duration of the call.
@@ -216,27 +301,21 @@ impl SetupGeneration<ReplicatedPlacement> for SymbolicSession {
     type Setup = RepSetup<Symbolic<HostPrfKey>>;
 
     fn setup(&self, plc: &ReplicatedPlacement) -> Result<Arc<Self::Setup>> {
-        // Produce a new replicated setup or returned a previously produced setup for the placement
-        let state = self.state.read();
-        match state.replicated_keys.get(plc) {
-            Some(setup) => Ok(Arc::clone(setup)),
-            None => {
-                drop(state); // Release the read access
-
-                // This may (likely) grab a write lock to the state inside
-                let new_setup = plc.gen_setup(self)?;
-
-                // Grab a new write lock.
-                let mut state = self.state.write();
-                // Only insert if missing, since someone else might have done that already
-                // If our `new_setup` ends up being unused due to the race, it will be pruned later on by a dedicated pruning pass.
-                let setup = state
-                    .replicated_keys
-                    .entry(plc.clone())
-                    .or_insert_with(|| Arc::new(new_setup));
-                Ok(Arc::clone(setup))
-            }
+        // Produce a new replicated setup or returned a previously produced setup for the placement.
+        // Generation is serialized so that concurrent requests can neither interleave the recorded
+        // key generation operations nor produce duplicate setups, keeping operation names reproducible.
+        let _guard = self.setup_lock.lock();
+        if let Some(setup) = self.state.read().replicated_keys.get(plc) {
+            return Ok(Arc::clone(setup));
         }
+
+        // This grabs a write lock to the state inside, so no state lock may be held here
+        let new_setup = Arc::new(plc.gen_setup(self)?);
+        self.state
+            .write()
+            .replicated_keys
+            .insert(plc.clone(), Arc::clone(&new_setup));
+        Ok(new_setup)
     }
 }
 
@@ -273,7 +352,69 @@ impl DispatchKernel<SymbolicSession, SymbolicValue> for Operator {
     fn compile(&self, plc: &Placement) -> Result<Kernel<SymbolicSession, SymbolicValue>> {
         use Operator::*;
         match self {
+            AssignClusters(op) => DispatchKernel::compile(op, plc),
+            BagOfWords(op) => DispatchKernel::compile(op, plc),
+            Bootstrap(op) => DispatchKernel::compile(op, plc),
+            BrierScore(op) => DispatchKernel::compile(op, plc),
+            Calibration(op) => DispatchKernel::compile(op, plc),
+            Ceil(op) => DispatchKernel::compile(op, plc),
+            CentroidDist(op) => DispatchKernel::compile(op, plc),
+            Clip(op) => DispatchKernel::compile(op, plc),
+            ClosestPair(op) => DispatchKernel::compile(op, plc),
+            CosineSimilarity(op) => DispatchKernel::compile(op, plc),
+            CountDistinct(op) => DispatchKernel::compile(op, plc),
+            Cumsum(op) => DispatchKernel::compile(op, plc),
+            Dbscan(op) => DispatchKernel::compile(op, plc),
+            DenseToSparseIndices(op) => DispatchKernel::compile(op, plc),
+            DenseToSparseValues(op) => DispatchKernel::compile(op, plc),
+            ExplainedVariance(op) => DispatchKernel::compile(op, plc),
+            F1(op) => DispatchKernel::compile(op, plc),
+            Flip(op) => DispatchKernel::compile(op, plc),
+            Floor(op) => DispatchKernel::compile(op, plc),
+            FocalLoss(op) => DispatchKernel::compile(op, plc),
+            ForestApply(op) => DispatchKernel::compile(op, plc),
+            Gather(op) => DispatchKernel::compile(op, plc),
+            GaussianNb(op) => DispatchKernel::compile(op, plc),
+            GbdtApply(op) => DispatchKernel::compile(op, plc),
+            GreaterEqual(op) => DispatchKernel::compile(op, plc),
+            HuberLoss(op) => DispatchKernel::compile(op, plc),
+            IsolationScore(op) => DispatchKernel::compile(op, plc),
+            Jaccard(op) => DispatchKernel::compile(op, plc),
+            KFold(op) => DispatchKernel::compile(op, plc),
+            Knn(op) => DispatchKernel::compile(op, plc),
+            KnnVote(op) => DispatchKernel::compile(op, plc),
+            LabelSmoothing(op) => DispatchKernel::compile(op, plc),
+            LinearPredict(op) => DispatchKernel::compile(op, plc),
+            LogLoss(op) => DispatchKernel::compile(op, plc),
+            LogisticPredict(op) => DispatchKernel::compile(op, plc),
+            LookupTable(op) => DispatchKernel::compile(op, plc),
+            Mae(op) => DispatchKernel::compile(op, plc),
+            Mahalanobis(op) => DispatchKernel::compile(op, plc),
+            Mcc(op) => DispatchKernel::compile(op, plc),
+            MinMaxScalerFit(op) => DispatchKernel::compile(op, plc),
+            Minimum(op) => DispatchKernel::compile(op, plc),
+            Mixup(op) => DispatchKernel::compile(op, plc),
+            Mod(op) => DispatchKernel::compile(op, plc),
+            MovingStd(op) => DispatchKernel::compile(op, plc),
+            Mse(op) => DispatchKernel::compile(op, plc),
+            NgramHash(op) => DispatchKernel::compile(op, plc),
+            NormalizeProb(op) => DispatchKernel::compile(op, plc),
+            Not(op) => DispatchKernel::compile(op, plc),
+            NotEqual(op) => DispatchKernel::compile(op, plc),
+            Pad(op) => DispatchKernel::compile(op, plc),
+            PcaProject(op) => DispatchKernel::compile(op, plc),
+            PolyFeatures(op) => DispatchKernel::compile(op, plc),
+            Popcount(op) => DispatchKernel::compile(op, plc),
+            Pow(op) => DispatchKernel::compile(op, plc),
+            PrecisionRecall(op) => DispatchKernel::compile(op, plc),
+            Prod(op) => DispatchKernel::compile(op, plc),
+            R2(op) => DispatchKernel::compile(op, plc),
+            RankNorm(op) => DispatchKernel::compile(op, plc),
             Receive(op) => DispatchKernel::compile(op, plc),
+            Reciprocal(op) => DispatchKernel::compile(op, plc),
+            RocAuc(op) => DispatchKernel::compile(op, plc),
+            Rotate(op) => DispatchKernel::compile(op, plc),
+            Round(op) => DispatchKernel::compile(op, plc),
             Send(op) => DispatchKernel::compile(op, plc),
             Abs(op) => DispatchKernel::compile(op, plc),
             Add(op) => DispatchKernel::compile(op, plc),
@@ -340,17 +481,33 @@ impl DispatchKernel<SymbolicSession, SymbolicValue> for Operator {
             Shl(op) => DispatchKernel::compile(op, plc),
             ShlDim(op) => DispatchKernel::compile(op, plc),
             Shr(op) => DispatchKernel::compile(op, plc),
+            ShrDim(op) => DispatchKernel::compile(op, plc),
             Sigmoid(op) => DispatchKernel::compile(op, plc),
             Sign(op) => DispatchKernel::compile(op, plc),
+            Silhouette(op) => DispatchKernel::compile(op, plc),
             Slice(op) => DispatchKernel::compile(op, plc),
             Softmax(op) => DispatchKernel::compile(op, plc),
+            SparseToDense(op) => DispatchKernel::compile(op, plc),
             Sqrt(op) => DispatchKernel::compile(op, plc),
+            Square(op) => DispatchKernel::compile(op, plc),
             Squeeze(op) => DispatchKernel::compile(op, plc),
+            Stack(op) => DispatchKernel::compile(op, plc),
+            StandardScalerFit(op) => DispatchKernel::compile(op, plc),
+            Std(op) => DispatchKernel::compile(op, plc),
+            StratifiedSample(op) => DispatchKernel::compile(op, plc),
             Sub(op) => DispatchKernel::compile(op, plc),
             Sum(op) => DispatchKernel::compile(op, plc),
+            TemperatureScale(op) => DispatchKernel::compile(op, plc),
+            Tfidf(op) => DispatchKernel::compile(op, plc),
+            Tile(op) => DispatchKernel::compile(op, plc),
             Transpose(op) => DispatchKernel::compile(op, plc),
+            TreeApply(op) => DispatchKernel::compile(op, plc),
             TruncPr(op) => DispatchKernel::compile(op, plc),
             Output(op) => DispatchKernel::compile(op, plc),
+            Var(op) => DispatchKernel::compile(op, plc),
+            WeightedCrossEntropy(op) => DispatchKernel::compile(op, plc),
+            Where(op) => DispatchKernel::compile(op, plc),
+            Whiten(op) => DispatchKernel::compile(op, plc),
             Xor(op) => DispatchKernel::compile(op, plc),
             Zeros(op) => DispatchKernel::compile(op, plc),
         }
@@ -409,19 +566,8 @@ impl SymbolicExecutor {
                 HashMap::with_capacity(computation.operations.len());
 
             for op in computation.operations.iter() {
-                let operands = op
-                    .inputs
-                    .iter()
-                    .map(|input_name| env.get(input_name).unwrap().clone())
-                    .collect();
-                let result = session
-                    .execute(&op.kind, &op.placement, operands)
-                    .map_err(|e| {
-                        Error::Compilation(format!(
-                            "SymbolicSession failed to lower computation due to an error: {}",
-                            e,
-                        ))
-                    })?;
+                let operands = lookup_operands(&env, op)?;
+                let result = lower_operation(&session, op, operands)?;
                 env.insert(&op.name, result);
             }
         }
@@ -429,7 +575,336 @@ impl SymbolicExecutor {
         let state = Arc::try_unwrap(session.state)
             .map_err(|_| Error::Compilation("could not consume state after lowering".to_string()))?
             .into_inner();
-        let operations = state.ops;
+        let lowered = Computation {
+            operations: state.ops,
+        };
+        // Drop operations recorded during lowering that nothing depends on, such as unused setups
+        crate::compilation::pruning::prune_unreachable(lowered)
+    }
+
+    /// Lower independent operations concurrently.
+    ///
+    /// Operations are grouped into topological levels, and the operations of each level are
+    /// lowered in parallel, each into its own session. Replicated setups are generated up front
+    /// and shared between these sessions. The recorded operations are concatenated in the order
+    /// of the original operations, making the result deterministic, although operation names
+    /// differ from those produced by `run_computation`. A setup is generated for every replicated
+    /// placement in the computation, even if no kernel ends up using it; as with any unused
+    /// operation it is left for `Pass::Prune` to remove.
+    pub fn run_computation_parallel(
+        &self,
+        computation: &Computation,
+    ) -> anyhow::Result<Computation> {
+        // Level of every operation, one more than the highest level among its inputs
+        let mut levels: Vec<Vec<usize>> = Vec::new();
+        {
+            let mut op_levels: HashMap<&String, usize> =
+                HashMap::with_capacity(computation.operations.len());
+            for (i, op) in computation.operations.iter().enumerate() {
+                let mut level = 0;
+                for input_name in op.inputs.iter() {
+                    let input_level = op_levels.get(input_name).ok_or_else(|| {
+                        Error::Compilation(format!(
+                            "SymbolicSession failed to lower computation: input '{}' of operation '{}' is not defined before it",
+                            input_name, op.name,
+                        ))
+                    })?;
+                    level = level.max(input_level + 1);
+                }
+                op_levels.insert(&op.name, level);
+                if levels.len() <= level {
+                    levels.resize_with(level + 1, Vec::new);
+                }
+                levels[level].push(i);
+            }
+        }
+
+        // Setups are generated in order of first use so that their operations are reproducible
+        let setup_session = SymbolicSession::default();
+        let mut setups = HashMap::new();
+        for op in computation.operations.iter() {
+            if let Placement::Replicated(plc) = &op.placement {
+                if let Entry::Vacant(entry) = setups.entry(plc.clone()) {
+                    entry.insert(setup_session.setup(plc)?);
+                }
+            }
+        }
+
+        let mut env: HashMap<&String, SymbolicValue> =
+            HashMap::with_capacity(computation.operations.len());
+        let mut recorded: Vec<Vec<Operation>> = vec![Vec::new(); computation.operations.len()];
+        for level in levels.iter() {
+            let results = level
+                .par_iter()
+                .map(|&i| {
+                    let op = &computation.operations[i];
+                    let session = SymbolicSession {
+                        state: Arc::new(RwLock::new(SymbolicSessionState {
+                            replicated_keys: setups.clone(),
+                            ..Default::default()
+                        })),
+                        op_prefix: format!("op_{}_", i),
+                        ..Default::default()
+                    };
+                    let operands = lookup_operands(&env, op)?;
+                    let result = lower_operation(&session, op, operands)?;
+                    let state = Arc::try_unwrap(session.state)
+                        .map_err(|_| {
+                            Error::Compilation("could not consume state after lowering".to_string())
+                        })?
+                        .into_inner();
+                    Ok((i, result, state.ops))
+                })
+                .collect::<Result<Vec<_>>>()?;
+
+            for (i, result, ops) in results {
+                env.insert(&computation.operations[i].name, result);
+                recorded[i] = ops;
+            }
+        }
+
+        let setup_ops = Arc::try_unwrap(setup_session.state)
+            .map_err(|_| Error::Compilation("could not consume state after lowering".to_string()))?
+            .into_inner()
+            .ops;
+        let operations = setup_ops
+            .into_iter()
+            .chain(recorded.into_iter().flatten())
+            .collect();
         Ok(Computation { operations })
     }
 }
+
+/// Values of the inputs of `op` from the environment of already lowered operations
+fn lookup_operands(
+    env: &HashMap<&String, SymbolicValue>,
+    op: &Operation,
+) -> Result<Operands<SymbolicValue>> {
+    op.inputs
+        .iter()
+        .map(|input_name| {
+            env.get(input_name).cloned().ok_or_else(|| {
+                Error::Compilation(format!(
+                    "SymbolicSession failed to lower computation: input '{}' of operation '{}' is not defined before it",
+                    input_name, op.name,
+                ))
+            })
+        })
+        .collect()
+}
+
+fn lower_operation(
+    session: &SymbolicSession,
+    op: &Operation,
+    operands: Operands<SymbolicValue>,
+) -> Result<SymbolicValue> {
+    session
+        .execute(&op.kind, &op.placement, operands)
+        .map_err(|e| {
+            Error::Compilation(format!(
+                "SymbolicSession failed to lower operation '{}' ({} on {:?}) due to an error: {}",
+                op.name,
+                op.kind.short_name(),
+                op.placement,
+                e,
+            ))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::host::HostPlacement;
+    use crate::kernels::{PlacementAdd, PlacementMul};
+    use crate::types::HostFloat64Tensor;
+
+    fn diamond(
+        sess: &SymbolicSession,
+    ) -> (SymbolicHandle<HostPlacement>, SymbolicHandle<HostPlacement>) {
+        let alice = HostPlacement::from("alice");
+        let x: <HostFloat64Tensor as KnownType<SymbolicSession>>::Type =
+            Symbolic::Symbolic(SymbolicHandle {
+                op: "x".into(),
+                plc: alice.clone(),
+            });
+
+        // both branches compute the same value before being joined
+        let a = alice.add(sess, &x, &x);
+        let b = alice.add(sess, &x, &x);
+        let _c = alice.mul(sess, &a, &b);
+
+        match (a, b) {
+            (Symbolic::Symbolic(a), Symbolic::Symbolic(b)) => (a, b),
+            _ => panic!("Expected symbolic results"),
+        }
+    }
+
+    #[test]
+    fn test_cse_diamond() {
+        let sess = SymbolicSession::with_cse();
+        let (a, b) = diamond(&sess);
+        assert_eq!(a.op, b.op);
+        sess.ops_iter(|iter| {
+            let ops: Vec<_> = iter.collect();
+            assert_eq!(ops.len(), 2);
+            assert_eq!(ops[1].inputs, vec![a.op.clone(), a.op.clone()]);
+        });
+    }
+
+    #[test]
+    fn test_without_cse_diamond() {
+        let sess = SymbolicSession::default();
+        let (a, b) = diamond(&sess);
+        assert_ne!(a.op, b.op);
+        sess.ops_iter(|iter| assert_eq!(iter.count(), 3));
+    }
+
+    #[test]
+    fn test_cse_keeps_side_effects() {
+        let sess = SymbolicSession::with_cse();
+        let alice = HostPlacement::from("alice");
+        let sample = SampleOp {
+            sig: Signature::unary(Ty::HostShape, Ty::HostRing64Tensor),
+            max_value: None,
+        };
+        let r0: SymbolicHandle<HostPlacement> = sess.add_operation(&sample, &["shape"], &alice);
+        let r1: SymbolicHandle<HostPlacement> = sess.add_operation(&sample, &["shape"], &alice);
+        assert_ne!(r0.op, r1.op);
+        sess.ops_iter(|iter| assert_eq!(iter.count(), 2));
+    }
+
+    #[test]
+    fn test_run_computation_prunes_unreachable() -> std::result::Result<(), anyhow::Error> {
+        use std::convert::TryInto;
+
+        let source = r#"
+        x = Constant{value=HostFloat32Tensor([1.0, 2.0])}: () -> HostFloat32Tensor @Host(alice)
+        shape = Constant{value=HostShape([2])}: () -> HostShape @Host(alice)
+        r = Sample{}: (HostShape) -> HostRing64Tensor (shape) @Host(alice)
+        y = Neg: (HostFloat32Tensor) -> HostFloat32Tensor (x) @Host(alice)
+        z = Output: (HostFloat32Tensor) -> HostFloat32Tensor (y) @Host(alice)"#;
+
+        let comp = SymbolicExecutor::default().run_computation(&source.try_into()?)?;
+        let kinds: Vec<_> = comp.operations.iter().map(|op| op.kind.clone()).collect();
+        assert!(matches!(
+            kinds.as_slice(),
+            [Operator::Constant(_), Operator::Neg(_), Operator::Output(_)]
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_graph_stats() {
+        let sess = SymbolicSession::default();
+        let _ = diamond(&sess);
+        let stats = sess.graph_stats();
+        assert_eq!(stats.total, 3);
+        assert_eq!(stats.by_operator.len(), 2);
+        assert_eq!(stats.by_operator["Add"], 2);
+        assert_eq!(stats.by_operator["Mul"], 1);
+        assert_eq!(stats.by_placement.len(), 1);
+        assert_eq!(stats.by_placement["Host"], 3);
+    }
+
+    #[test]
+    fn test_computation_graph_stats() -> std::result::Result<(), anyhow::Error> {
+        use std::convert::TryInto;
+
+        let source = r#"
+        x = Constant{value = HostFloat64Tensor([[1.0, 2.0]])}: () -> Tensor<Float64> () @Host(alice)
+        y = Constant{value = HostFloat64Tensor([[3.0], [4.0]])}: () -> Tensor<Float64> () @Host(bob)
+        x_fixed = Cast: (Tensor<Float64>) -> Tensor<Fixed128(24, 40)> (x) @Host(alice)
+        y_fixed = Cast: (Tensor<Float64>) -> Tensor<Fixed128(24, 40)> (y) @Host(bob)
+        dot = Dot: (Tensor<Fixed128(24, 40)>, Tensor<Fixed128(24, 40)>) -> Tensor<Fixed128(24, 40)> (x_fixed, y_fixed) @Replicated(alice, bob, carole)
+        res = Cast: (Tensor<Fixed128(24, 40)>) -> Tensor<Float64> (dot) @Host(carole)
+        output = Output: (Tensor<Float64>) -> Tensor<Float64> (res) @Host(carole)"#;
+
+        let comp = SymbolicExecutor::default().run_computation(&source.try_into()?)?;
+        let stats = comp.graph_stats();
+        assert_eq!(stats.total, comp.operations.len());
+        assert_eq!(stats.by_operator["Output"], 1);
+        // the replicated dot is lowered into host operations only
+        assert_eq!(stats.by_placement.len(), 1);
+        assert_eq!(stats.by_placement["Host"], stats.total);
+        Ok(())
+    }
+
+    #[test]
+    fn test_run_computation_deterministic() -> std::result::Result<(), anyhow::Error> {
+        use std::convert::TryInto;
+
+        let source = r#"
+        x = Constant{value = HostFloat64Tensor([[1.0, 2.0]])}: () -> Tensor<Float64> () @Host(alice)
+        y = Constant{value = HostFloat64Tensor([[3.0], [4.0]])}: () -> Tensor<Float64> () @Host(bob)
+        x_fixed = Cast: (Tensor<Float64>) -> Tensor<Fixed128(24, 40)> (x) @Host(alice)
+        y_fixed = Cast: (Tensor<Float64>) -> Tensor<Fixed128(24, 40)> (y) @Host(bob)
+        dot = Dot: (Tensor<Fixed128(24, 40)>, Tensor<Fixed128(24, 40)>) -> Tensor<Fixed128(24, 40)> (x_fixed, y_fixed) @Replicated(alice, bob, carole)
+        res = Cast: (Tensor<Fixed128(24, 40)>) -> Tensor<Float64> (dot) @Host(carole)
+        output = Output: (Tensor<Float64>) -> Tensor<Float64> (res) @Host(carole)"#;
+
+        let comp: Computation = source.try_into()?;
+        let first = SymbolicExecutor::default().run_computation(&comp)?;
+        let second = SymbolicExecutor::default().run_computation(&comp)?;
+        assert_eq!(first, second);
+        Ok(())
+    }
+
+    #[test]
+    fn test_run_computation_dangling_input() {
+        use std::convert::TryInto;
+
+        let source = r#"
+        x = Constant{value=HostFloat32Tensor([1.0, 2.0])}: () -> HostFloat32Tensor @Host(alice)
+        y = Add: (HostFloat32Tensor, HostFloat32Tensor) -> HostFloat32Tensor (x, w) @Host(alice)
+        z = Output: (HostFloat32Tensor) -> HostFloat32Tensor (y) @Host(alice)"#;
+
+        let comp: Computation = source.try_into().unwrap();
+        let err = SymbolicExecutor::default()
+            .run_computation(&comp)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("'w'"), "{}", err);
+        assert!(err.contains("'y'"), "{}", err);
+    }
+
+    #[test]
+    fn test_run_computation_parallel() -> std::result::Result<(), anyhow::Error> {
+        use std::convert::TryInto;
+
+        let source = r#"
+        x = Constant{value = HostFloat64Tensor([[1.0, 2.0]])}: () -> Tensor<Float64> () @Host(alice)
+        y = Constant{value = HostFloat64Tensor([[3.0], [4.0]])}: () -> Tensor<Float64> () @Host(bob)
+        x_fixed = Cast: (Tensor<Float64>) -> Tensor<Fixed128(24, 40)> (x) @Host(alice)
+        y_fixed = Cast: (Tensor<Float64>) -> Tensor<Fixed128(24, 40)> (y) @Host(bob)
+        dot = Dot: (Tensor<Fixed128(24, 40)>, Tensor<Fixed128(24, 40)>) -> Tensor<Fixed128(24, 40)> (x_fixed, y_fixed) @Replicated(alice, bob, carole)
+        sq = Mul: (Tensor<Fixed128(24, 40)>, Tensor<Fixed128(24, 40)>) -> Tensor<Fixed128(24, 40)> (x_fixed, x_fixed) @Replicated(alice, bob, carole)
+        res = Cast: (Tensor<Fixed128(24, 40)>) -> Tensor<Float64> (dot) @Host(carole)
+        res_sq = Cast: (Tensor<Fixed128(24, 40)>) -> Tensor<Float64> (sq) @Host(carole)
+        output = Output: (Tensor<Float64>) -> Tensor<Float64> (res) @Host(carole)
+        output_sq = Output: (Tensor<Float64>) -> Tensor<Float64> (res_sq) @Host(carole)"#;
+
+        let comp: Computation = source.try_into()?;
+        let sequential = SymbolicExecutor::default().run_computation(&comp)?;
+        let parallel = SymbolicExecutor::default().run_computation_parallel(&comp)?;
+        assert_eq!(
+            parallel,
+            SymbolicExecutor::default().run_computation_parallel(&comp)?
+        );
+
+        // both paths record the same operations, only their names and order differ
+        fn counts(comp: &Computation) -> HashMap<(Operator, Placement), usize> {
+            let mut counts = HashMap::new();
+            for op in comp.operations.iter() {
+                let mut kind = op.kind.clone();
+                // sync keys are derived from the operation names
+                if let Operator::DeriveSeed(op) = &mut kind {
+                    op.sync_key = SyncKey::from_bytes([0; TAG_BYTES]);
+                }
+                *counts.entry((kind, op.placement.clone())).or_insert(0) += 1;
+            }
+            counts
+        }
+        assert_eq!(counts(&sequential), counts(&parallel));
+        Ok(())
+    }
+}
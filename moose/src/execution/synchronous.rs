@@ -4,7 +4,7 @@ use super::{Identity, Operands, RuntimeSession, Session, SetupGeneration};
 use crate::computation::*;
 use crate::error::{Error, Result};
 use crate::host::*;
-use crate::kernels::{DispatchKernel, Kernel};
+use crate::kernels::{DispatchKernel, Kernel, PlacementPlace};
 use crate::networking::{local::LocalSyncNetworking, SyncNetworking};
 use crate::replicated::*;
 use crate::storage::{local::LocalSyncStorage, SyncStorage};
@@ -119,23 +119,63 @@ impl DispatchKernel<SyncSession, Value> for SendOp {
                 }),
             })
         } else {
-            unimplemented!()
+            Err(Error::UnimplementedOperator(format!(
+                "SendOp is not implemented for placement {:?}",
+                plc
+            )))
         }
     }
 }
 
+/// Moves a received host value onto the receiving placement.
+///
+/// Values without a host placement are returned as received.
+fn place_received(sess: &SyncSession, plc: &HostPlacement, value: Value) -> Value {
+    match value {
+        Value::HostUnit(x) => plc.place(sess, *x).into(),
+        Value::HostString(x) => plc.place(sess, *x).into(),
+        Value::HostShape(x) => plc.place(sess, *x).into(),
+        Value::HostSeed(x) => plc.place(sess, *x).into(),
+        Value::HostPrfKey(x) => plc.place(sess, *x).into(),
+        Value::HostBitTensor(x) => plc.place(sess, *x).into(),
+        Value::HostRing64Tensor(x) => plc.place(sess, *x).into(),
+        Value::HostRing128Tensor(x) => plc.place(sess, *x).into(),
+        Value::HostFixed64Tensor(x) => plc.place(sess, *x).into(),
+        Value::HostFixed128Tensor(x) => plc.place(sess, *x).into(),
+        Value::HostFloat32Tensor(x) => plc.place(sess, *x).into(),
+        Value::HostFloat64Tensor(x) => plc.place(sess, *x).into(),
+        Value::HostInt8Tensor(x) => plc.place(sess, *x).into(),
+        Value::HostInt16Tensor(x) => plc.place(sess, *x).into(),
+        Value::HostInt32Tensor(x) => plc.place(sess, *x).into(),
+        Value::HostInt64Tensor(x) => plc.place(sess, *x).into(),
+        Value::HostUint8Tensor(x) => plc.place(sess, *x).into(),
+        Value::HostUint16Tensor(x) => plc.place(sess, *x).into(),
+        Value::HostUint32Tensor(x) => plc.place(sess, *x).into(),
+        Value::HostUint64Tensor(x) => plc.place(sess, *x).into(),
+        value => value,
+    }
+}
+
 impl DispatchKernel<SyncSession, Value> for ReceiveOp {
     fn compile(&self, plc: &Placement) -> Result<Kernel<SyncSession, Value>> {
-        if let Placement::Host(_plc) = plc {
+        if let Placement::Host(plc) = plc {
+            let plc = plc.clone();
             let op = self.clone();
             Ok(Kernel::Nullary {
                 closure: Box::new(move |sess, _plc| {
-                    // TODO(Morten) we should verify type of received value
-                    sess.networking.receive(
+                    let value = sess.networking.receive(
                         sess.find_role_assignment(&op.sender)?,
                         &op.rendezvous_key,
                         &sess.session_id,
-                    )
+                    )?;
+                    let expected_ty = op.sig.ret();
+                    if value.ty() != expected_ty {
+                        return Err(Error::TypeMismatch {
+                            expected: expected_ty.to_string(),
+                            found: value.ty(),
+                        });
+                    }
+                    Ok(place_received(sess, &plc, value))
                 }),
             })
         } else {
@@ -153,6 +193,68 @@ impl DispatchKernel<SyncSession, Value> for Operator {
         match self {
             Load(_) => unimplemented!(),
             Save(_) => unimplemented!(),
+            AssignClusters(op) => DispatchKernel::compile(op, plc),
+            BagOfWords(op) => DispatchKernel::compile(op, plc),
+            Bootstrap(op) => DispatchKernel::compile(op, plc),
+            BrierScore(op) => DispatchKernel::compile(op, plc),
+            Calibration(op) => DispatchKernel::compile(op, plc),
+            Ceil(op) => DispatchKernel::compile(op, plc),
+            CentroidDist(op) => DispatchKernel::compile(op, plc),
+            Clip(op) => DispatchKernel::compile(op, plc),
+            ClosestPair(op) => DispatchKernel::compile(op, plc),
+            CosineSimilarity(op) => DispatchKernel::compile(op, plc),
+            CountDistinct(op) => DispatchKernel::compile(op, plc),
+            Cumsum(op) => DispatchKernel::compile(op, plc),
+            Dbscan(op) => DispatchKernel::compile(op, plc),
+            DenseToSparseIndices(op) => DispatchKernel::compile(op, plc),
+            DenseToSparseValues(op) => DispatchKernel::compile(op, plc),
+            ExplainedVariance(op) => DispatchKernel::compile(op, plc),
+            F1(op) => DispatchKernel::compile(op, plc),
+            Flip(op) => DispatchKernel::compile(op, plc),
+            Floor(op) => DispatchKernel::compile(op, plc),
+            FocalLoss(op) => DispatchKernel::compile(op, plc),
+            ForestApply(op) => DispatchKernel::compile(op, plc),
+            Gather(op) => DispatchKernel::compile(op, plc),
+            GaussianNb(op) => DispatchKernel::compile(op, plc),
+            GbdtApply(op) => DispatchKernel::compile(op, plc),
+            GreaterEqual(op) => DispatchKernel::compile(op, plc),
+            HuberLoss(op) => DispatchKernel::compile(op, plc),
+            IsolationScore(op) => DispatchKernel::compile(op, plc),
+            Jaccard(op) => DispatchKernel::compile(op, plc),
+            KFold(op) => DispatchKernel::compile(op, plc),
+            Knn(op) => DispatchKernel::compile(op, plc),
+            KnnVote(op) => DispatchKernel::compile(op, plc),
+            LabelSmoothing(op) => DispatchKernel::compile(op, plc),
+            LinearPredict(op) => DispatchKernel::compile(op, plc),
+            LogLoss(op) => DispatchKernel::compile(op, plc),
+            LogisticPredict(op) => DispatchKernel::compile(op, plc),
+            LookupTable(op) => DispatchKernel::compile(op, plc),
+            Mae(op) => DispatchKernel::compile(op, plc),
+            Mahalanobis(op) => DispatchKernel::compile(op, plc),
+            Mcc(op) => DispatchKernel::compile(op, plc),
+            MinMaxScalerFit(op) => DispatchKernel::compile(op, plc),
+            Minimum(op) => DispatchKernel::compile(op, plc),
+            Mixup(op) => DispatchKernel::compile(op, plc),
+            Mod(op) => DispatchKernel::compile(op, plc),
+            MovingStd(op) => DispatchKernel::compile(op, plc),
+            Mse(op) => DispatchKernel::compile(op, plc),
+            NgramHash(op) => DispatchKernel::compile(op, plc),
+            NormalizeProb(op) => DispatchKernel::compile(op, plc),
+            Not(op) => DispatchKernel::compile(op, plc),
+            NotEqual(op) => DispatchKernel::compile(op, plc),
+            Pad(op) => DispatchKernel::compile(op, plc),
+            PcaProject(op) => DispatchKernel::compile(op, plc),
+            PolyFeatures(op) => DispatchKernel::compile(op, plc),
+            Popcount(op) => DispatchKernel::compile(op, plc),
+            Pow(op) => DispatchKernel::compile(op, plc),
+            PrecisionRecall(op) => DispatchKernel::compile(op, plc),
+            Prod(op) => DispatchKernel::compile(op, plc),
+            R2(op) => DispatchKernel::compile(op, plc),
+            RankNorm(op) => DispatchKernel::compile(op, plc),
+            Reciprocal(op) => DispatchKernel::compile(op, plc),
+            RocAuc(op) => DispatchKernel::compile(op, plc),
+            Rotate(op) => DispatchKernel::compile(op, plc),
+            Round(op) => DispatchKernel::compile(op, plc),
             Send(op) => DispatchKernel::compile(op, plc),
             Receive(op) => DispatchKernel::compile(op, plc),
 
@@ -219,17 +321,33 @@ impl DispatchKernel<SyncSession, Value> for Operator {
             Shl(op) => DispatchKernel::compile(op, plc),
             ShlDim(op) => DispatchKernel::compile(op, plc),
             Shr(op) => DispatchKernel::compile(op, plc),
+            ShrDim(op) => DispatchKernel::compile(op, plc),
             Sigmoid(op) => DispatchKernel::compile(op, plc),
             Sign(op) => DispatchKernel::compile(op, plc),
+            Silhouette(op) => DispatchKernel::compile(op, plc),
             Slice(op) => DispatchKernel::compile(op, plc),
             Softmax(op) => DispatchKernel::compile(op, plc),
+            SparseToDense(op) => DispatchKernel::compile(op, plc),
             Sqrt(op) => DispatchKernel::compile(op, plc),
+            Square(op) => DispatchKernel::compile(op, plc),
             Squeeze(op) => DispatchKernel::compile(op, plc),
+            Stack(op) => DispatchKernel::compile(op, plc),
+            StandardScalerFit(op) => DispatchKernel::compile(op, plc),
+            Std(op) => DispatchKernel::compile(op, plc),
+            StratifiedSample(op) => DispatchKernel::compile(op, plc),
             Sub(op) => DispatchKernel::compile(op, plc),
             Sum(op) => DispatchKernel::compile(op, plc),
+            TemperatureScale(op) => DispatchKernel::compile(op, plc),
+            Tfidf(op) => DispatchKernel::compile(op, plc),
+            Tile(op) => DispatchKernel::compile(op, plc),
             Transpose(op) => DispatchKernel::compile(op, plc),
+            TreeApply(op) => DispatchKernel::compile(op, plc),
             TruncPr(op) => DispatchKernel::compile(op, plc),
             Output(op) => DispatchKernel::compile(op, plc),
+            Var(op) => DispatchKernel::compile(op, plc),
+            WeightedCrossEntropy(op) => DispatchKernel::compile(op, plc),
+            Where(op) => DispatchKernel::compile(op, plc),
+            Whiten(op) => DispatchKernel::compile(op, plc),
             Xor(op) => DispatchKernel::compile(op, plc),
             Zeros(op) => DispatchKernel::compile(op, plc),
         }
@@ -253,15 +371,17 @@ impl Session for SyncSession {
             }
             Operator::Save(_) => {
                 assert_eq!(operands.len(), 2);
+                let host =
+                    match plc {
+                        Placement::Host(host) => host,
+                        _ => return Err(Error::UnimplementedOperator(
+                            "SyncSession does not support running Save on non-host placements yet"
+                                .to_string(),
+                        )),
+                    };
                 let value: Value = operands.pop().unwrap();
                 let key: HostString = operands.pop().unwrap().try_into()?;
                 self.storage.save(&key.0, &self.session_id, &value)?;
-                let host = match plc {
-                    Placement::Host(host) => host,
-                    _ => unimplemented!(
-                        "SyncSession does not support running Save on non-host placements yet"
-                    ),
-                };
                 return Ok(HostUnit(host.clone()).into());
             }
             op => DispatchKernel::compile(op, plc),
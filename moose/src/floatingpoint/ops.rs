@@ -27,6 +27,34 @@ impl IdentityOp {
     }
 }
 
+/// Apply a unary host operation to every replica of a mirrored float value.
+///
+/// The replicas are public, so the result is mirrored as well; demirroring onto an owner
+/// simply selects its own replica.
+fn float_unary_mirrored<S: Session, HostFloatT1, HostFloatT2, MirroredT1, MirroredT2, F>(
+    sess: &S,
+    x: &MirroredT1,
+    op: F,
+) -> Result<MirroredT2>
+where
+    F: Fn(&HostPlacement, &HostFloatT1) -> HostFloatT2,
+    HostPlacement: PlacementDemirror<S, MirroredT1, HostFloatT1>,
+    MirroredT1: Placed<Placement = Mirrored3Placement>,
+    Mir3Tensor<HostFloatT2>: Into<MirroredT2>,
+{
+    let mir = x.placement()?;
+    let (player0, player1, player2) = mir.host_placements();
+
+    let z0 = op(&player0, &player0.demirror(sess, x));
+    let z1 = op(&player1, &player1.demirror(sess, x));
+    let z2 = op(&player2, &player2.demirror(sess, x));
+
+    Ok(Mir3Tensor {
+        values: [z0, z1, z2],
+    }
+    .into())
+}
+
 impl MeanOp {
     pub(crate) fn float_host_kernel<S: Session, HostFloatT, MirroredT>(
         sess: &S,
@@ -36,13 +64,20 @@ impl MeanOp {
     ) -> Result<FloatTensor<HostFloatT, MirroredT>>
     where
         HostPlacement: PlacementMean<S, HostFloatT, HostFloatT>,
+        HostPlacement: PlacementDemirror<S, MirroredT, HostFloatT>,
+        MirroredT: Placed<Placement = Mirrored3Placement>,
+        Mir3Tensor<HostFloatT>: Into<MirroredT>,
     {
-        let x = match x {
-            FloatTensor::Host(v) => v,
-            FloatTensor::Mirrored3(_v) => unimplemented!(),
-        };
-        let z = plc.mean(sess, axis, &x);
-        Ok(FloatTensor::Host(z))
+        match x {
+            FloatTensor::Host(v) => {
+                let z = plc.mean(sess, axis, &v);
+                Ok(FloatTensor::Host(z))
+            }
+            FloatTensor::Mirrored3(v) => {
+                let z = float_unary_mirrored(sess, &v, |plc, x| plc.mean(sess, axis, x))?;
+                Ok(FloatTensor::Mirrored3(z))
+            }
+        }
     }
 }
 
@@ -54,13 +89,20 @@ impl CastOp {
     ) -> Result<FloatTensor<HostFloatT2, MirroredT2>>
     where
         HostPlacement: PlacementCast<S, HostFloatT1, HostFloatT2>,
+        HostPlacement: PlacementDemirror<S, MirroredT1, HostFloatT1>,
+        MirroredT1: Placed<Placement = Mirrored3Placement>,
+        Mir3Tensor<HostFloatT2>: Into<MirroredT2>,
     {
-        let x = match x {
-            FloatTensor::Host(v) => v,
-            FloatTensor::Mirrored3(_v) => unimplemented!(),
-        };
-        let z = plc.cast(sess, &x);
-        Ok(FloatTensor::Host(z))
+        match x {
+            FloatTensor::Host(v) => {
+                let z = plc.cast(sess, &v);
+                Ok(FloatTensor::Host(z))
+            }
+            FloatTensor::Mirrored3(v) => {
+                let z = float_unary_mirrored(sess, &v, |plc, x| plc.cast(sess, x))?;
+                Ok(FloatTensor::Mirrored3(z))
+            }
+        }
     }
 }
 
@@ -91,17 +133,20 @@ impl SigmoidOp {
     ) -> Result<FloatTensor<HostFloatT, MirroredT>>
     where
         HostPlacement: PlacementSigmoid<S, HostFloatT, HostFloatT>,
+        HostPlacement: PlacementDemirror<S, MirroredT, HostFloatT>,
+        MirroredT: Placed<Placement = Mirrored3Placement>,
+        Mir3Tensor<HostFloatT>: Into<MirroredT>,
     {
-        let x = match x {
-            FloatTensor::Host(v) => v,
-            FloatTensor::Mirrored3(_v) => {
-                return Err(Error::UnimplementedOperator(
-                    "SigmoidOp @ Mirrored3Placement".to_string(),
-                ))
+        match x {
+            FloatTensor::Host(v) => {
+                let z = plc.sigmoid(sess, &v);
+                Ok(FloatTensor::Host(z))
             }
-        };
-        let z = plc.sigmoid(sess, &x);
-        Ok(FloatTensor::Host(z))
+            FloatTensor::Mirrored3(v) => {
+                let z = float_unary_mirrored(sess, &v, |plc, x| plc.sigmoid(sess, x))?;
+                Ok(FloatTensor::Mirrored3(z))
+            }
+        }
     }
 }
 
@@ -115,12 +160,43 @@ impl SoftmaxOp {
     ) -> Result<FloatTensor<HostFloatT, MirroredT>>
     where
         HostPlacement: PlacementSoftmax<S, HostFloatT, HostFloatT>,
+        HostPlacement: PlacementDemirror<S, MirroredT, HostFloatT>,
+        MirroredT: Placed<Placement = Mirrored3Placement>,
+        Mir3Tensor<HostFloatT>: Into<MirroredT>,
+    {
+        match x {
+            FloatTensor::Host(v) => {
+                let z = plc.softmax(sess, axis, upmost_index, &v);
+                Ok(FloatTensor::Host(z))
+            }
+            FloatTensor::Mirrored3(v) => {
+                let z = float_unary_mirrored(sess, &v, |plc, x| {
+                    plc.softmax(sess, axis, upmost_index, x)
+                })?;
+                Ok(FloatTensor::Mirrored3(z))
+            }
+        }
+    }
+}
+
+impl NegOp {
+    pub(crate) fn float_host_kernel<S: Session, HostFloatT, MirroredT>(
+        sess: &S,
+        plc: &HostPlacement,
+        x: FloatTensor<HostFloatT, MirroredT>,
+    ) -> Result<FloatTensor<HostFloatT, MirroredT>>
+    where
+        HostPlacement: PlacementNeg<S, HostFloatT, HostFloatT>,
     {
         let x = match x {
             FloatTensor::Host(v) => v,
-            FloatTensor::Mirrored3(_v) => unimplemented!(),
+            FloatTensor::Mirrored3(_v) => {
+                return Err(Error::UnimplementedOperator(
+                    "NegOp @ Mirrored3Placement".to_string(),
+                ))
+            }
         };
-        let z = plc.softmax(sess, axis, upmost_index, &x);
+        let z = plc.neg(sess, &x);
         Ok(FloatTensor::Host(z))
     }
 }
@@ -183,6 +259,61 @@ impl ReluOp {
     }
 }
 
+/// Apply a binary host operation to float operands.
+///
+/// Two mirrored operands are combined replica by replica into a mirrored result, which
+/// keeps public values mirrored. In the mixed case the mirrored operand is demirrored onto
+/// `plc` first and the result is a host tensor.
+fn float_binary_host_kernel<S: Session, HostFloatT, MirroredT, F>(
+    sess: &S,
+    plc: &HostPlacement,
+    x: FloatTensor<HostFloatT, MirroredT>,
+    y: FloatTensor<HostFloatT, MirroredT>,
+    op: F,
+) -> Result<FloatTensor<HostFloatT, MirroredT>>
+where
+    F: Fn(&HostPlacement, &HostFloatT, &HostFloatT) -> HostFloatT,
+    HostPlacement: PlacementDemirror<S, MirroredT, HostFloatT>,
+    MirroredT: Placed<Placement = Mirrored3Placement>,
+    Mir3Tensor<HostFloatT>: Into<MirroredT>,
+{
+    let (x, y) = match (x, y) {
+        (FloatTensor::Mirrored3(x), FloatTensor::Mirrored3(y)) => {
+            let mir = x.placement()?;
+            let (player0, player1, player2) = mir.host_placements();
+
+            let z0 = op(
+                &player0,
+                &player0.demirror(sess, &x),
+                &player0.demirror(sess, &y),
+            );
+            let z1 = op(
+                &player1,
+                &player1.demirror(sess, &x),
+                &player1.demirror(sess, &y),
+            );
+            let z2 = op(
+                &player2,
+                &player2.demirror(sess, &x),
+                &player2.demirror(sess, &y),
+            );
+
+            return Ok(FloatTensor::Mirrored3(
+                Mir3Tensor {
+                    values: [z0, z1, z2],
+                }
+                .into(),
+            ));
+        }
+        (FloatTensor::Host(x), FloatTensor::Host(y)) => (x, y),
+        (FloatTensor::Host(x), FloatTensor::Mirrored3(y)) => (x, plc.demirror(sess, &y)),
+        (FloatTensor::Mirrored3(x), FloatTensor::Host(y)) => (plc.demirror(sess, &x), y),
+    };
+
+    let z = op(plc, &x, &y);
+    Ok(FloatTensor::Host(z))
+}
+
 impl AddOp {
     pub(crate) fn float_host_kernel<S: Session, HostFloatT, MirroredT>(
         sess: &S,
@@ -192,19 +323,11 @@ impl AddOp {
     ) -> Result<FloatTensor<HostFloatT, MirroredT>>
     where
         HostPlacement: PlacementAdd<S, HostFloatT, HostFloatT, HostFloatT>,
+        HostPlacement: PlacementDemirror<S, MirroredT, HostFloatT>,
+        MirroredT: Placed<Placement = Mirrored3Placement>,
+        Mir3Tensor<HostFloatT>: Into<MirroredT>,
     {
-        let x = match x {
-            FloatTensor::Host(v) => v,
-            FloatTensor::Mirrored3(_v) => unimplemented!(),
-        };
-
-        let y = match y {
-            FloatTensor::Host(v) => v,
-            FloatTensor::Mirrored3(_v) => unimplemented!(),
-        };
-
-        let z = plc.add(sess, &x, &y);
-        Ok(FloatTensor::Host(z))
+        float_binary_host_kernel(sess, plc, x, y, |plc, x, y| plc.add(sess, x, y))
     }
 }
 
@@ -252,18 +375,11 @@ impl SubOp {
     ) -> Result<FloatTensor<HostFloatT, MirroredT>>
     where
         HostPlacement: PlacementSub<S, HostFloatT, HostFloatT, HostFloatT>,
+        HostPlacement: PlacementDemirror<S, MirroredT, HostFloatT>,
+        MirroredT: Placed<Placement = Mirrored3Placement>,
+        Mir3Tensor<HostFloatT>: Into<MirroredT>,
     {
-        let x = match x {
-            FloatTensor::Host(v) => v,
-            FloatTensor::Mirrored3(_v) => unimplemented!(),
-        };
-        let y = match y {
-            FloatTensor::Host(v) => v,
-            FloatTensor::Mirrored3(_v) => unimplemented!(),
-        };
-
-        let z = plc.sub(sess, &x, &y);
-        Ok(FloatTensor::Host(z))
+        float_binary_host_kernel(sess, plc, x, y, |plc, x, y| plc.sub(sess, x, y))
     }
 }
 
@@ -276,18 +392,11 @@ impl MulOp {
     ) -> Result<FloatTensor<HostFloatT, MirroredT>>
     where
         HostPlacement: PlacementMul<S, HostFloatT, HostFloatT, HostFloatT>,
+        HostPlacement: PlacementDemirror<S, MirroredT, HostFloatT>,
+        MirroredT: Placed<Placement = Mirrored3Placement>,
+        Mir3Tensor<HostFloatT>: Into<MirroredT>,
     {
-        let x = match x {
-            FloatTensor::Host(v) => v,
-            FloatTensor::Mirrored3(_v) => unimplemented!(),
-        };
-        let y = match y {
-            FloatTensor::Host(v) => v,
-            FloatTensor::Mirrored3(_v) => unimplemented!(),
-        };
-
-        let z = plc.mul(sess, &x, &y);
-        Ok(FloatTensor::Host(z))
+        float_binary_host_kernel(sess, plc, x, y, |plc, x, y| plc.mul(sess, x, y))
     }
 }
 
@@ -494,13 +603,47 @@ impl ConcatOp {
     ) -> Result<FloatTensor<HostFloatT, MirroredT>>
     where
         HostPlacement: PlacementConcatenate<S, HostFloatT, HostFloatT>,
+        HostPlacement: PlacementDemirror<S, MirroredT, HostFloatT>,
         HostFloatT: Clone,
+        MirroredT: Placed<Placement = Mirrored3Placement>,
+        Mir3Tensor<HostFloatT>: Into<MirroredT>,
     {
+        let mirrored: Vec<&MirroredT> = xs
+            .iter()
+            .filter_map(|x| match x {
+                FloatTensor::Mirrored3(x) => Some(x),
+                FloatTensor::Host(_) => None,
+            })
+            .collect();
+
+        // only concatenate replica by replica when every input is mirrored;
+        // a mix of host and mirrored inputs is demirrored onto `plc` instead
+        if !mirrored.is_empty() && mirrored.len() == xs.len() {
+            let mir = mirrored[0].placement()?;
+            let (player0, player1, player2) = mir.host_placements();
+
+            let concat_replica = |player: &HostPlacement| {
+                let replicas: Vec<HostFloatT> =
+                    mirrored.iter().map(|x| player.demirror(sess, *x)).collect();
+                player.concatenate(sess, axis, &replicas)
+            };
+            let z0 = concat_replica(&player0);
+            let z1 = concat_replica(&player1);
+            let z2 = concat_replica(&player2);
+
+            return Ok(FloatTensor::Mirrored3(
+                Mir3Tensor {
+                    values: [z0, z1, z2],
+                }
+                .into(),
+            ));
+        }
+
         let xs: Vec<HostFloatT> = xs
             .iter()
             .map(|x| match x {
                 FloatTensor::Host(x) => (*x).clone(),
-                FloatTensor::Mirrored3(_x) => unimplemented!(), // TODO(Dragos) fix this
+                FloatTensor::Mirrored3(x) => plc.demirror(sess, x),
             })
             .collect();
 
@@ -613,6 +756,30 @@ impl SqrtOp {
     }
 }
 
+impl ClipOp {
+    pub(crate) fn float_host_kernel<S: Session, HostFloatT, MirroredT>(
+        sess: &S,
+        plc: &HostPlacement,
+        min: f64,
+        max: f64,
+        x: FloatTensor<HostFloatT, MirroredT>,
+    ) -> Result<FloatTensor<HostFloatT, MirroredT>>
+    where
+        HostPlacement: PlacementClip<S, HostFloatT, HostFloatT>,
+    {
+        let x = match x {
+            FloatTensor::Host(v) => v,
+            FloatTensor::Mirrored3(_v) => {
+                return Err(Error::UnimplementedOperator(
+                    "ClipOp @ Mirrored3Placement".to_string(),
+                ))
+            }
+        };
+        let z = plc.clip(sess, min, max, &x);
+        Ok(FloatTensor::Host(z))
+    }
+}
+
 impl ExpOp {
     pub(crate) fn float_kernel<S: Session, HostFloatT, MirroredT>(
         sess: &S,
@@ -866,3 +1033,174 @@ impl SqueezeOp {
         Ok(FloatTensor::Host(z))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::execution::SyncSession;
+    use crate::host::FromRaw;
+    use ndarray::prelude::*;
+
+    #[test]
+    fn test_mean_mirrored() {
+        let alice = HostPlacement::from("alice");
+        let mir3 = Mirrored3Placement::from(["alice", "bob", "carole"]);
+
+        let sess = SyncSession::default();
+
+        let x: HostFloat64Tensor = alice.from_raw(array![[1.0, 2.0], [3.0, 6.0]]);
+        let x_mir: Mirrored3Float64 = mir3.mirror(&sess, &x);
+
+        for axis in [None, Some(0), Some(1)] {
+            let expected = alice.mean(&sess, axis, &x);
+
+            let z = MeanOp::float_host_kernel(
+                &sess,
+                &alice,
+                axis,
+                Float64Tensor::Mirrored3(x_mir.clone()),
+            )
+            .unwrap();
+            let z = match z {
+                FloatTensor::Mirrored3(v) => alice.demirror(&sess, &v),
+                FloatTensor::Host(_) => panic!("expected a mirrored result"),
+            };
+            assert_eq!(z, expected);
+        }
+    }
+
+    #[test]
+    fn test_binary_mirrored() {
+        let alice = HostPlacement::from("alice");
+        let mir3 = Mirrored3Placement::from(["alice", "bob", "carole"]);
+
+        let sess = SyncSession::default();
+
+        let x: HostFloat64Tensor = alice.from_raw(array![1.0, 2.5, -3.0]);
+        let y: HostFloat64Tensor = alice.from_raw(array![4.0, 0.5, 2.0]);
+        let x_mir: Mirrored3Float64 = mir3.mirror(&sess, &x);
+        let y_mir: Mirrored3Float64 = mir3.mirror(&sess, &y);
+
+        type Kernel =
+            fn(&SyncSession, &HostPlacement, Float64Tensor, Float64Tensor) -> Result<Float64Tensor>;
+
+        let kernels: [(Kernel, HostFloat64Tensor); 3] = [
+            (AddOp::float_host_kernel, alice.add(&sess, &x, &y)),
+            (SubOp::float_host_kernel, alice.sub(&sess, &x, &y)),
+            (MulOp::float_host_kernel, alice.mul(&sess, &x, &y)),
+        ];
+        for (kernel, expected) in kernels {
+            let z: Float64Tensor = kernel(
+                &sess,
+                &alice,
+                FloatTensor::Mirrored3(x_mir.clone()),
+                FloatTensor::Mirrored3(y_mir.clone()),
+            )
+            .unwrap();
+            match z {
+                FloatTensor::Mirrored3(v) => assert_eq!(alice.demirror(&sess, &v), expected),
+                FloatTensor::Host(_) => panic!("expected a mirrored result"),
+            }
+
+            let z: Float64Tensor = kernel(
+                &sess,
+                &alice,
+                FloatTensor::Mirrored3(x_mir.clone()),
+                FloatTensor::Host(y.clone()),
+            )
+            .unwrap();
+            assert_eq!(z, FloatTensor::Host(expected.clone()));
+
+            let z: Float64Tensor = kernel(
+                &sess,
+                &alice,
+                FloatTensor::Host(x.clone()),
+                FloatTensor::Mirrored3(y_mir.clone()),
+            )
+            .unwrap();
+            assert_eq!(z, FloatTensor::Host(expected));
+        }
+    }
+
+    #[test]
+    fn test_concat_mirrored() {
+        let alice = HostPlacement::from("alice");
+        let mir3 = Mirrored3Placement::from(["alice", "bob", "carole"]);
+
+        let sess = SyncSession::default();
+
+        let xs: Vec<HostFloat64Tensor> = vec![
+            alice.from_raw(array![1.0, 2.0]),
+            alice.from_raw(array![3.0, 4.0]),
+            alice.from_raw(array![5.0, 6.0]),
+        ];
+        let expected: HostFloat64Tensor = alice.from_raw(array![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+
+        let mirrored: Vec<Float64Tensor> = xs
+            .iter()
+            .map(|x| FloatTensor::Mirrored3(mir3.mirror(&sess, x)))
+            .collect();
+        let z = ConcatOp::float_host_kernel(&sess, &alice, 0, &mirrored).unwrap();
+        match z {
+            FloatTensor::Mirrored3(v) => assert_eq!(alice.demirror(&sess, &v), expected),
+            FloatTensor::Host(_) => panic!("expected a mirrored result"),
+        }
+
+        let mut mixed = mirrored;
+        mixed[1] = FloatTensor::Host(xs[1].clone());
+        let z = ConcatOp::float_host_kernel(&sess, &alice, 0, &mixed).unwrap();
+        assert_eq!(z, FloatTensor::Host(expected));
+    }
+
+    #[test]
+    fn test_cast_mirrored() {
+        let alice = HostPlacement::from("alice");
+        let mir3 = Mirrored3Placement::from(["alice", "bob", "carole"]);
+
+        let sess = SyncSession::default();
+
+        let x: HostFloat32Tensor = alice.from_raw(array![0.5f32, -2.0, 3.25]);
+        let x_mir: Mirrored3Float32 = mir3.mirror(&sess, &x);
+
+        let z: Float64Tensor =
+            CastOp::float_host_kernel(&sess, &alice, Float32Tensor::Mirrored3(x_mir)).unwrap();
+        let expected: HostFloat64Tensor = alice.from_raw(array![0.5, -2.0, 3.25]);
+        match z {
+            FloatTensor::Mirrored3(v) => assert_eq!(alice.demirror(&sess, &v), expected),
+            FloatTensor::Host(_) => panic!("expected a mirrored result"),
+        }
+    }
+
+    #[test]
+    fn test_softmax_sigmoid_mirrored() {
+        let alice = HostPlacement::from("alice");
+        let mir3 = Mirrored3Placement::from(["alice", "bob", "carole"]);
+
+        let sess = SyncSession::default();
+
+        let x: HostFloat64Tensor = alice.from_raw(array![[1.0, 2.0, 3.0], [-1.0, 0.0, 4.0]]);
+        let x_mir: Mirrored3Float64 = mir3.mirror(&sess, &x);
+
+        let z = SoftmaxOp::float_host_kernel(
+            &sess,
+            &alice,
+            1,
+            3,
+            Float64Tensor::Mirrored3(x_mir.clone()),
+        )
+        .unwrap();
+        let expected: HostFloat64Tensor = alice.softmax(&sess, 1, 3, &x);
+        match z {
+            FloatTensor::Mirrored3(v) => assert_eq!(alice.demirror(&sess, &v), expected),
+            FloatTensor::Host(_) => panic!("expected a mirrored result"),
+        }
+
+        let z =
+            SigmoidOp::float_host_kernel(&sess, &alice, Float64Tensor::Mirrored3(x_mir)).unwrap();
+        let expected: HostFloat64Tensor = alice.sigmoid(&sess, &x);
+        match z {
+            FloatTensor::Mirrored3(v) => assert_eq!(alice.demirror(&sess, &v), expected),
+            FloatTensor::Host(_) => panic!("expected a mirrored result"),
+        }
+    }
+}
@@ -0,0 +1,1043 @@
+//! Host kernels for similarity measures, scores, and losses.
+
+use super::ops::variance;
+use super::*;
+use crate::error::{Error, Result};
+use crate::execution::RuntimeSession;
+use ndarray::Zip;
+use num_traits::{Float, FromPrimitive};
+
+impl CosineSimilarityOp {
+    /// Cosine similarity between `x` and `y` along `axis`.
+    ///
+    /// One of the operands may be broadcast to the shape of the other, e.g. to compare
+    /// every row of a matrix with a single vector. Slices with zero norm yield zero.
+    pub(crate) fn host_kernel<S: RuntimeSession, T>(
+        _sess: &S,
+        plc: &HostPlacement,
+        axis: usize,
+        x: HostTensor<T>,
+        y: HostTensor<T>,
+    ) -> Result<HostTensor<T>>
+    where
+        T: LinalgScalar + Float,
+    {
+        let (x, y) = broadcast_views(&x.0, &y.0)?;
+        if axis >= x.ndim() {
+            return Err(Error::KernelError(format!(
+                "CosineSimilarityOp axis {} out of bounds for tensor of rank {}",
+                axis,
+                x.ndim()
+            )));
+        }
+
+        let axis = Axis(axis);
+        let dots = (&x * &y).sum_axis(axis);
+        let x_norms = x.mapv(|v| v * v).sum_axis(axis).mapv(|v| v.sqrt());
+        let y_norms = y.mapv(|v| v * v).sum_axis(axis).mapv(|v| v.sqrt());
+
+        let mut res = dots;
+        Zip::from(&mut res)
+            .and(&x_norms)
+            .and(&y_norms)
+            .for_each(|r, &x_norm, &y_norm| {
+                let norm = x_norm * y_norm;
+                *r = if norm == T::zero() {
+                    T::zero()
+                } else {
+                    *r / norm
+                };
+            });
+        Ok(HostTensor(res.into_shared(), plc.clone()))
+    }
+}
+
+impl JaccardOp {
+    /// Jaccard index `|x ∩ y| / |x ∪ y|` between sets encoded as bits along `axis`.
+    ///
+    /// Two empty sets have an index of one, or zero if `zero_if_empty` is set.
+    pub(crate) fn host_kernel<S: RuntimeSession>(
+        _sess: &S,
+        plc: &HostPlacement,
+        axis: usize,
+        zero_if_empty: bool,
+        x: HostBitTensor,
+        y: HostBitTensor,
+    ) -> Result<HostFloat64Tensor> {
+        if x.0.shape() != y.0.shape() {
+            return Err(Error::KernelError(format!(
+                "JaccardOp expected tensors of equal shape but got {:?} and {:?}",
+                x.0.shape(),
+                y.0.shape()
+            )));
+        }
+        if axis >= x.0.ndim() {
+            return Err(Error::KernelError(format!(
+                "JaccardOp axis {} out of bounds for tensor of rank {}",
+                axis,
+                x.0.ndim()
+            )));
+        }
+        let x =
+            x.0.into_array::<u64>()
+                .map_err(|e| Error::KernelError(e.to_string()))?;
+        let y =
+            y.0.into_array::<u64>()
+                .map_err(|e| Error::KernelError(e.to_string()))?;
+
+        let axis = Axis(axis);
+        let both = &x * &y;
+        let intersection = both.sum_axis(axis);
+        let union = (&(&x + &y) - &both).sum_axis(axis);
+
+        let empty = if zero_if_empty { 0.0 } else { 1.0 };
+        let res = Zip::from(&intersection).and(&union).map_collect(|&i, &u| {
+            if u == 0 {
+                empty
+            } else {
+                i as f64 / u as f64
+            }
+        });
+        Ok(HostTensor(res.into_shared(), plc.clone()))
+    }
+}
+
+/// Counts of true positives, false positives, false negatives, and true negatives.
+struct ConfusionCounts {
+    tp: u64,
+    fp: u64,
+    fn_: u64,
+    tn: u64,
+}
+
+fn confusion_counts(
+    op: &str,
+    prediction: HostBitTensor,
+    label: HostBitTensor,
+) -> Result<ConfusionCounts> {
+    if prediction.0.shape() != label.0.shape() {
+        return Err(Error::KernelError(format!(
+            "{} expected tensors of equal shape but got {:?} and {:?}",
+            op,
+            prediction.0.shape(),
+            label.0.shape()
+        )));
+    }
+    let prediction = prediction
+        .0
+        .into_array::<u8>()
+        .map_err(|e| Error::KernelError(e.to_string()))?;
+    let label = label
+        .0
+        .into_array::<u8>()
+        .map_err(|e| Error::KernelError(e.to_string()))?;
+
+    let mut counts = ConfusionCounts {
+        tp: 0,
+        fp: 0,
+        fn_: 0,
+        tn: 0,
+    };
+    Zip::from(&prediction)
+        .and(&label)
+        .for_each(|&p, &l| match (p != 0, l != 0) {
+            (true, true) => counts.tp += 1,
+            (true, false) => counts.fp += 1,
+            (false, true) => counts.fn_ += 1,
+            (false, false) => counts.tn += 1,
+        });
+    Ok(counts)
+}
+
+/// Ratio `num / den`, defined as zero when `den` is zero.
+fn ratio_or_zero(num: u64, den: u64) -> f64 {
+    if den == 0 {
+        0.0
+    } else {
+        num as f64 / den as f64
+    }
+}
+
+impl PrecisionRecallOp {
+    /// Precision and recall of `prediction` against `label`, returned as `[precision, recall]`.
+    ///
+    /// Either score is zero when its denominator is, e.g. precision when nothing was
+    /// predicted positive.
+    pub(crate) fn host_kernel<S: RuntimeSession>(
+        _sess: &S,
+        plc: &HostPlacement,
+        prediction: HostBitTensor,
+        label: HostBitTensor,
+    ) -> Result<HostFloat64Tensor> {
+        let c = confusion_counts("PrecisionRecallOp", prediction, label)?;
+        let precision = ratio_or_zero(c.tp, c.tp + c.fp);
+        let recall = ratio_or_zero(c.tp, c.tp + c.fn_);
+        let res = Array::from_vec(vec![precision, recall]).into_dyn();
+        Ok(HostTensor(res.into_shared(), plc.clone()))
+    }
+}
+
+impl F1Op {
+    /// Harmonic mean of precision and recall, returned as a scalar.
+    ///
+    /// The score is zero when both precision and recall are.
+    pub(crate) fn host_kernel<S: RuntimeSession>(
+        _sess: &S,
+        plc: &HostPlacement,
+        prediction: HostBitTensor,
+        label: HostBitTensor,
+    ) -> Result<HostFloat64Tensor> {
+        let c = confusion_counts("F1Op", prediction, label)?;
+        let precision = ratio_or_zero(c.tp, c.tp + c.fp);
+        let recall = ratio_or_zero(c.tp, c.tp + c.fn_);
+        let f1 = if precision + recall == 0.0 {
+            0.0
+        } else {
+            2.0 * precision * recall / (precision + recall)
+        };
+        Ok(HostTensor(arr0(f1).into_dyn().into_shared(), plc.clone()))
+    }
+}
+
+impl MccOp {
+    /// Matthews correlation coefficient, returned as a scalar.
+    ///
+    /// The coefficient is zero when any row or column of the confusion matrix is empty.
+    pub(crate) fn host_kernel<S: RuntimeSession>(
+        _sess: &S,
+        plc: &HostPlacement,
+        prediction: HostBitTensor,
+        label: HostBitTensor,
+    ) -> Result<HostFloat64Tensor> {
+        let c = confusion_counts("MccOp", prediction, label)?;
+        let (tp, fp, fn_, tn) = (c.tp as f64, c.fp as f64, c.fn_ as f64, c.tn as f64);
+        let den = (tp + fp) * (tp + fn_) * (tn + fp) * (tn + fn_);
+        let mcc = if den == 0.0 {
+            0.0
+        } else {
+            (tp * tn - fp * fn_) / den.sqrt()
+        };
+        Ok(HostTensor(arr0(mcc).into_dyn().into_shared(), plc.clone()))
+    }
+}
+
+impl RocAucOp {
+    /// Area under the ROC curve of `score` against `label`, returned as a scalar.
+    ///
+    /// Computed from the rank sum of the positive samples (Mann-Whitney U), with tied
+    /// scores receiving their average rank. Both classes must be present.
+    pub(crate) fn host_kernel<S: RuntimeSession, T>(
+        _sess: &S,
+        plc: &HostPlacement,
+        score: HostTensor<T>,
+        label: HostBitTensor,
+    ) -> Result<HostFloat64Tensor>
+    where
+        T: LinalgScalar + PartialOrd,
+    {
+        if score.0.shape() != label.0.shape() {
+            return Err(Error::KernelError(format!(
+                "RocAucOp expected tensors of equal shape but got {:?} and {:?}",
+                score.0.shape(),
+                label.0.shape()
+            )));
+        }
+        let label = label
+            .0
+            .into_array::<u8>()
+            .map_err(|e| Error::KernelError(e.to_string()))?;
+        let scores: Vec<T> = score.0.iter().cloned().collect();
+        let ranks = average_ranks(&scores);
+
+        let mut n_pos = 0;
+        let mut rank_sum = 0.0;
+        for (rank, l) in ranks.iter().zip(label.iter()) {
+            if *l != 0 {
+                n_pos += 1;
+                rank_sum += rank;
+            }
+        }
+        let n_neg = scores.len() - n_pos;
+        if n_pos == 0 || n_neg == 0 {
+            return Err(Error::KernelError(
+                "RocAucOp requires both positive and negative labels".to_string(),
+            ));
+        }
+
+        let (n_pos, n_neg) = (n_pos as f64, n_neg as f64);
+        let auc = (rank_sum - n_pos * (n_pos + 1.0) / 2.0) / (n_pos * n_neg);
+        Ok(HostTensor(arr0(auc).into_dyn().into_shared(), plc.clone()))
+    }
+}
+
+/// Checks that `prob` is a matrix with one row per entry of `label`, and that every
+/// label is a valid column index.
+fn check_class_labels<T>(op: &str, prob: &ArcArrayD<T>, label: &ArcArrayD<u64>) -> Result<()> {
+    if prob.ndim() != 2 || label.ndim() != 1 || prob.shape()[0] != label.len() {
+        return Err(Error::KernelError(format!(
+            "{} expected probabilities of shape [n, k] and labels of shape [n] but got {:?} and {:?}",
+            op,
+            prob.shape(),
+            label.shape()
+        )));
+    }
+    let num_classes = prob.shape()[1] as u64;
+    if let Some(l) = label.iter().find(|&&l| l >= num_classes) {
+        return Err(Error::KernelError(format!(
+            "{} label {} out of range for {} classes",
+            op, l, num_classes
+        )));
+    }
+    Ok(())
+}
+
+impl BrierScoreOp {
+    /// Mean over samples of the squared distance between `prob` and the one-hot `label`.
+    ///
+    /// Rows of `prob` hold the predicted class probabilities for each sample, and the
+    /// score is summed over classes before averaging over samples.
+    pub(crate) fn host_kernel<S: RuntimeSession, T>(
+        _sess: &S,
+        plc: &HostPlacement,
+        prob: HostTensor<T>,
+        label: HostUint64Tensor,
+    ) -> Result<HostTensor<T>>
+    where
+        T: LinalgScalar + Float,
+    {
+        check_class_labels("BrierScoreOp", &prob.0, &label.0)?;
+        let mut total = T::zero();
+        for (row, &l) in prob.0.outer_iter().zip(label.0.iter()) {
+            for (k, &p) in row.iter().enumerate() {
+                let target = if k as u64 == l { T::one() } else { T::zero() };
+                total = total + (p - target) * (p - target);
+            }
+        }
+        let n = T::from(label.0.len()).unwrap();
+        let score = if label.0.is_empty() {
+            T::zero()
+        } else {
+            total / n
+        };
+        Ok(HostTensor(
+            arr0(score).into_dyn().into_shared(),
+            plc.clone(),
+        ))
+    }
+}
+
+impl LogLossOp {
+    /// Mean negative log-likelihood of `label` under the class probabilities in `prob`.
+    ///
+    /// Probabilities are clamped to `[eps, 1 - eps]` so that the loss stays finite.
+    pub(crate) fn host_kernel<S: RuntimeSession, T>(
+        _sess: &S,
+        plc: &HostPlacement,
+        eps: f64,
+        prob: HostTensor<T>,
+        label: HostUint64Tensor,
+    ) -> Result<HostTensor<T>>
+    where
+        T: LinalgScalar + Float,
+    {
+        if !(0.0..0.5).contains(&eps) {
+            return Err(Error::KernelError(format!(
+                "LogLossOp expected eps in [0, 0.5) but got {}",
+                eps
+            )));
+        }
+        check_class_labels("LogLossOp", &prob.0, &label.0)?;
+        let lo = T::from(eps).unwrap();
+        let hi = T::one() - lo;
+
+        let mut total = T::zero();
+        for (row, &l) in prob.0.outer_iter().zip(label.0.iter()) {
+            let p = row[[l as usize]].max(lo).min(hi);
+            total = total - p.ln();
+        }
+        let loss = if label.0.is_empty() {
+            T::zero()
+        } else {
+            total / T::from(label.0.len()).unwrap()
+        };
+        Ok(HostTensor(arr0(loss).into_dyn().into_shared(), plc.clone()))
+    }
+}
+
+impl CalibrationOp {
+    /// Reliability curve of the positive-class probabilities `prob` against `label`.
+    ///
+    /// Probabilities are assigned to `bins` equal-width bins over `[0, 1]`. The result has
+    /// shape `[2, bins]`, holding the mean predicted probability of each bin in the first
+    /// row and the fraction of positive labels in the second; empty bins are zero.
+    pub(crate) fn host_kernel<S: RuntimeSession, T>(
+        _sess: &S,
+        plc: &HostPlacement,
+        bins: usize,
+        prob: HostTensor<T>,
+        label: HostBitTensor,
+    ) -> Result<HostTensor<T>>
+    where
+        T: LinalgScalar + Float,
+    {
+        if bins == 0 {
+            return Err(Error::KernelError(
+                "CalibrationOp requires at least one bin".to_string(),
+            ));
+        }
+        if prob.0.shape() != label.0.shape() {
+            return Err(Error::KernelError(format!(
+                "CalibrationOp expected tensors of equal shape but got {:?} and {:?}",
+                prob.0.shape(),
+                label.0.shape()
+            )));
+        }
+        let label = label
+            .0
+            .into_array::<u8>()
+            .map_err(|e| Error::KernelError(e.to_string()))?;
+
+        let num_bins = T::from(bins).unwrap();
+        let mut prob_sums = vec![T::zero(); bins];
+        let mut positives = vec![T::zero(); bins];
+        let mut counts = vec![T::zero(); bins];
+        for (&p, &l) in prob.0.iter().zip(label.iter()) {
+            if !(T::zero()..=T::one()).contains(&p) {
+                return Err(Error::KernelError(format!(
+                    "CalibrationOp expected probabilities in [0, 1] but got {}",
+                    p.to_f64().unwrap_or(f64::NAN)
+                )));
+            }
+            let bin = (p * num_bins).floor().to_usize().unwrap().min(bins - 1);
+            prob_sums[bin] = prob_sums[bin] + p;
+            if l != 0 {
+                positives[bin] = positives[bin] + T::one();
+            }
+            counts[bin] = counts[bin] + T::one();
+        }
+
+        let mut res = Array2::zeros((2, bins));
+        let bin_stats = prob_sums.iter().zip(&positives).zip(&counts);
+        for (bin, ((&prob_sum, &positive), &count)) in bin_stats.enumerate() {
+            if count > T::zero() {
+                res[[0, bin]] = prob_sum / count;
+                res[[1, bin]] = positive / count;
+            }
+        }
+        Ok(HostTensor(res.into_dyn().into_shared(), plc.clone()))
+    }
+}
+
+impl WeightedCrossEntropyOp {
+    /// Mean over samples of the negative log-likelihood of `label`, scaled by the weight of
+    /// the sample's class.
+    ///
+    /// Probabilities are not clamped, so a zero probability for the true class gives an
+    /// infinite loss.
+    pub(crate) fn host_kernel<S: RuntimeSession, T>(
+        _sess: &S,
+        plc: &HostPlacement,
+        weights: Vec<f64>,
+        prob: HostTensor<T>,
+        label: HostUint64Tensor,
+    ) -> Result<HostTensor<T>>
+    where
+        T: LinalgScalar + Float,
+    {
+        check_class_labels("WeightedCrossEntropyOp", &prob.0, &label.0)?;
+        if weights.len() != prob.0.shape()[1] {
+            return Err(Error::KernelError(format!(
+                "WeightedCrossEntropyOp expected {} class weights but got {}",
+                prob.0.shape()[1],
+                weights.len()
+            )));
+        }
+        let weights: Vec<T> = weights.iter().map(|&w| T::from(w).unwrap()).collect();
+
+        let mut total = T::zero();
+        for (row, &l) in prob.0.outer_iter().zip(label.0.iter()) {
+            let l = l as usize;
+            total = total - weights[l] * row[[l]].ln();
+        }
+        let loss = if label.0.is_empty() {
+            T::zero()
+        } else {
+            total / T::from(label.0.len()).unwrap()
+        };
+        Ok(HostTensor(arr0(loss).into_dyn().into_shared(), plc.clone()))
+    }
+}
+
+impl FocalLossOp {
+    /// Mean over samples of `-(1 - p)^gamma * log(p)`, where `p` is the probability of the
+    /// true class.
+    ///
+    /// Probabilities are clamped to `[eps, 1 - eps]` as in `LogLossOp`, which this reduces
+    /// to for `gamma = 0`.
+    pub(crate) fn host_kernel<S: RuntimeSession, T>(
+        _sess: &S,
+        plc: &HostPlacement,
+        gamma: f64,
+        eps: f64,
+        prob: HostTensor<T>,
+        label: HostUint64Tensor,
+    ) -> Result<HostTensor<T>>
+    where
+        T: LinalgScalar + Float,
+    {
+        if gamma < 0.0 {
+            return Err(Error::KernelError(format!(
+                "FocalLossOp expected non-negative gamma but got {}",
+                gamma
+            )));
+        }
+        if !(0.0..0.5).contains(&eps) {
+            return Err(Error::KernelError(format!(
+                "FocalLossOp expected eps in [0, 0.5) but got {}",
+                eps
+            )));
+        }
+        check_class_labels("FocalLossOp", &prob.0, &label.0)?;
+        let gamma = T::from(gamma).unwrap();
+        let lo = T::from(eps).unwrap();
+        let hi = T::one() - lo;
+
+        let mut total = T::zero();
+        for (row, &l) in prob.0.outer_iter().zip(label.0.iter()) {
+            let p = row[[l as usize]].max(lo).min(hi);
+            total = total - (T::one() - p).powf(gamma) * p.ln();
+        }
+        let loss = if label.0.is_empty() {
+            T::zero()
+        } else {
+            total / T::from(label.0.len()).unwrap()
+        };
+        Ok(HostTensor(arr0(loss).into_dyn().into_shared(), plc.clone()))
+    }
+}
+
+impl HuberLossOp {
+    /// Mean Huber loss between `prediction` and `target` along `axis`.
+    ///
+    /// Residuals `r` with `|r| <= delta` contribute `r^2 / 2`, larger ones contribute
+    /// `delta * (|r| - delta / 2)`. One of the operands may be broadcast to the other.
+    pub(crate) fn host_kernel<S: RuntimeSession, T>(
+        _sess: &S,
+        plc: &HostPlacement,
+        axis: usize,
+        delta: f64,
+        prediction: HostTensor<T>,
+        target: HostTensor<T>,
+    ) -> Result<HostTensor<T>>
+    where
+        T: LinalgScalar + Float + FromPrimitive,
+    {
+        if delta <= 0.0 {
+            return Err(Error::KernelError(format!(
+                "HuberLossOp expected positive delta but got {}",
+                delta
+            )));
+        }
+        let (prediction, target) = broadcast_views(&prediction.0, &target.0)?;
+        if axis >= prediction.ndim() {
+            return Err(Error::KernelError(format!(
+                "HuberLossOp axis {} out of bounds for tensor of rank {}",
+                axis,
+                prediction.ndim()
+            )));
+        }
+
+        let delta = T::from(delta).unwrap();
+        let half = T::from(0.5).unwrap();
+        let losses = Zip::from(&prediction).and(&target).map_collect(|&p, &t| {
+            let r = (p - t).abs();
+            if r <= delta {
+                half * r * r
+            } else {
+                delta * (r - half * delta)
+            }
+        });
+        let res = losses.mean_axis(Axis(axis)).ok_or_else(|| {
+            Error::KernelError("HuberLossOp cannot reduce over an empty axis".to_string())
+        })?;
+        Ok(HostTensor(res.into_shared(), plc.clone()))
+    }
+}
+
+/// Mean of `f(prediction - target)` along `axis`, or over all elements.
+fn mean_error<T, F>(
+    op: &str,
+    prediction: &ArcArrayD<T>,
+    target: &ArcArrayD<T>,
+    axis: Option<usize>,
+    f: F,
+) -> Result<ArrayD<T>>
+where
+    T: LinalgScalar + Float + FromPrimitive,
+    F: Fn(T) -> T,
+{
+    if prediction.shape() != target.shape() {
+        return Err(Error::KernelError(format!(
+            "{} expected tensors of equal shape but got {:?} and {:?}",
+            op,
+            prediction.shape(),
+            target.shape()
+        )));
+    }
+    let errors = Zip::from(prediction)
+        .and(target)
+        .map_collect(|&p, &t| f(p - t));
+    let mean = match axis {
+        Some(axis) => {
+            if axis >= errors.ndim() {
+                return Err(Error::KernelError(format!(
+                    "{} axis {} out of bounds for tensor of rank {}",
+                    op,
+                    axis,
+                    errors.ndim()
+                )));
+            }
+            errors.mean_axis(Axis(axis))
+        }
+        None => errors.mean().map(|m| arr0(m).into_dyn()),
+    };
+    mean.ok_or_else(|| Error::KernelError(format!("{} cannot reduce over zero elements", op)))
+}
+
+impl MseOp {
+    pub(crate) fn host_kernel<S: RuntimeSession, T>(
+        _sess: &S,
+        plc: &HostPlacement,
+        axis: Option<usize>,
+        prediction: HostTensor<T>,
+        target: HostTensor<T>,
+    ) -> Result<HostTensor<T>>
+    where
+        T: LinalgScalar + Float + FromPrimitive,
+    {
+        let res = mean_error("MseOp", &prediction.0, &target.0, axis, |r| r * r)?;
+        Ok(HostTensor(res.into_shared(), plc.clone()))
+    }
+}
+
+impl MaeOp {
+    pub(crate) fn host_kernel<S: RuntimeSession, T>(
+        _sess: &S,
+        plc: &HostPlacement,
+        axis: Option<usize>,
+        prediction: HostTensor<T>,
+        target: HostTensor<T>,
+    ) -> Result<HostTensor<T>>
+    where
+        T: LinalgScalar + Float + FromPrimitive,
+    {
+        let res = mean_error("MaeOp", &prediction.0, &target.0, axis, |r| r.abs())?;
+        Ok(HostTensor(res.into_shared(), plc.clone()))
+    }
+}
+
+impl R2Op {
+    /// Coefficient of determination `1 - SS_res / SS_tot` over all elements.
+    ///
+    /// By convention the score is zero when `target` is constant, i.e. `SS_tot` is zero.
+    pub(crate) fn host_kernel<S: RuntimeSession, T>(
+        _sess: &S,
+        plc: &HostPlacement,
+        prediction: HostTensor<T>,
+        target: HostTensor<T>,
+    ) -> Result<HostTensor<T>>
+    where
+        T: LinalgScalar + Float + FromPrimitive,
+    {
+        let mse = mean_error("R2Op", &prediction.0, &target.0, None, |r| r * r)?;
+        let n = T::from(target.0.len()).unwrap();
+        let ss_res = mse.sum() * n;
+
+        let target_mean = target.0.mean().unwrap();
+        let ss_tot = target.0.fold(T::zero(), |acc, &t| {
+            acc + (t - target_mean) * (t - target_mean)
+        });
+
+        let r2 = if ss_tot == T::zero() {
+            T::zero()
+        } else {
+            T::one() - ss_res / ss_tot
+        };
+        Ok(HostTensor(arr0(r2).into_dyn().into_shared(), plc.clone()))
+    }
+}
+
+impl ExplainedVarianceOp {
+    /// Explained variance `1 - Var(target - prediction) / Var(target)` over all elements.
+    ///
+    /// When `target` is constant the score is one for perfect predictions and zero
+    /// otherwise, matching scikit-learn.
+    pub(crate) fn host_kernel<S: RuntimeSession, T>(
+        _sess: &S,
+        plc: &HostPlacement,
+        prediction: HostTensor<T>,
+        target: HostTensor<T>,
+    ) -> Result<HostTensor<T>>
+    where
+        T: LinalgScalar + Float + FromPrimitive,
+    {
+        if prediction.0.shape() != target.0.shape() {
+            return Err(Error::KernelError(format!(
+                "ExplainedVarianceOp expected tensors of equal shape but got {:?} and {:?}",
+                prediction.0.shape(),
+                target.0.shape()
+            )));
+        }
+        let residual = (&target.0 - &prediction.0).into_shared();
+        let residual_var = variance(&residual, None, 0)?.sum();
+        let target_var = variance(&target.0, None, 0)?.sum();
+
+        let score = if target_var != T::zero() {
+            T::one() - residual_var / target_var
+        } else if residual_var == T::zero() {
+            T::one()
+        } else {
+            T::zero()
+        };
+        Ok(HostTensor(
+            arr0(score).into_dyn().into_shared(),
+            plc.clone(),
+        ))
+    }
+}
+
+#[cfg(feature = "sync_execute")]
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cosine_similarity() {
+        let (sess, plc) = test_session();
+
+        let raw_x = array![[1.0, 2.0, 3.0], [0.0, 0.0, 0.0], [-1.0, 0.5, 2.0]];
+        let raw_y = array![[2.0, 4.0, 6.0], [1.0, 1.0, 1.0], [3.0, -2.0, 1.0]];
+
+        // naive reference implementation
+        let expected: Vec<f64> = raw_x
+            .outer_iter()
+            .zip(raw_y.outer_iter())
+            .map(|(a, b)| {
+                let dot: f64 = a.iter().zip(b.iter()).map(|(u, v)| u * v).sum();
+                let norm_a: f64 = a.iter().map(|u| u * u).sum::<f64>().sqrt();
+                let norm_b: f64 = b.iter().map(|v| v * v).sum::<f64>().sqrt();
+                if norm_a * norm_b == 0.0 {
+                    0.0
+                } else {
+                    dot / (norm_a * norm_b)
+                }
+            })
+            .collect();
+
+        let x: HostFloat64Tensor = plc.from_raw(raw_x);
+        let y: HostFloat64Tensor = plc.from_raw(raw_y);
+        let z = plc.cosine_similarity(&sess, 1, &x, &y);
+        assert_close(&z, &expected);
+        assert_close(&z, &[1.0, 0.0, -2.0 / (5.25f64.sqrt() * 14f64.sqrt())]);
+    }
+
+    #[test]
+    fn test_cosine_similarity_matrix_vector() {
+        let (sess, plc) = test_session();
+
+        let x: HostFloat64Tensor = plc.from_raw(array![[3.0, 4.0], [4.0, 3.0], [-3.0, -4.0]]);
+        let y: HostFloat64Tensor = plc.from_raw(array![3.0, 4.0]);
+        let z = plc.cosine_similarity(&sess, 1, &x, &y);
+        assert_close(&z, &[1.0, 0.96, -1.0]);
+    }
+
+    #[test]
+    fn test_jaccard() {
+        let (sess, plc) = test_session();
+
+        let x: HostBitTensor = plc.from_raw(array![[1, 1, 0, 0], [1, 0, 1, 0], [0, 0, 0, 0]]);
+        let y: HostBitTensor = plc.from_raw(array![[1, 0, 1, 0], [1, 0, 1, 0], [0, 0, 0, 0]]);
+
+        let z = plc.jaccard(&sess, 1, false, &x, &y);
+        assert_close(&z, &[1.0 / 3.0, 1.0, 1.0]);
+
+        let z = plc.jaccard(&sess, 1, true, &x, &y);
+        assert_close(&z, &[1.0 / 3.0, 1.0, 0.0]);
+
+        let z = plc.jaccard(&sess, 0, false, &x, &y);
+        assert_close(&z, &[1.0, 0.0, 0.5, 1.0]);
+    }
+
+    #[test]
+    fn test_precision_recall() {
+        let (sess, plc) = test_session();
+
+        // tp = 2, fp = 1, fn = 2
+        let prediction: HostBitTensor = plc.from_raw(array![1, 1, 1, 0, 0, 0]);
+        let label: HostBitTensor = plc.from_raw(array![1, 1, 0, 1, 1, 0]);
+        let z = plc.precision_recall(&sess, &prediction, &label);
+        assert_close(&z, &[2.0 / 3.0, 0.5]);
+
+        // nothing predicted positive
+        let prediction: HostBitTensor = plc.from_raw(array![0, 0, 0, 0]);
+        let label: HostBitTensor = plc.from_raw(array![1, 0, 1, 0]);
+        let z = plc.precision_recall(&sess, &prediction, &label);
+        assert_close(&z, &[0.0, 0.0]);
+    }
+
+    #[test]
+    fn test_f1() {
+        let (sess, plc) = test_session();
+
+        let prediction: HostBitTensor = plc.from_raw(array![1, 1, 1, 0, 0, 0]);
+        let label: HostBitTensor = plc.from_raw(array![1, 1, 0, 1, 1, 0]);
+        let (p, r) = (2.0 / 3.0, 0.5);
+        let z = plc.f1(&sess, &prediction, &label);
+        assert_eq!(z.0.ndim(), 0);
+        assert_close(&z, &[2.0 * p * r / (p + r)]);
+
+        let prediction: HostBitTensor = plc.from_raw(array![0, 0, 1]);
+        let label: HostBitTensor = plc.from_raw(array![1, 0, 0]);
+        let z = plc.f1(&sess, &prediction, &label);
+        assert_close(&z, &[0.0]);
+    }
+
+    #[test]
+    fn test_mcc() {
+        let (sess, plc) = test_session();
+
+        // tp = 2, fp = 1, fn = 2, tn = 3
+        let prediction: HostBitTensor = plc.from_raw(array![1, 1, 1, 0, 0, 0, 0, 0]);
+        let label: HostBitTensor = plc.from_raw(array![1, 1, 0, 1, 1, 0, 0, 0]);
+        let z = plc.mcc(&sess, &prediction, &label);
+        assert_close(&z, &[4.0 / 240f64.sqrt()]);
+
+        let z = plc.mcc(&sess, &label, &label);
+        assert_close(&z, &[1.0]);
+
+        // all-negative predictions leave a column of the confusion matrix empty
+        let prediction: HostBitTensor = plc.from_raw(array![0, 0, 0, 0]);
+        let label: HostBitTensor = plc.from_raw(array![1, 0, 1, 0]);
+        let z = plc.mcc(&sess, &prediction, &label);
+        assert_close(&z, &[0.0]);
+    }
+
+    #[test]
+    fn test_roc_auc() {
+        let (sess, plc) = test_session();
+
+        // sklearn.metrics.roc_auc_score([0, 0, 1, 1], [0.1, 0.4, 0.35, 0.8]) == 0.75
+        let score: HostFloat64Tensor = plc.from_raw(array![0.1, 0.4, 0.35, 0.8]);
+        let label: HostBitTensor = plc.from_raw(array![0, 0, 1, 1]);
+        let z = plc.roc_auc(&sess, &score, &label);
+        assert_close(&z, &[0.75]);
+
+        // sklearn.metrics.roc_auc_score([0, 1, 0, 1], [0.5, 0.5, 0.2, 0.9]) == 0.875
+        let score: HostFloat64Tensor = plc.from_raw(array![0.5, 0.5, 0.2, 0.9]);
+        let label: HostBitTensor = plc.from_raw(array![0, 1, 0, 1]);
+        let z = plc.roc_auc(&sess, &score, &label);
+        assert_close(&z, &[0.875]);
+    }
+
+    #[test]
+    fn test_roc_auc_single_class() {
+        let (sess, plc) = test_session();
+
+        let score: HostFloat64Tensor = plc.from_raw(array![0.1, 0.4]);
+        let label: HostBitTensor = plc.from_raw(array![1, 1]);
+        let res = RocAucOp::host_kernel(&sess, &plc, score, label);
+        assert!(matches!(res, Err(Error::KernelError(_))));
+    }
+
+    #[test]
+    fn test_brier_score() {
+        let (sess, plc) = test_session();
+
+        let prob: HostFloat64Tensor =
+            plc.from_raw(array![[0.7, 0.2, 0.1], [0.1, 0.8, 0.1], [0.5, 0.25, 0.25]]);
+        let label: HostUint64Tensor = plc.from_raw(array![0, 1, 2]);
+        let z = plc.brier_score(&sess, &prob, &label);
+        // (0.14 + 0.06 + 0.875) / 3
+        assert_close(&z, &[1.075 / 3.0]);
+    }
+
+    #[test]
+    fn test_brier_score_invalid_label() {
+        let (sess, plc) = test_session();
+
+        let prob: HostFloat64Tensor = plc.from_raw(array![[0.5, 0.5]]);
+        let label: HostUint64Tensor = plc.from_raw(array![2]);
+        let res = BrierScoreOp::host_kernel(&sess, &plc, prob, label);
+        assert!(matches!(res, Err(Error::KernelError(_))));
+    }
+
+    #[test]
+    fn test_log_loss() {
+        let (sess, plc) = test_session();
+
+        let prob: HostFloat64Tensor = plc.from_raw(array![[0.8, 0.2], [0.4, 0.6], [0.9, 0.1]]);
+        let label: HostUint64Tensor = plc.from_raw(array![0, 1, 0]);
+        let z = plc.log_loss(&sess, 1e-15, &prob, &label);
+        let expected = -(0.8f64.ln() + 0.6f64.ln() + 0.9f64.ln()) / 3.0;
+        assert_close(&z, &[expected]);
+    }
+
+    #[test]
+    fn test_log_loss_clamps_zero_probability() {
+        let (sess, plc) = test_session();
+
+        let prob: HostFloat64Tensor = plc.from_raw(array![[1.0, 0.0], [0.0, 1.0]]);
+        let label: HostUint64Tensor = plc.from_raw(array![1, 1]);
+        let z = plc.log_loss(&sess, 1e-7, &prob, &label);
+        assert!(z.0.iter().all(|v| v.is_finite()));
+        assert_close(&z, &[-(1e-7f64.ln() + (1.0 - 1e-7f64).ln()) / 2.0]);
+    }
+
+    #[test]
+    fn test_calibration() {
+        let (sess, plc) = test_session();
+
+        let prob: HostFloat64Tensor = plc.from_raw(array![0.1, 0.2, 0.3, 0.8, 0.9, 1.0]);
+        let label: HostBitTensor = plc.from_raw(array![0, 0, 1, 1, 0, 1]);
+        let z = plc.calibration(&sess, 3, &prob, &label);
+        assert_eq!(z.0.shape(), &[2, 3]);
+        assert_close(&z, &[0.2, 0.0, 0.9, 1.0 / 3.0, 0.0, 2.0 / 3.0]);
+    }
+
+    #[test]
+    fn test_weighted_cross_entropy() {
+        let (sess, plc) = test_session();
+
+        let prob: HostFloat64Tensor = plc.from_raw(array![[0.8, 0.2], [0.4, 0.6], [0.7, 0.3]]);
+        let label: HostUint64Tensor = plc.from_raw(array![0, 1, 1]);
+
+        let unweighted = plc.weighted_cross_entropy(&sess, vec![1.0, 1.0], &prob, &label);
+        let expected = -(0.8f64.ln() + 0.6f64.ln() + 0.3f64.ln()) / 3.0;
+        assert_close(&unweighted, &[expected]);
+        assert_close(
+            &unweighted,
+            plc.log_loss(&sess, 0.0, &prob, &label)
+                .0
+                .as_slice()
+                .unwrap(),
+        );
+
+        // upweighting class 1 increases the loss by exactly its extra contribution
+        let weighted = plc.weighted_cross_entropy(&sess, vec![1.0, 3.0], &prob, &label);
+        let expected = expected - 2.0 * (0.6f64.ln() + 0.3f64.ln()) / 3.0;
+        assert_close(&weighted, &[expected]);
+        assert!(weighted.0.sum() > unweighted.0.sum());
+    }
+
+    #[test]
+    fn test_focal_loss() {
+        let (sess, plc) = test_session();
+
+        let label: HostUint64Tensor = plc.from_raw(array![0]);
+        let easy: HostFloat64Tensor = plc.from_raw(array![[0.9, 0.1]]);
+        let hard: HostFloat64Tensor = plc.from_raw(array![[0.3, 0.7]]);
+
+        // gamma = 0 is plain cross-entropy
+        let ce = plc.log_loss(&sess, 1e-7, &easy, &label);
+        let z = plc.focal_loss(&sess, 0.0, 1e-7, &easy, &label);
+        assert_close(&z, ce.0.as_slice().unwrap());
+
+        let easy_ce = plc.focal_loss(&sess, 0.0, 1e-7, &easy, &label).0.sum();
+        let hard_ce = plc.focal_loss(&sess, 0.0, 1e-7, &hard, &label).0.sum();
+        let easy_focal = plc.focal_loss(&sess, 2.0, 1e-7, &easy, &label).0.sum();
+        let hard_focal = plc.focal_loss(&sess, 2.0, 1e-7, &hard, &label).0.sum();
+        assert!((easy_focal - 0.01 * easy_ce).abs() < 1e-12);
+        assert!((hard_focal - 0.49 * hard_ce).abs() < 1e-12);
+        // the well-classified example is downweighted much more than the hard one
+        assert!(easy_focal / easy_ce < hard_focal / hard_ce);
+    }
+
+    #[test]
+    fn test_huber_loss() {
+        let (sess, plc) = test_session();
+
+        let target: HostFloat64Tensor = plc.from_raw(array![[0.0, 0.0], [0.0, 0.0]]);
+
+        // small residuals give half the squared error
+        let prediction: HostFloat64Tensor = plc.from_raw(array![[0.2, -0.4], [0.6, 1.0]]);
+        let z = plc.huber_loss(&sess, 1, 1.0, &prediction, &target);
+        assert_close(&z, &[(0.04 + 0.16) / 4.0, (0.36 + 1.0) / 4.0]);
+
+        // large residuals grow linearly with the absolute error
+        let prediction: HostFloat64Tensor = plc.from_raw(array![[3.0, -5.0], [10.0, -2.0]]);
+        let z = plc.huber_loss(&sess, 1, 1.0, &prediction, &target);
+        assert_close(&z, &[(2.5 + 4.5) / 2.0, (9.5 + 1.5) / 2.0]);
+
+        let z = plc.huber_loss(&sess, 0, 1.0, &prediction, &target);
+        assert_close(&z, &[(2.5 + 9.5) / 2.0, (4.5 + 1.5) / 2.0]);
+    }
+
+    #[test]
+    fn test_mse_mae() {
+        let (sess, plc) = test_session();
+
+        let prediction: HostFloat64Tensor = plc.from_raw(array![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        let target: HostFloat64Tensor = plc.from_raw(array![[1.0, 0.0, 4.0], [4.0, 8.0, 6.0]]);
+
+        let z = plc.mse(&sess, None, &prediction, &target);
+        assert_eq!(z.0.ndim(), 0);
+        assert_close(&z, &[14.0 / 6.0]);
+        let z = plc.mse(&sess, Some(0), &prediction, &target);
+        assert_close(&z, &[0.0, 6.5, 0.5]);
+        let z = plc.mse(&sess, Some(1), &prediction, &target);
+        assert_close(&z, &[5.0 / 3.0, 3.0]);
+
+        let z = plc.mae(&sess, None, &prediction, &target);
+        assert_close(&z, &[1.0]);
+        let z = plc.mae(&sess, Some(0), &prediction, &target);
+        assert_close(&z, &[0.0, 2.5, 0.5]);
+        let z = plc.mae(&sess, Some(1), &prediction, &target);
+        assert_close(&z, &[1.0, 1.0]);
+    }
+
+    #[test]
+    fn test_mse_errors() {
+        let (sess, plc) = test_session();
+
+        let prediction: HostFloat64Tensor = plc.from_raw(array![1.0, 2.0]);
+        let target: HostFloat64Tensor = plc.from_raw(array![1.0, 2.0, 3.0]);
+        let res = MseOp::host_kernel(&sess, &plc, None, prediction, target);
+        assert!(matches!(res, Err(Error::KernelError(_))));
+
+        let empty: HostFloat64Tensor = plc.from_raw(Array1::<f64>::zeros(0));
+        let res = MaeOp::host_kernel(&sess, &plc, None, empty.clone(), empty);
+        assert!(matches!(res, Err(Error::KernelError(_))));
+    }
+
+    #[test]
+    fn test_r2() {
+        let (sess, plc) = test_session();
+
+        // sklearn.metrics.r2_score([3, -0.5, 2, 7], [2.5, 0.0, 2, 8]) == 0.9486081370449679
+        let target: HostFloat64Tensor = plc.from_raw(array![3.0, -0.5, 2.0, 7.0]);
+        let prediction: HostFloat64Tensor = plc.from_raw(array![2.5, 0.0, 2.0, 8.0]);
+        let z = plc.r2(&sess, &prediction, &target);
+        assert_close(&z, &[0.9486081370449679]);
+
+        let target: HostFloat64Tensor = plc.from_raw(array![1.0, 1.0, 1.0]);
+        let z = plc.r2(&sess, &target, &target);
+        assert_close(&z, &[0.0]);
+    }
+
+    #[test]
+    fn test_explained_variance() {
+        let (sess, plc) = test_session();
+
+        // sklearn.metrics.explained_variance_score([3, -0.5, 2, 7], [2.5, 0.0, 2, 8])
+        //   == 0.9571734475374732
+        let target: HostFloat64Tensor = plc.from_raw(array![3.0, -0.5, 2.0, 7.0]);
+        let prediction: HostFloat64Tensor = plc.from_raw(array![2.5, 0.0, 2.0, 8.0]);
+        let z = plc.explained_variance(&sess, &prediction, &target);
+        assert_close(&z, &[0.9571734475374732]);
+
+        // a constant offset is fully explained, unlike for R2
+        let shifted: HostFloat64Tensor = plc.from_raw(array![4.0, 0.5, 3.0, 8.0]);
+        let z = plc.explained_variance(&sess, &shifted, &target);
+        assert_close(&z, &[1.0]);
+    }
+}
@@ -21,7 +21,10 @@ use std::num::Wrapping;
 
 mod bitarray;
 mod fixedpoint;
+mod metrics;
+mod models;
 mod ops;
+mod preprocessing;
 mod prim;
 pub use bitarray::*;
 pub use fixedpoint::Convert;
@@ -201,6 +204,63 @@ where
     }
 }
 
+/// Broadcast two arrays against each other, returning views of matching shape.
+///
+/// Only one-sided broadcasting is supported, i.e. one of the two shapes must
+/// be broadcastable to the other.
+pub(crate) fn broadcast_views<'a, A, B>(
+    x: &'a ArcArrayD<A>,
+    y: &'a ArcArrayD<B>,
+) -> Result<(ArrayViewD<'a, A>, ArrayViewD<'a, B>)> {
+    if x.shape() == y.shape() {
+        return Ok((x.view(), y.view()));
+    }
+    if let Some(y_view) = y.broadcast(x.shape()) {
+        return Ok((x.view(), y_view));
+    }
+    if let Some(x_view) = x.broadcast(y.shape()) {
+        return Ok((x_view, y.view()));
+    }
+    Err(crate::error::Error::KernelError(format!(
+        "Shapes {:?} and {:?} are not broadcastable",
+        x.shape(),
+        y.shape()
+    )))
+}
+
+/// Indices that would sort `values` in ascending order.
+///
+/// The sort is stable; incomparable values such as NaN are treated as equal.
+pub(crate) fn argsort<T: PartialOrd>(values: &[T]) -> Vec<usize> {
+    let mut indices: Vec<usize> = (0..values.len()).collect();
+    indices.sort_by(|&i, &j| {
+        values[i]
+            .partial_cmp(&values[j])
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+    indices
+}
+
+/// One-based ranks of `values`, with tied values sharing the average of their ranks.
+pub(crate) fn average_ranks<T: PartialOrd>(values: &[T]) -> Vec<f64> {
+    let order = argsort(values);
+    let mut ranks = vec![0.0; values.len()];
+    let mut start = 0;
+    while start < order.len() {
+        let mut end = start + 1;
+        while end < order.len() && values[order[end]] == values[order[start]] {
+            end += 1;
+        }
+        // positions start..end hold ranks start+1..=end
+        let rank = (start + end + 1) as f64 / 2.0;
+        for &i in &order[start..end] {
+            ranks[i] = rank;
+        }
+        start = end;
+    }
+    ranks
+}
+
 #[derive(Serialize, Deserialize, Hash, Clone, PartialEq)]
 pub struct HostBitTensor(pub BitArrayRepr, pub HostPlacement);
 
@@ -451,6 +511,24 @@ impl<T: Clone> HostRingTensor<T> {
     }
 }
 
+/// Session and placement shared by the host kernel tests.
+#[cfg(all(test, feature = "sync_execute"))]
+pub(crate) fn test_session() -> (crate::execution::SyncSession, HostPlacement) {
+    (
+        crate::execution::SyncSession::default(),
+        HostPlacement::from("host"),
+    )
+}
+
+/// Asserts that `actual` holds `expected` up to floating point error.
+#[cfg(test)]
+pub(crate) fn assert_close(actual: &HostFloat64Tensor, expected: &[f64]) {
+    assert_eq!(actual.0.len(), expected.len());
+    for (a, e) in actual.0.iter().zip(expected) {
+        assert!((a - e).abs() < 1e-9, "{} != {}", a, e);
+    }
+}
+
 impl<T> HostRingTensor<T> {
     fn shape(&self) -> HostShape {
         HostShape(RawShape(self.0.shape().into()), self.1.clone())
@@ -603,6 +681,20 @@ mod tests {
         assert_eq!(x_inv, expected);
     }
 
+    #[cfg(not(feature = "blas"))]
+    #[test]
+    fn test_inverse_without_blas() {
+        let sess = SyncSession::default();
+        let plc = HostPlacement::from("host");
+
+        let x: HostFloat32Tensor = plc.from_raw(array![[1.0, 2.0], [3.0, 4.0]]);
+        let res = InverseOp::host_kernel(&sess, &plc, x);
+        assert!(matches!(
+            res,
+            Err(crate::error::Error::UnimplementedOperator(_))
+        ));
+    }
+
     #[test]
     fn test_shape_slice() {
         let x_shape = RawShape(vec![1, 2, 3]);
@@ -610,6 +702,48 @@ mod tests {
         assert_eq!(x_slice, RawShape(vec![2, 3]))
     }
 
+    #[rstest]
+    #[case(1, Some(3), None, vec![2, 3])]
+    #[case(2, None, None, vec![3, 4])]
+    #[case(-2, None, None, vec![3, 4])]
+    #[case(0, Some(-1), None, vec![1, 2, 3])]
+    #[case(-3, Some(-1), None, vec![2, 3])]
+    #[case(0, None, Some(2), vec![1, 3])]
+    #[case(4, None, None, vec![])]
+    fn test_shape_slice_kernel(
+        #[case] start: isize,
+        #[case] end: Option<isize>,
+        #[case] step: Option<isize>,
+        #[case] expected: Vec<usize>,
+    ) {
+        let sess = SyncSession::default();
+        let plc = HostPlacement::from("host");
+
+        let x: HostShape = plc.from_raw(RawShape(vec![1, 2, 3, 4]));
+        let slice = SliceInfo(vec![SliceInfoElem { start, end, step }]);
+        let y = plc.slice(&sess, slice, &x);
+        assert_eq!(y.0, RawShape(expected));
+    }
+
+    #[rstest]
+    #[case(5, None)]
+    #[case(-5, None)]
+    #[case(0, Some(5))]
+    #[case(3, Some(1))]
+    fn test_shape_slice_kernel_out_of_range(#[case] start: isize, #[case] end: Option<isize>) {
+        let sess = SyncSession::default();
+        let plc = HostPlacement::from("host");
+
+        let x: HostShape = plc.from_raw(RawShape(vec![1, 2, 3, 4]));
+        let slice = SliceInfo(vec![SliceInfoElem {
+            start,
+            end,
+            step: None,
+        }]);
+        let res = SliceOp::shape_kernel(&sess, &plc, slice, x);
+        assert!(matches!(res, Err(crate::error::Error::KernelError(_))));
+    }
+
     #[test]
     fn test_tensor_slice() {
         let sess = SyncSession::default();
@@ -969,8 +1103,8 @@ mod tests {
         let sess = SyncSession::default();
 
         let shape: HostShape = plc.from_raw(RawShape(vec![5]));
-        let zero = plc.fill(&sess, 0_u8.into(), &shape);
-        let one = plc.fill(&sess, 1_u8.into(), &shape);
+        let zero: HostBitTensor = plc.fill(&sess, 0_u8.into(), &shape);
+        let one: HostBitTensor = plc.fill(&sess, 1_u8.into(), &shape);
 
         assert_eq!(&plc.xor(&sess, &zero, &one), &one);
         assert_eq!(&plc.xor(&sess, &one, &zero), &one);
@@ -1148,6 +1282,28 @@ mod tests {
         assert_eq!(out, expected);
     }
 
+    #[test]
+    fn add_n_shape_mismatch() {
+        let plc = HostPlacement::from("host");
+        let sess = SyncSession::default();
+
+        let x: HostRing64Tensor = plc.from_raw(array![[1, 2], [3, 4]]);
+        let y: HostRing64Tensor = plc.from_raw(array![[1, 2], [3, 4]]);
+        let z: HostRing64Tensor = plc.from_raw(array![1, 2]);
+        let res = AddNOp::host_kernel(&sess, &plc, &[x, y, z]);
+        match res {
+            Err(crate::error::Error::KernelError(msg)) => {
+                assert!(msg.contains("tensor 2 has shape [2]"), "{}", msg)
+            }
+            _ => panic!("expected a kernel error"),
+        }
+
+        let x: HostFloat64Tensor = plc.from_raw(array![1.0, 2.0, 3.0]);
+        let y: HostFloat64Tensor = plc.from_raw(array![[1.0, 2.0, 3.0]]);
+        let res = AddNOp::host_float_kernel(&sess, &plc, &[x, y]);
+        assert!(matches!(res, Err(crate::error::Error::KernelError(_))));
+    }
+
     #[test]
     fn bit_extract() {
         let plc = HostPlacement::from("host");
@@ -1229,6 +1385,34 @@ mod tests {
         assert_eq!(x_bits, expected);
     }
 
+    #[test]
+    fn bit_shr_dim() {
+        let plc = HostPlacement::from("host");
+        let sess = SyncSession::default();
+
+        let x: HostRing64Tensor = plc.from_raw(array![6743216615002642708, u64::MAX]);
+        let x_bits: HostBitTensor = plc.bit_decompose(&sess, &x);
+
+        let y_bits = plc.shr_dim(&sess, 6, 64, &x_bits);
+        let y: HostRing64Tensor = plc.bit_compose(&sess, &y_bits);
+        let expected: HostRing64Tensor =
+            plc.from_raw(array![6743216615002642708 >> 6, u64::MAX >> 6]);
+        assert_eq!(y, expected);
+
+        // shifting up and back down clears the 6 highest bit planes
+        let z_bits = plc.shl_dim(&sess, 6, 64, &x_bits);
+        let z_bits = plc.shr_dim(&sess, 6, 64, &z_bits);
+        let z: HostRing64Tensor = plc.bit_compose(&sess, &z_bits);
+        let expected: HostRing64Tensor =
+            plc.from_raw(array![6743216615002642708 & (u64::MAX >> 6), u64::MAX >> 6]);
+        assert_eq!(z, expected);
+        for i in 58..64 {
+            let plane: HostBitTensor = plc.index_axis(&sess, 0, i, &z_bits);
+            let zeros: HostBitTensor = plc.from_raw(array![0, 0]);
+            assert_eq!(plane, zeros);
+        }
+    }
+
     #[test]
     fn bit_transpose() {
         let plc = HostPlacement::from("host");
@@ -1298,4 +1482,745 @@ mod tests {
         let expected: HostRing128Tensor = plc.from_raw(array![37011954726876357358499180449]);
         assert_eq!(y, expected);
     }
+
+    #[test]
+    fn test_maximum_minimum() {
+        let sess = SyncSession::default();
+        let plc = HostPlacement::from("host");
+
+        let x: HostFloat64Tensor = plc.from_raw(array![[1.0, -2.0], [3.0, 0.5]]);
+        let y: HostFloat64Tensor = plc.from_raw(array![[0.0, 4.0], [3.5, -1.0]]);
+
+        let max = plc.maximum(&sess, &[x.clone(), y.clone()]);
+        let expected: HostFloat64Tensor = plc.from_raw(array![[1.0, 4.0], [3.5, 0.5]]);
+        assert_eq!(max, expected);
+
+        let min = plc.minimum(&sess, &[x, y]);
+        let expected: HostFloat64Tensor = plc.from_raw(array![[0.0, -2.0], [3.0, -1.0]]);
+        assert_eq!(min, expected);
+    }
+
+    #[test]
+    fn test_maximum_minimum_broadcasting() {
+        let sess = SyncSession::default();
+        let plc = HostPlacement::from("host");
+
+        let x: HostRing64Tensor = plc.from_raw(array![[1, 5], [3, 7]]);
+        let s: HostRing64Tensor = plc.from_raw(arr0(3));
+
+        let expected: HostRing64Tensor = plc.from_raw(array![[3, 5], [3, 7]]);
+        assert_eq!(plc.maximum(&sess, &[x.clone(), s.clone()]), expected);
+        assert_eq!(plc.maximum(&sess, &[s.clone(), x.clone()]), expected);
+
+        let expected: HostRing64Tensor = plc.from_raw(array![[1, 3], [3, 3]]);
+        assert_eq!(plc.minimum(&sess, &[x.clone(), s.clone()]), expected);
+        assert_eq!(plc.minimum(&sess, &[s, x]), expected);
+    }
+
+    #[test]
+    fn test_maximum_shape_mismatch() {
+        let sess = SyncSession::default();
+        let plc = HostPlacement::from("host");
+
+        let x: HostFloat64Tensor = plc.from_raw(array![1.0, 2.0, 3.0]);
+        let y: HostFloat64Tensor = plc.from_raw(array![1.0, 2.0]);
+
+        let res = MaximumOp::host_kernel(&sess, &plc, &[x.clone(), y.clone()]);
+        assert!(matches!(res, Err(crate::error::Error::KernelError(_))));
+        let res = MinimumOp::host_kernel(&sess, &plc, &[x, y]);
+        assert!(matches!(res, Err(crate::error::Error::KernelError(_))));
+    }
+
+    #[test]
+    fn test_prod() {
+        let sess = SyncSession::default();
+        let plc = HostPlacement::from("host");
+
+        let x: HostFloat64Tensor = plc.from_raw(array![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+
+        let expected: HostFloat64Tensor = plc.from_raw(array![4.0, 10.0, 18.0]);
+        assert_eq!(plc.prod(&sess, Some(0), &x), expected);
+
+        let expected: HostFloat64Tensor = plc.from_raw(array![6.0, 120.0]);
+        assert_eq!(plc.prod(&sess, Some(1), &x), expected);
+
+        let expected: HostFloat64Tensor = plc.from_raw(arr0(720.0));
+        let total = plc.prod(&sess, None, &x);
+        assert_eq!(total.0.ndim(), 0);
+        assert_eq!(total, expected);
+    }
+
+    #[test]
+    fn test_ring_prod_wraps_around() {
+        let sess = SyncSession::default();
+        let plc = HostPlacement::from("host");
+
+        let x: HostRing64Tensor = plc.from_raw(array![[1 << 32, 1 << 63], [1 << 32, 3]]);
+
+        let expected: HostRing64Tensor = plc.from_raw(array![0, 1 << 63]);
+        assert_eq!(plc.prod(&sess, Some(0), &x), expected);
+
+        let expected: HostRing64Tensor = plc.from_raw(arr0(0));
+        assert_eq!(plc.prod(&sess, None, &x), expected);
+    }
+
+    #[test]
+    fn test_lookup_table() {
+        let sess = SyncSession::default();
+        let plc = HostPlacement::from("host");
+
+        let x: HostRing64Tensor = plc.from_raw(array![[0, 15], [3, 7]]);
+
+        let table: HostRing64Tensor = plc.from_raw(Array::from_iter((0..16).map(|i| i * i)));
+        let expected: HostRing64Tensor = plc.from_raw(array![[0, 225], [9, 49]]);
+        assert_eq!(plc.lookup_table(&sess, &x, &table), expected);
+
+        let table: HostFloat64Tensor =
+            plc.from_raw(Array::from_iter((0..16).map(|i| i as f64 / 2.0)));
+        let expected: HostFloat64Tensor = plc.from_raw(array![[0.0, 7.5], [1.5, 3.5]]);
+        assert_eq!(plc.lookup_table(&sess, &x, &table), expected);
+    }
+
+    #[test]
+    fn test_lookup_table_out_of_range() {
+        let sess = SyncSession::default();
+        let plc = HostPlacement::from("host");
+
+        let x: HostRing64Tensor = plc.from_raw(array![2, 16]);
+        let table: HostFloat64Tensor = plc.from_raw(Array::from_elem(16, 1.0));
+        let res = LookupTableOp::host_float_kernel(&sess, &plc, x, table);
+        assert!(matches!(res, Err(crate::error::Error::KernelError(_))));
+    }
+
+    #[test]
+    fn test_float_argmax() {
+        let sess = SyncSession::default();
+        let plc = HostPlacement::from("host");
+
+        let x: HostFloat64Tensor =
+            plc.from_raw(array![[0.1, 0.7, 0.2], [0.4, 0.1, 0.4], [-1.0, -2.0, -0.5]]);
+
+        // ties resolve to the lowest index, as in the second row
+        let expected: HostUint64Tensor = plc.from_raw(array![1, 0, 2]);
+        assert_eq!(plc.argmax(&sess, 1, 3, &x), expected);
+
+        let expected: HostUint64Tensor = plc.from_raw(array![1, 0, 1]);
+        assert_eq!(plc.argmax(&sess, 0, 3, &x), expected);
+    }
+
+    #[test]
+    fn test_count_distinct() {
+        let sess = SyncSession::default();
+        let plc = HostPlacement::from("host");
+
+        let x: HostRing64Tensor = plc.from_raw(array![[1, 2, 2, 7, 1], [3, 3, 3, 3, 3]]);
+
+        let expected: HostRing64Tensor = plc.from_raw(array![3, 1]);
+        assert_eq!(plc.count_distinct(&sess, 1, &x), expected);
+
+        let expected: HostRing64Tensor = plc.from_raw(array![2, 2, 2, 2, 2]);
+        assert_eq!(plc.count_distinct(&sess, 0, &x), expected);
+    }
+
+    #[rstest]
+    #[case(Some(0), 0, array![2.25, 2.25, 2.25].into_dyn())]
+    #[case(Some(0), 1, array![4.5, 4.5, 4.5].into_dyn())]
+    #[case(Some(1), 0, array![2.0 / 3.0, 2.0 / 3.0].into_dyn())]
+    #[case(Some(1), 1, array![1.0, 1.0].into_dyn())]
+    #[case(None, 0, arr0(17.5 / 6.0).into_dyn())]
+    #[case(None, 1, arr0(3.5).into_dyn())]
+    fn test_var_std(#[case] axis: Option<u32>, #[case] ddof: u32, #[case] expected: ArrayD<f64>) {
+        let sess = SyncSession::default();
+        let plc = HostPlacement::from("host");
+
+        let x: HostFloat64Tensor = plc.from_raw(array![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+
+        let var = plc.var(&sess, axis, ddof, &x);
+        assert_eq!(var.0.shape(), expected.shape());
+        for (a, e) in var.0.iter().zip(expected.iter()) {
+            assert!((a - e).abs() < 1e-12);
+        }
+
+        let std = plc.std(&sess, axis, ddof, &x);
+        for (a, e) in std.0.iter().zip(expected.iter()) {
+            assert!((a - e.sqrt()).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_var_empty_axis() {
+        let sess = SyncSession::default();
+        let plc = HostPlacement::from("host");
+
+        let x: HostFloat64Tensor = plc.from_raw(Array2::<f64>::zeros((2, 0)));
+        let res = VarOp::host_kernel(&sess, &plc, Some(1), 0, x);
+        assert!(matches!(res, Err(crate::error::Error::KernelError(_))));
+    }
+
+    #[test]
+    fn test_moving_std() {
+        let sess = SyncSession::default();
+        let plc = HostPlacement::from("host");
+
+        let raw_x = array![[1.0, 3.0, 2.0, 8.0, 5.0], [0.0, 0.0, 1.0, 1.0, 4.0]];
+        let x: HostFloat64Tensor = plc.from_raw(raw_x.clone());
+        let y = plc.moving_std(&sess, 1, 3, 1, &x);
+        assert_eq!(y.0.shape(), &[2, 3]);
+
+        // naive rolling sample standard deviation
+        for (row, y_row) in raw_x.outer_iter().zip(y.0.outer_iter()) {
+            for (start, actual) in y_row.iter().enumerate() {
+                let w = row.slice(s![start..start + 3]);
+                let mean = w.sum() / 3.0;
+                let var = w.iter().map(|v| (v - mean) * (v - mean)).sum::<f64>() / 2.0;
+                assert!((actual - var.sqrt()).abs() < 1e-12);
+            }
+        }
+        assert!((y.0[[0, 0]] - 1.0).abs() < 1e-12);
+        assert!((y.0[[1, 0]] - (1.0f64 / 3.0).sqrt()).abs() < 1e-12);
+
+        let res = MovingStdOp::host_kernel(&sess, &plc, 1, 6, 1, x);
+        assert!(matches!(res, Err(crate::error::Error::KernelError(_))));
+    }
+
+    #[test]
+    fn test_clip() {
+        let sess = SyncSession::default();
+        let plc = HostPlacement::from("host");
+
+        let x: HostFloat64Tensor = plc.from_raw(array![[-2.0, -0.5, 0.0], [0.5, 1.0, 3.0]]);
+        let expected: HostFloat64Tensor = plc.from_raw(array![[-1.0, -0.5, 0.0], [0.5, 1.0, 1.0]]);
+        assert_eq!(plc.clip(&sess, -1.0, 1.0, &x), expected);
+
+        let y = plc.clip(&sess, -1.0, 1.0, &Float64Tensor::Host(x));
+        assert_eq!(y, Float64Tensor::Host(expected));
+    }
+
+    #[test]
+    fn test_clip_invalid_bounds() {
+        let sess = SyncSession::default();
+        let plc = HostPlacement::from("host");
+
+        let x: HostFloat32Tensor = plc.from_raw(array![1.0, 2.0]);
+        let res = ClipOp::host_kernel(&sess, &plc, 1.0, 0.0, x);
+        assert!(matches!(res, Err(crate::error::Error::KernelError(_))));
+    }
+
+    #[test]
+    fn test_pow() {
+        let sess = SyncSession::default();
+        let plc = HostPlacement::from("host");
+
+        let x: HostFloat64Tensor = plc.from_raw(array![[1.0, -2.0], [3.0, 0.5]]);
+        let expected: HostFloat64Tensor = plc.from_raw(array![[1.0, 4.0], [9.0, 0.25]]);
+        assert_eq!(plc.pow(&sess, 2.0, &x), expected);
+
+        let expected: HostFloat64Tensor = plc.from_raw(array![[1.0, -0.5], [1.0 / 3.0, 2.0]]);
+        assert_eq!(plc.pow(&sess, -1.0, &x), expected);
+    }
+
+    #[test]
+    fn test_pow_half_is_sqrt() {
+        let sess = SyncSession::default();
+        let plc = HostPlacement::from("host");
+
+        let x: HostFloat64Tensor = plc.from_raw(array![0.0, 1.0, 2.0, 10.0, 0.3]);
+        let y = plc.pow(&sess, 0.5, &x);
+        let expected = plc.sqrt(&sess, &x);
+        for (a, e) in y.0.iter().zip(expected.0.iter()) {
+            assert!((a - e).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_floor_ceil_round() {
+        let sess = SyncSession::default();
+        let plc = HostPlacement::from("host");
+
+        let x: HostFloat64Tensor = plc.from_raw(array![-2.5, -1.5, -0.4, 0.0, 0.5, 1.2, 2.5]);
+
+        let expected: HostFloat64Tensor =
+            plc.from_raw(array![-3.0, -2.0, -1.0, 0.0, 0.0, 1.0, 2.0]);
+        assert_eq!(plc.floor(&sess, &x), expected);
+
+        let expected: HostFloat64Tensor =
+            plc.from_raw(array![-2.0, -1.0, -0.0, 0.0, 1.0, 2.0, 3.0]);
+        assert_eq!(plc.ceil(&sess, &x), expected);
+
+        let expected: HostFloat64Tensor =
+            plc.from_raw(array![-3.0, -2.0, -0.0, 0.0, 1.0, 1.0, 3.0]);
+        assert_eq!(plc.round(&sess, &x), expected);
+    }
+
+    #[test]
+    fn test_reciprocal() {
+        let sess = SyncSession::default();
+        let plc = HostPlacement::from("host");
+
+        let x: HostFloat64Tensor = plc.from_raw(array![2.0, -4.0, 0.0, -0.0]);
+        let y = plc.reciprocal(&sess, &x);
+        assert_eq!(y.0[0], 0.5);
+        assert_eq!(y.0[1], -0.25);
+        assert_eq!(y.0[2], f64::INFINITY);
+        assert_eq!(y.0[3], f64::NEG_INFINITY);
+    }
+
+    #[test]
+    fn test_cumsum() {
+        let sess = SyncSession::default();
+        let plc = HostPlacement::from("host");
+
+        let x: HostFloat64Tensor = plc.from_raw(array![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+
+        let expected: HostFloat64Tensor = plc.from_raw(array![[1.0, 2.0, 3.0], [5.0, 7.0, 9.0]]);
+        assert_eq!(plc.cumsum(&sess, 0, &x), expected);
+
+        let expected: HostFloat64Tensor = plc.from_raw(array![[1.0, 3.0, 6.0], [4.0, 9.0, 15.0]]);
+        assert_eq!(plc.cumsum(&sess, 1, &x), expected);
+
+        let res = CumsumOp::host_kernel(&sess, &plc, 2, x);
+        assert!(matches!(res, Err(crate::error::Error::KernelError(_))));
+    }
+
+    #[test]
+    fn test_ring_cumsum_wraps_around() {
+        let sess = SyncSession::default();
+        let plc = HostPlacement::from("host");
+
+        let x: HostRing64Tensor = plc.from_raw(array![[u64::MAX, 1, 2], [3, 4, 5]]);
+
+        let expected: HostRing64Tensor = plc.from_raw(array![[u64::MAX, 1, 2], [2, 5, 7]]);
+        assert_eq!(plc.cumsum(&sess, 0, &x), expected);
+
+        let expected: HostRing64Tensor = plc.from_raw(array![[u64::MAX, 0, 2], [3, 7, 12]]);
+        assert_eq!(plc.cumsum(&sess, 1, &x), expected);
+    }
+
+    #[test]
+    fn test_tile() {
+        let sess = SyncSession::default();
+        let plc = HostPlacement::from("host");
+
+        let bias: HostFloat64Tensor = plc.from_raw(array![[1.0, 2.0, 3.0]]);
+        let expected: HostFloat64Tensor =
+            plc.from_raw(array![[1.0, 2.0, 3.0], [1.0, 2.0, 3.0], [1.0, 2.0, 3.0]]);
+        assert_eq!(plc.tile(&sess, 0, 3, &bias), expected);
+
+        let expected: HostFloat64Tensor = plc.from_raw(array![[1.0, 2.0, 3.0, 1.0, 2.0, 3.0]]);
+        assert_eq!(plc.tile(&sess, 1, 2, &bias), expected);
+
+        let empty = plc.tile(&sess, 0, 0, &bias);
+        assert_eq!(empty.0.shape(), &[0, 3]);
+
+        let x: HostRing64Tensor = plc.from_raw(array![1, 2]);
+        let expected: HostRing64Tensor = plc.from_raw(array![1, 2, 1, 2]);
+        assert_eq!(plc.tile(&sess, 0, 2, &x), expected);
+
+        let res = TileOp::host_kernel(&sess, &plc, 2, 1, bias);
+        assert!(matches!(res, Err(crate::error::Error::KernelError(_))));
+    }
+
+    #[test]
+    fn test_flip() {
+        let sess = SyncSession::default();
+        let plc = HostPlacement::from("host");
+
+        let x: HostFloat64Tensor = plc.from_raw(array![1.0, 2.0, 3.0]);
+        let expected: HostFloat64Tensor = plc.from_raw(array![3.0, 2.0, 1.0]);
+        assert_eq!(plc.flip(&sess, 0, &x), expected);
+
+        let x: HostRing64Tensor = plc.from_raw(array![[1, 2, 3], [4, 5, 6]]);
+        let expected: HostRing64Tensor = plc.from_raw(array![[4, 5, 6], [1, 2, 3]]);
+        assert_eq!(plc.flip(&sess, 0, &x), expected);
+        let expected: HostRing64Tensor = plc.from_raw(array![[3, 2, 1], [6, 5, 4]]);
+        assert_eq!(plc.flip(&sess, 1, &x), expected);
+
+        let x: HostBitTensor = plc.from_raw(array![[1, 1, 0], [0, 0, 1]]);
+        let expected: HostBitTensor = plc.from_raw(array![[0, 1, 1], [1, 0, 0]]);
+        assert_eq!(plc.flip(&sess, 1, &x), expected);
+
+        let x: HostRing64Tensor = plc.from_raw(array![1, 2, 3]);
+        let res = FlipOp::host_ring_kernel(&sess, &plc, 1, x);
+        assert!(matches!(res, Err(crate::error::Error::KernelError(_))));
+    }
+
+    #[test]
+    fn test_pad() {
+        let sess = SyncSession::default();
+        let plc = HostPlacement::from("host");
+
+        let x: HostFloat64Tensor = plc.from_raw(array![[1.0, 2.0], [3.0, 4.0]]);
+        let expected: HostFloat64Tensor = plc.from_raw(array![
+            [9.0, 9.0, 9.0, 9.0, 9.0],
+            [9.0, 1.0, 2.0, 9.0, 9.0],
+            [9.0, 3.0, 4.0, 9.0, 9.0],
+        ]);
+        assert_eq!(plc.pad(&sess, vec![1, 0, 1, 2], 9.0, &x), expected);
+
+        let x: HostRing64Tensor = plc.from_raw(array![[1, 2], [3, 4]]);
+        let expected: HostRing64Tensor = plc.from_raw(array![[1, 2, 0], [3, 4, 0], [0, 0, 0]]);
+        assert_eq!(plc.pad(&sess, vec![0, 1, 0, 1], 0.0, &x), expected);
+    }
+
+    #[test]
+    fn test_pad_rank_mismatch() {
+        let sess = SyncSession::default();
+        let plc = HostPlacement::from("host");
+
+        let x: HostFloat64Tensor = plc.from_raw(array![[1.0, 2.0], [3.0, 4.0]]);
+        let res = PadOp::host_kernel(&sess, &plc, vec![1, 1], 0.0, x);
+        assert!(matches!(res, Err(crate::error::Error::KernelError(_))));
+    }
+
+    #[test]
+    fn test_gather() {
+        let sess = SyncSession::default();
+        let plc = HostPlacement::from("host");
+
+        let embeddings: HostFloat64Tensor =
+            plc.from_raw(array![[0.0, 0.1], [1.0, 1.1], [2.0, 2.1]]);
+        let indices: HostRing64Tensor = plc.from_raw(array![2, 0, 2]);
+        let expected: HostFloat64Tensor = plc.from_raw(array![[2.0, 2.1], [0.0, 0.1], [2.0, 2.1]]);
+        assert_eq!(plc.gather(&sess, 0, &embeddings, &indices), expected);
+
+        let x: HostRing64Tensor = plc.from_raw(array![[1, 2, 3], [4, 5, 6]]);
+        let indices: HostRing64Tensor = plc.from_raw(array![1, 1]);
+        let expected: HostRing64Tensor = plc.from_raw(array![[2, 2], [5, 5]]);
+        assert_eq!(plc.gather(&sess, 1, &x, &indices), expected);
+    }
+
+    #[test]
+    fn test_gather_out_of_bounds() {
+        let sess = SyncSession::default();
+        let plc = HostPlacement::from("host");
+
+        let x: HostRing64Tensor = plc.from_raw(array![[1, 2, 3], [4, 5, 6]]);
+        let indices: HostRing64Tensor = plc.from_raw(array![0, 2]);
+        let res = GatherOp::host_ring_kernel(&sess, &plc, 0, x, indices);
+        assert!(matches!(res, Err(crate::error::Error::KernelError(_))));
+    }
+
+    #[test]
+    fn test_where() {
+        let sess = SyncSession::default();
+        let plc = HostPlacement::from("host");
+
+        let s: HostBitTensor = plc.from_raw(array![[1, 0], [0, 1]]);
+
+        let x: HostFloat64Tensor = plc.from_raw(array![[1.0, f64::INFINITY], [3.0, 4.0]]);
+        let y: HostFloat64Tensor = plc.from_raw(array![[-1.0, -2.0], [f64::INFINITY, -4.0]]);
+        let expected: HostFloat64Tensor = plc.from_raw(array![[1.0, -2.0], [f64::INFINITY, 4.0]]);
+        assert_eq!(plc.where_(&sess, &s, &x, &y), expected);
+
+        let x: HostRing64Tensor = plc.from_raw(array![[1, 2], [3, 4]]);
+        let y: HostRing64Tensor = plc.from_raw(array![[5, 6], [7, 8]]);
+        let expected: HostRing64Tensor = plc.from_raw(array![[1, 6], [7, 4]]);
+        assert_eq!(plc.where_(&sess, &s, &x, &y), expected);
+    }
+
+    #[test]
+    fn test_where_shape_mismatch() {
+        let sess = SyncSession::default();
+        let plc = HostPlacement::from("host");
+
+        let s: HostBitTensor = plc.from_raw(array![[1, 0], [0, 1]]);
+        let x: HostRing64Tensor = plc.from_raw(array![1, 2]);
+        let y: HostRing64Tensor = plc.from_raw(array![3, 4]);
+        let res = WhereOp::host_ring_kernel(&sess, &plc, s, x, y);
+        assert!(matches!(res, Err(crate::error::Error::KernelError(_))));
+    }
+
+    #[test]
+    fn test_equal_not_equal() {
+        let sess = SyncSession::default();
+        let plc = HostPlacement::from("host");
+
+        let x: HostFloat64Tensor = plc.from_raw(array![1.0, 2.0, -0.0, f64::NAN]);
+        let y: HostFloat64Tensor = plc.from_raw(array![1.0, 2.5, 0.0, f64::NAN]);
+        let expected: HostBitTensor = plc.from_raw(array![1, 0, 1, 0]);
+        assert_eq!(plc.equal(&sess, &x, &y), expected);
+        let expected: HostBitTensor = plc.from_raw(array![0, 1, 0, 1]);
+        assert_eq!(plc.not_equal(&sess, &x, &y), expected);
+
+        let x: HostRing64Tensor = plc.from_raw(array![0, 5, u64::MAX]);
+        let y: HostRing64Tensor = plc.from_raw(array![0, 6, u64::MAX]);
+        let expected: HostBitTensor = plc.from_raw(array![1, 0, 1]);
+        assert_eq!(plc.equal(&sess, &x, &y), expected);
+        let expected: HostBitTensor = plc.from_raw(array![0, 1, 0]);
+        assert_eq!(plc.not_equal(&sess, &x, &y), expected);
+    }
+
+    #[test]
+    fn test_greater_equal() {
+        let sess = SyncSession::default();
+        let plc = HostPlacement::from("host");
+
+        let x: HostFloat64Tensor = plc.from_raw(array![1.0, 2.0, 3.0, f64::INFINITY]);
+        let y: HostFloat64Tensor = plc.from_raw(array![2.0, 2.0, 2.0, f64::INFINITY]);
+        let expected: HostBitTensor = plc.from_raw(array![0, 1, 1, 1]);
+        assert_eq!(plc.greater_equal(&sess, &x, &y), expected);
+
+        // ring elements are interpreted as signed; u64::MAX is -1
+        let x: HostRing64Tensor = plc.from_raw(array![1, 2, 3, u64::MAX, 0]);
+        let y: HostRing64Tensor = plc.from_raw(array![2, 2, 2, u64::MAX, u64::MAX]);
+        let expected: HostBitTensor = plc.from_raw(array![0, 1, 1, 1, 1]);
+        assert_eq!(plc.greater_equal(&sess, &x, &y), expected);
+
+        let x: HostRing128Tensor = plc.from_raw(array![7, 8, u128::MAX]);
+        let y: HostRing128Tensor = plc.from_raw(array![8, 8, 0]);
+        let expected: HostBitTensor = plc.from_raw(array![0, 1, 0]);
+        assert_eq!(plc.greater_equal(&sess, &x, &y), expected);
+    }
+
+    #[test]
+    fn test_stack() {
+        let sess = SyncSession::default();
+        let plc = HostPlacement::from("host");
+
+        let xs: Vec<HostFloat64Tensor> = vec![
+            plc.from_raw(array![1.0, 2.0]),
+            plc.from_raw(array![3.0, 4.0]),
+            plc.from_raw(array![5.0, 6.0]),
+        ];
+        let expected: HostFloat64Tensor = plc.from_raw(array![[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]);
+        assert_eq!(plc.stack(&sess, 0, &xs), expected);
+        let expected: HostFloat64Tensor = plc.from_raw(array![[1.0, 3.0, 5.0], [2.0, 4.0, 6.0]]);
+        assert_eq!(plc.stack(&sess, 1, &xs), expected);
+
+        let xs: Vec<HostRing64Tensor> =
+            vec![plc.from_raw(array![1, 2]), plc.from_raw(array![3, 4])];
+        let expected: HostRing64Tensor = plc.from_raw(array![[1, 2], [3, 4]]);
+        assert_eq!(plc.stack(&sess, 0, &xs), expected);
+    }
+
+    #[test]
+    fn test_stack_errors() {
+        let sess = SyncSession::default();
+        let plc = HostPlacement::from("host");
+
+        let res = StackOp::ring_kernel::<_, u64>(&sess, &plc, 0, &[]);
+        assert!(matches!(res, Err(crate::error::Error::KernelError(_))));
+
+        let xs: Vec<HostRing64Tensor> = vec![plc.from_raw(array![1, 2]), plc.from_raw(array![3])];
+        let res = StackOp::ring_kernel(&sess, &plc, 0, &xs);
+        assert!(matches!(res, Err(crate::error::Error::KernelError(_))));
+    }
+
+    #[test]
+    fn test_ring_xor_or() {
+        let sess = SyncSession::default();
+        let plc = HostPlacement::from("host");
+
+        let x: HostRing64Tensor = plc.from_raw(array![0b1100, 0, u64::MAX, 1 << 63]);
+        let y: HostRing64Tensor = plc.from_raw(array![0b1010, 7, 1, 1]);
+        let zero: HostRing64Tensor = plc.from_raw(array![0, 0, 0, 0]);
+
+        let expected: HostRing64Tensor =
+            plc.from_raw(array![0b0110, 7, u64::MAX - 1, (1 << 63) + 1]);
+        assert_eq!(plc.xor(&sess, &x, &y), expected);
+        let expected: HostRing64Tensor = plc.from_raw(array![0b1110, 7, u64::MAX, (1 << 63) + 1]);
+        assert_eq!(plc.or(&sess, &x, &y), expected);
+
+        // x ^ x == 0, x ^ 0 == x, x | x == x, x | 0 == x
+        assert_eq!(plc.xor(&sess, &x, &x), zero);
+        assert_eq!(plc.xor(&sess, &x, &zero), x);
+        assert_eq!(plc.or(&sess, &x, &x), x);
+        assert_eq!(plc.or(&sess, &x, &zero), x);
+
+        // x ^ y == (x | y) - (x & y)
+        let or = plc.or(&sess, &x, &y);
+        let and = plc.and(&sess, &x, &y);
+        let xor = plc.xor(&sess, &x, &y);
+        assert_eq!(plc.sub(&sess, &or, &and), xor);
+    }
+
+    #[test]
+    fn test_ring_not() {
+        let sess = SyncSession::default();
+        let plc = HostPlacement::from("host");
+
+        let x: HostRing64Tensor = plc.from_raw(array![0, u64::MAX, 0x00ff_00ff_00ff_00ff, 5]);
+        let expected: HostRing64Tensor =
+            plc.from_raw(array![u64::MAX, 0, 0xff00_ff00_ff00_ff00, u64::MAX - 5]);
+        let not_x = plc.not(&sess, &x);
+        assert_eq!(not_x, expected);
+        assert_eq!(plc.not(&sess, &not_x), x);
+
+        // two's complement negation
+        let one: HostRing64Tensor = plc.from_raw(array![1, 1, 1, 1]);
+        assert_eq!(plc.add(&sess, &not_x, &one), plc.neg(&sess, &x));
+    }
+
+    #[test]
+    fn test_ring_rotate() {
+        let sess = SyncSession::default();
+        let plc = HostPlacement::from("host");
+
+        let x: HostRing64Tensor = plc.from_raw(array![1, 1 << 63, 0x8000_0000_0000_0001, 0]);
+        assert_eq!(plc.rotate(&sess, 64, true, &x), x);
+        assert_eq!(plc.rotate(&sess, 64, false, &x), x);
+
+        let expected: HostRing64Tensor = plc.from_raw(array![2, 1, 3, 0]);
+        assert_eq!(plc.rotate(&sess, 1, true, &x), expected);
+        let expected: HostRing64Tensor =
+            plc.from_raw(array![1 << 63, 1 << 62, 0xc000_0000_0000_0000, 0]);
+        assert_eq!(plc.rotate(&sess, 1, false, &x), expected);
+
+        // no bits are lost, unlike shifting
+        let y = plc.rotate(&sess, 13, true, &x);
+        assert_eq!(plc.rotate(&sess, 13, false, &y), x);
+    }
+
+    #[test]
+    fn test_bit_compose() {
+        let sess = SyncSession::default();
+        let plc = HostPlacement::from("host");
+
+        let x: HostRing64Tensor = plc.from_raw(array![[0, 1, 5], [u64::MAX, 1 << 63, 123456789]]);
+        let bits: HostBitTensor = plc.bit_decompose(&sess, &x);
+        let y: HostRing64Tensor = plc.bit_compose(&sess, &bits);
+        assert_eq!(y, x);
+
+        let x: HostRing128Tensor = plc.from_raw(array![0, 7, u128::MAX, 1 << 100]);
+        let bits: HostBitTensor = plc.bit_decompose(&sess, &x);
+        let y: HostRing128Tensor = plc.bit_compose(&sess, &bits);
+        assert_eq!(y, x);
+
+        // fewer bits than the ring bit length are zero-extended
+        let bits: HostBitTensor = plc.from_raw(array![[1, 0], [1, 1]]);
+        let y: HostRing64Tensor = plc.bit_compose(&sess, &bits);
+        let expected: HostRing64Tensor = plc.from_raw(array![3, 2]);
+        assert_eq!(y, expected);
+    }
+
+    #[test]
+    fn test_bit_compose_too_many_bits() {
+        let sess = SyncSession::default();
+        let plc = HostPlacement::from("host");
+
+        let bits: HostBitTensor = plc.from_raw(ArrayD::from_elem(vec![65, 2], 1u8));
+        let res = BitComposeOp::ring64_kernel(&sess, &plc, bits);
+        assert!(matches!(res, Err(crate::error::Error::KernelError(_))));
+    }
+
+    #[test]
+    fn test_ring_popcount() {
+        let sess = SyncSession::default();
+        let plc = HostPlacement::from("host");
+
+        let x: HostRing64Tensor = plc.from_raw(array![0xFF, 0, u64::MAX, 0b1010_0001]);
+        let expected: HostRing64Tensor = plc.from_raw(array![8, 0, 64, 3]);
+        assert_eq!(plc.popcount(&sess, &x), expected);
+
+        let x: HostRing128Tensor = plc.from_raw(array![0xFF, u128::MAX, 1 << 100]);
+        let expected: HostRing64Tensor = plc.from_raw(array![8, 128, 1]);
+        assert_eq!(plc.popcount(&sess, &x), expected);
+    }
+
+    #[test]
+    fn test_broadcast_ring() {
+        let sess = SyncSession::default();
+        let plc = HostPlacement::from("host");
+
+        let x: HostRing64Tensor = plc.from_raw(array![1, 2, 3]);
+        let s: HostShape = plc.from_raw(RawShape(vec![2, 3]));
+        let expected: HostRing64Tensor = plc.from_raw(array![[1, 2, 3], [1, 2, 3]]);
+        assert_eq!(plc.broadcast(&sess, &s, &x), expected);
+
+        let s: HostShape = plc.from_raw(RawShape(vec![3, 2]));
+        let res = BroadcastOp::host_ring_kernel(&sess, &plc, s, x);
+        assert!(matches!(res, Err(crate::error::Error::KernelError(_))));
+    }
+
+    #[test]
+    fn test_float_neg() {
+        let sess = SyncSession::default();
+        let plc = HostPlacement::from("host");
+
+        let x: HostFloat64Tensor = plc.from_raw(array![[1.5, -2.0], [0.0, 3.25]]);
+        let expected: HostFloat64Tensor = plc.from_raw(array![[-1.5, 2.0], [-0.0, -3.25]]);
+        let neg = plc.neg(&sess, &x);
+        assert_eq!(neg, expected);
+        assert_eq!(plc.neg(&sess, &neg), x);
+    }
+
+    #[test]
+    fn test_ring_abs() {
+        let sess = SyncSession::default();
+        let plc = HostPlacement::from("host");
+
+        let x: HostRing64Tensor = plc.from_raw(array![
+            5,
+            -7_i64 as u64,
+            0,
+            i64::MAX as u64,
+            (i64::MIN + 1) as u64,
+            i64::MIN as u64
+        ]);
+        let expected: HostRing64Tensor = plc.from_raw(array![
+            5,
+            7,
+            0,
+            i64::MAX as u64,
+            i64::MAX as u64,
+            i64::MIN as u64
+        ]);
+        assert_eq!(plc.abs(&sess, &x), expected);
+
+        let x: HostRing128Tensor = plc.from_raw(array![
+            -3_i128 as u128,
+            i128::MIN as u128,
+            i128::MAX as u128
+        ]);
+        let expected: HostRing128Tensor =
+            plc.from_raw(array![3, i128::MIN as u128, i128::MAX as u128]);
+        assert_eq!(plc.abs(&sess, &x), expected);
+    }
+
+    #[test]
+    fn test_square() {
+        let sess = SyncSession::default();
+        let plc = HostPlacement::from("host");
+
+        let x: HostFloat64Tensor = plc.from_raw(array![[1.5, -2.0], [0.0, 3.0]]);
+        let expected: HostFloat64Tensor = plc.mul(&sess, &x, &x);
+        assert_eq!(plc.square(&sess, &x), expected);
+
+        let x: HostRing64Tensor = plc.from_raw(array![3, -4_i64 as u64, u64::MAX, 1 << 33]);
+        let expected: HostRing64Tensor = plc.mul(&sess, &x, &x);
+        assert_eq!(plc.square(&sess, &x), expected);
+    }
+
+    #[test]
+    fn test_mod() {
+        let sess = SyncSession::default();
+        let plc = HostPlacement::from("host");
+
+        let x: HostInt64Tensor = plc.from_raw(array![7, -7, 12, -12, i64::MIN]);
+        let y: HostInt64Tensor = plc.from_raw(array![3, 3, -5, -5, -1]);
+        let expected: HostInt64Tensor = plc.from_raw(array![1, -1, 2, -2, 0]);
+        assert_eq!(plc.modulo(&sess, &x, &y), expected);
+
+        // reduction modulo a broadcast prime
+        let x: HostRing64Tensor = plc.from_raw(array![10, 22, u64::MAX]);
+        let p: HostRing64Tensor = plc.from_raw(array![7]);
+        let expected: HostRing64Tensor = plc.from_raw(array![3, 1, u64::MAX % 7]);
+        assert_eq!(plc.modulo(&sess, &x, &p), expected);
+    }
+
+    #[test]
+    fn test_mod_zero() {
+        let sess = SyncSession::default();
+        let plc = HostPlacement::from("host");
+
+        let x: HostInt64Tensor = plc.from_raw(array![7, -7]);
+        let y: HostInt64Tensor = plc.from_raw(array![3, 0]);
+        let res = ModOp::host_kernel(&sess, &plc, x, y);
+        assert!(matches!(res, Err(crate::error::Error::KernelError(_))));
+
+        let x: HostRing64Tensor = plc.from_raw(array![7]);
+        let y: HostRing64Tensor = plc.from_raw(array![0]);
+        let res = ModOp::ring_kernel(&sess, &plc, x, y);
+        assert!(matches!(res, Err(crate::error::Error::KernelError(_))));
+    }
 }
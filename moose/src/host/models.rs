@@ -0,0 +1,1669 @@
+//! Host kernels for clustering and model inference.
+
+use super::*;
+use crate::error::{Error, Result};
+use crate::execution::RuntimeSession;
+use ndarray::Zip;
+use num_traits::{Float, FromPrimitive};
+use std::collections::BTreeMap;
+use std::num::Wrapping;
+
+/// Views of the sample matrix `x` and a matrix `c` of other points, such as centroids,
+/// with matching feature dimensions; `other` names the latter in errors.
+fn sample_views<'a, T>(
+    op: &str,
+    other: &str,
+    x: &'a ArcArrayD<T>,
+    c: &'a ArcArrayD<T>,
+) -> Result<(ArrayView2<'a, T>, ArrayView2<'a, T>)> {
+    match (
+        x.view().into_dimensionality::<Ix2>(),
+        c.view().into_dimensionality::<Ix2>(),
+    ) {
+        (Ok(x), Ok(c)) if x.ncols() == c.ncols() => Ok((x, c)),
+        _ => Err(Error::KernelError(format!(
+            "{} expected samples [n, f] and {} [k, f] but got {:?} and {:?}",
+            op,
+            other,
+            x.shape(),
+            c.shape()
+        ))),
+    }
+}
+
+/// Squared Euclidean distances between the rows of `x` and `c`, of shape `[n, k]`.
+///
+/// Uses `|x - c|^2 = |x|^2 - 2 x.c + |c|^2`, clamping the rounding errors this may
+/// introduce for nearby points at zero.
+fn squared_distances<T>(x: ArrayView2<T>, c: ArrayView2<T>) -> Array2<T>
+where
+    T: LinalgScalar + Float,
+{
+    let x_norms = x.map_axis(Axis(1), |row| row.dot(&row));
+    let c_norms = c.map_axis(Axis(1), |row| row.dot(&row));
+    let two = T::one() + T::one();
+    let mut res = x.dot(&c.t());
+    for ((i, j), d) in res.indexed_iter_mut() {
+        *d = (x_norms[i] - two * *d + c_norms[j]).max(T::zero());
+    }
+    res
+}
+
+impl CentroidDistOp {
+    /// Euclidean distance from every sample in `x` to every centroid.
+    ///
+    /// With `x` of shape `[n, f]` and `centroids` of shape `[k, f]` the result has shape
+    /// `[n, k]`.
+    pub(crate) fn host_kernel<S: RuntimeSession, T>(
+        _sess: &S,
+        plc: &HostPlacement,
+        x: HostTensor<T>,
+        centroids: HostTensor<T>,
+    ) -> Result<HostTensor<T>>
+    where
+        T: LinalgScalar + Float,
+    {
+        let (x, c) = sample_views("CentroidDistOp", "centroids", &x.0, &centroids.0)?;
+        let res = squared_distances(x, c).mapv(T::sqrt);
+        Ok(HostTensor(res.into_dyn().into_shared(), plc.clone()))
+    }
+}
+
+impl AssignClustersOp {
+    /// Index of the nearest centroid for every sample in `x`.
+    ///
+    /// With `x` of shape `[n, f]` and `centroids` of shape `[k, f]` the result has shape
+    /// `[n]`. Ties are broken in favour of the centroid with the lowest index.
+    pub(crate) fn host_kernel<S: RuntimeSession, T>(
+        _sess: &S,
+        plc: &HostPlacement,
+        x: HostTensor<T>,
+        centroids: HostTensor<T>,
+    ) -> Result<HostRing64Tensor>
+    where
+        T: LinalgScalar + Float,
+    {
+        let (x, c) = sample_views("AssignClustersOp", "centroids", &x.0, &centroids.0)?;
+        if c.nrows() == 0 {
+            return Err(Error::KernelError(
+                "AssignClustersOp requires at least one centroid".to_string(),
+            ));
+        }
+        let dists = squared_distances(x, c);
+        let assignments = dists.map_axis(Axis(1), |row| {
+            let mut best = 0;
+            for (j, d) in row.iter().enumerate().skip(1) {
+                if *d < row[best] {
+                    best = j;
+                }
+            }
+            Wrapping(best as u64)
+        });
+        Ok(HostRingTensor(
+            assignments.into_dyn().into_shared(),
+            plc.clone(),
+        ))
+    }
+}
+
+impl SilhouetteOp {
+    /// Silhouette score of every sample in `x` under the cluster `labels`.
+    ///
+    /// With `x` of shape `[n, f]` and `labels` of shape `[n]`, the score of a sample is
+    /// `(b - a) / max(a, b)` where `a` is its mean Euclidean distance to the other members
+    /// of its cluster and `b` the smallest mean distance to the members of another cluster.
+    /// Samples in singleton clusters score zero. When `average` is set the mean score is
+    /// returned as a scalar, otherwise the per-sample scores of shape `[n]`.
+    pub(crate) fn host_kernel<S: RuntimeSession, T>(
+        _sess: &S,
+        plc: &HostPlacement,
+        average: bool,
+        x: HostTensor<T>,
+        labels: HostRing64Tensor,
+    ) -> Result<HostTensor<T>>
+    where
+        T: LinalgScalar + Float + FromPrimitive,
+    {
+        let x = match x.0.view().into_dimensionality::<Ix2>() {
+            Ok(x) if labels.0.shape() == [x.nrows()] => x,
+            _ => {
+                return Err(Error::KernelError(format!(
+                    "SilhouetteOp expected samples [n, f] and labels [n] but got {:?} and {:?}",
+                    x.0.shape(),
+                    labels.0.shape()
+                )))
+            }
+        };
+        let labels: Vec<u64> = labels.0.iter().map(|l| l.0).collect();
+
+        let mut sizes: BTreeMap<u64, usize> = BTreeMap::new();
+        for l in labels.iter() {
+            *sizes.entry(*l).or_insert(0) += 1;
+        }
+        if sizes.len() < 2 {
+            return Err(Error::KernelError(
+                "SilhouetteOp requires at least two clusters".to_string(),
+            ));
+        }
+
+        let dists = squared_distances(x, x).mapv(T::sqrt);
+        let scores: Vec<T> = dists
+            .outer_iter()
+            .zip(labels.iter())
+            .enumerate()
+            .map(|(i, (row, own))| {
+                if sizes[own] == 1 {
+                    return T::zero();
+                }
+                let mut sums: BTreeMap<u64, T> = BTreeMap::new();
+                for (j, (d, l)) in row.iter().zip(labels.iter()).enumerate() {
+                    if j != i {
+                        let sum = sums.entry(*l).or_insert_with(T::zero);
+                        *sum = *sum + *d;
+                    }
+                }
+                let a = sums[own] / T::from_usize(sizes[own] - 1).unwrap();
+                let b = sums
+                    .iter()
+                    .filter(|(l, _)| *l != own)
+                    .map(|(l, sum)| *sum / T::from_usize(sizes[l]).unwrap())
+                    .fold(T::infinity(), T::min);
+                let denom = a.max(b);
+                if denom > T::zero() {
+                    (b - a) / denom
+                } else {
+                    T::zero()
+                }
+            })
+            .collect();
+
+        let res = if average {
+            let total = scores.iter().fold(T::zero(), |acc, s| acc + *s);
+            Array::from_elem(IxDyn(&[]), total / T::from_usize(scores.len()).unwrap())
+        } else {
+            Array::from_vec(scores).into_dyn()
+        };
+        Ok(HostTensor(res.into_shared(), plc.clone()))
+    }
+}
+
+impl KnnOp {
+    /// Indices of the `k` rows of `reference` nearest to every query row in `x`.
+    ///
+    /// With `x` of shape `[m, f]` and `reference` of shape `[n, f]` the result has shape
+    /// `[m, k]`, with neighbours ordered by increasing Euclidean distance and ties broken in
+    /// favour of the lowest index.
+    pub(crate) fn host_kernel<S: RuntimeSession, T>(
+        _sess: &S,
+        plc: &HostPlacement,
+        k: usize,
+        x: HostTensor<T>,
+        reference: HostTensor<T>,
+    ) -> Result<HostRing64Tensor>
+    where
+        T: LinalgScalar + Float,
+    {
+        let (x, r) = sample_views("KnnOp", "reference", &x.0, &reference.0)?;
+        if k == 0 || k > r.nrows() {
+            return Err(Error::KernelError(format!(
+                "KnnOp cannot select {} neighbours out of {} reference rows",
+                k,
+                r.nrows()
+            )));
+        }
+
+        let dists = squared_distances(x, r);
+        let mut neighbours = Array2::from_elem((x.nrows(), k), Wrapping(0));
+        for (row, mut out) in dists.outer_iter().zip(neighbours.outer_iter_mut()) {
+            let order = argsort(&row.to_vec());
+            for (o, i) in out.iter_mut().zip(order) {
+                *o = Wrapping(i as u64);
+            }
+        }
+        Ok(HostRingTensor(
+            neighbours.into_dyn().into_shared(),
+            plc.clone(),
+        ))
+    }
+}
+
+impl KnnVoteOp {
+    /// Predicted label of every query from the labels of its nearest neighbours.
+    ///
+    /// `indices` and `distances` both have shape `[m, k]` and index into the reference
+    /// `labels` of shape `[n]`. Every neighbour votes for its label, with unit weight or,
+    /// when `distance` is set, with weight inversely proportional to its distance; if some
+    /// neighbours coincide with the query only they vote. Ties go to the smallest label.
+    pub(crate) fn host_kernel<S: RuntimeSession, T>(
+        _sess: &S,
+        plc: &HostPlacement,
+        distance: bool,
+        indices: HostRing64Tensor,
+        distances: HostTensor<T>,
+        labels: HostRing64Tensor,
+    ) -> Result<HostRing64Tensor>
+    where
+        T: Float,
+    {
+        let (indices, distances) = match (
+            indices.0.view().into_dimensionality::<Ix2>(),
+            distances.0.view().into_dimensionality::<Ix2>(),
+        ) {
+            (Ok(i), Ok(d)) if i.shape() == d.shape() && labels.0.ndim() == 1 => (i, d),
+            _ => {
+                return Err(Error::KernelError(format!(
+                    "KnnVoteOp expected indices [m, k], distances [m, k], and labels [n] but got {:?}, {:?}, and {:?}",
+                    indices.0.shape(),
+                    distances.0.shape(),
+                    labels.0.shape()
+                )))
+            }
+        };
+
+        let labels: Vec<u64> = labels.0.iter().map(|l| l.0).collect();
+
+        let mut votes = Vec::with_capacity(indices.nrows());
+        for (idx, dist) in indices.outer_iter().zip(distances.outer_iter()) {
+            let exact = dist.iter().any(|d| *d == T::zero());
+            let mut tally: BTreeMap<u64, T> = BTreeMap::new();
+            for (i, d) in idx.iter().zip(dist.iter()) {
+                let label = labels.get(i.0 as usize).ok_or_else(|| {
+                    Error::KernelError(format!(
+                        "KnnVoteOp neighbour index {} is out of bounds for {} labels",
+                        i.0,
+                        labels.len()
+                    ))
+                })?;
+                let weight = match (distance, exact) {
+                    (false, _) => T::one(),
+                    (true, false) => T::one() / *d,
+                    (true, true) if *d == T::zero() => T::one(),
+                    (true, true) => T::zero(),
+                };
+                let w = tally.entry(*label).or_insert_with(T::zero);
+                *w = *w + weight;
+            }
+
+            let mut best: Option<(u64, T)> = None;
+            for (label, w) in tally {
+                match best {
+                    Some((_, bw)) if bw >= w => (),
+                    _ => best = Some((label, w)),
+                }
+            }
+            votes.push(Wrapping(best.map(|(l, _)| l).unwrap_or(0)));
+        }
+        Ok(HostRingTensor(
+            Array::from_vec(votes).into_dyn().into_shared(),
+            plc.clone(),
+        ))
+    }
+}
+
+/// Views of the feature matrix `x`, weight vector `w`, and scalar bias `b` of a linear model.
+fn linear_model_views<'a, T>(
+    op: &str,
+    x: &'a ArcArrayD<T>,
+    w: &'a ArcArrayD<T>,
+    b: &'a ArcArrayD<T>,
+) -> Result<(ArrayView2<'a, T>, ArrayView1<'a, T>, &'a T)> {
+    match (
+        x.view().into_dimensionality::<Ix2>(),
+        w.view().into_dimensionality::<Ix1>(),
+        b.iter().next(),
+    ) {
+        (Ok(x), Ok(w), Some(bias)) if x.ncols() == w.len() && b.len() == 1 => Ok((x, w, bias)),
+        _ => Err(Error::KernelError(format!(
+            "{} expected features [n, f], weights [f], and a scalar bias but got {:?}, {:?}, and {:?}",
+            op,
+            x.shape(),
+            w.shape(),
+            b.shape()
+        ))),
+    }
+}
+
+impl LinearPredictOp {
+    /// Linear model predictions `x.w + b`.
+    ///
+    /// With `x` of shape `[n, f]`, `weights` of shape `[f]`, and a scalar `bias` the result
+    /// has shape `[n]`.
+    pub(crate) fn host_kernel<S: RuntimeSession, T>(
+        _sess: &S,
+        plc: &HostPlacement,
+        x: HostTensor<T>,
+        weights: HostTensor<T>,
+        bias: HostTensor<T>,
+    ) -> Result<HostTensor<T>>
+    where
+        T: LinalgScalar,
+    {
+        let (x, w, b) = linear_model_views("LinearPredictOp", &x.0, &weights.0, &bias.0)?;
+        let res = x.dot(&w).mapv(|v| v + *b);
+        Ok(HostTensor(res.into_dyn().into_shared(), plc.clone()))
+    }
+}
+
+impl LogisticPredictOp {
+    /// Logistic regression predictions `sigmoid(x.w + b)`.
+    ///
+    /// Shapes are as for `LinearPredictOp`. The result holds the probabilities of the
+    /// positive class or, when `threshold` is set, the hard predictions `p >= 0.5` as zeros
+    /// and ones.
+    pub(crate) fn host_kernel<S: RuntimeSession, T>(
+        sess: &S,
+        plc: &HostPlacement,
+        threshold: bool,
+        x: HostTensor<T>,
+        weights: HostTensor<T>,
+        bias: HostTensor<T>,
+    ) -> Result<HostTensor<T>>
+    where
+        T: 'static + LinalgScalar + Float + FromPrimitive,
+        HostPlacement: PlacementPlace<S, HostTensor<T>>,
+    {
+        let logits = LinearPredictOp::host_kernel(sess, plc, x, weights, bias)?;
+        let probs = SigmoidOp::host_kernel(sess, plc, logits)?;
+        if !threshold {
+            return Ok(probs);
+        }
+        let half = T::from_f64(0.5).unwrap();
+        let labels = probs
+            .0
+            .mapv(|p| if p >= half { T::one() } else { T::zero() });
+        Ok(HostTensor(labels.into_shared(), plc.clone()))
+    }
+}
+
+/// Node of a decision tree; leaves have no children.
+struct TreeNode<T> {
+    feature: usize,
+    threshold: T,
+    children: Option<(usize, usize)>,
+    value: T,
+}
+
+/// Decode and validate a decision tree over `features` features.
+///
+/// Row `i` of `nodes` holds the feature index and the left and right children of node `i`,
+/// and row `i` of `params` its threshold and leaf value. Node 0 is the root and children
+/// must come after their parent, so a node with both children set to 0 is a leaf.
+fn decode_tree<T: Copy>(
+    op: &str,
+    nodes: ArrayView2<Wrapping<u64>>,
+    params: ArrayView2<T>,
+    features: usize,
+) -> Result<Vec<TreeNode<T>>> {
+    let size = nodes.nrows();
+    if size == 0 || nodes.ncols() != 3 || params.shape() != [size, 2] {
+        return Err(Error::KernelError(format!(
+            "{} expected tree nodes [m, 3] and parameters [m, 2] but got {:?} and {:?}",
+            op,
+            nodes.shape(),
+            params.shape()
+        )));
+    }
+    nodes
+        .outer_iter()
+        .zip(params.outer_iter())
+        .enumerate()
+        .map(|(i, (node, param))| {
+            let (feature, left, right) = (node[0].0, node[1].0, node[2].0);
+            let children = match (left, right) {
+                (0, 0) => None,
+                (l, r) if (i as u64) < l.min(r)
+                    && l.max(r) < size as u64
+                    && feature < features as u64 =>
+                {
+                    Some((l as usize, r as usize))
+                }
+                _ => {
+                    return Err(Error::KernelError(format!(
+                        "{} found malformed node {} with feature {} and children {} and {} in a tree of {} nodes over {} features",
+                        op, i, feature, left, right, size, features
+                    )))
+                }
+            };
+            Ok(TreeNode {
+                feature: feature as usize,
+                threshold: param[0],
+                children,
+                value: param[1],
+            })
+        })
+        .collect()
+}
+
+/// Leaf value reached by `row`, going left whenever its feature is at most the threshold.
+fn apply_tree<T: Copy + PartialOrd>(tree: &[TreeNode<T>], row: ArrayView1<T>) -> T {
+    let mut node = &tree[0];
+    while let Some((left, right)) = node.children {
+        node = if row[node.feature] <= node.threshold {
+            &tree[left]
+        } else {
+            &tree[right]
+        };
+    }
+    node.value
+}
+
+/// View of the feature matrix `x` of shape `[n, f]`.
+fn feature_matrix<'a, T>(op: &str, x: &'a ArcArrayD<T>) -> Result<ArrayView2<'a, T>> {
+    x.view().into_dimensionality::<Ix2>().map_err(|_| {
+        Error::KernelError(format!(
+            "{} expected features [n, f] but got {:?}",
+            op,
+            x.shape()
+        ))
+    })
+}
+
+impl TreeApplyOp {
+    /// Leaf value of a decision tree for every sample in `x`.
+    ///
+    /// With `x` of shape `[n, f]` the result has shape `[n]`; see `decode_tree` for the
+    /// encoding of the tree in `nodes` and `params`.
+    pub(crate) fn host_kernel<S: RuntimeSession, T>(
+        _sess: &S,
+        plc: &HostPlacement,
+        x: HostTensor<T>,
+        nodes: HostRing64Tensor,
+        params: HostTensor<T>,
+    ) -> Result<HostTensor<T>>
+    where
+        T: Copy + PartialOrd,
+    {
+        let x = feature_matrix("TreeApplyOp", &x.0)?;
+        let (nodes, params) = match (
+            nodes.0.view().into_dimensionality::<Ix2>(),
+            params.0.view().into_dimensionality::<Ix2>(),
+        ) {
+            (Ok(n), Ok(p)) => (n, p),
+            _ => {
+                return Err(Error::KernelError(format!(
+                    "TreeApplyOp expected tree nodes [m, 3] and parameters [m, 2] but got {:?} and {:?}",
+                    nodes.0.shape(),
+                    params.0.shape()
+                )))
+            }
+        };
+        let tree = decode_tree("TreeApplyOp", nodes, params, x.ncols())?;
+        let res = x.map_axis(Axis(1), |row| apply_tree(&tree, row));
+        Ok(HostTensor(res.into_dyn().into_shared(), plc.clone()))
+    }
+}
+
+/// Decode a batch of trees stacked along the leading axis of `nodes` and `params`.
+fn decode_forest<T: Copy>(
+    op: &str,
+    nodes: &ArcArrayD<Wrapping<u64>>,
+    params: &ArcArrayD<T>,
+    features: usize,
+) -> Result<Vec<Vec<TreeNode<T>>>> {
+    match (
+        nodes.view().into_dimensionality::<Ix3>(),
+        params.view().into_dimensionality::<Ix3>(),
+    ) {
+        (Ok(n), Ok(p)) if n.len_of(Axis(0)) == p.len_of(Axis(0)) && !n.is_empty() => n
+            .outer_iter()
+            .zip(p.outer_iter())
+            .map(|(n, p)| decode_tree(op, n, p, features))
+            .collect(),
+        _ => Err(Error::KernelError(format!(
+            "{} expected tree nodes [t, m, 3] and parameters [t, m, 2] but got {:?} and {:?}",
+            op,
+            nodes.shape(),
+            params.shape()
+        ))),
+    }
+}
+
+impl ForestApplyOp {
+    /// Combined output of a forest of decision trees for every sample in `x`.
+    ///
+    /// The `t` trees are stacked in `nodes` of shape `[t, m, 3]` and `params` of shape
+    /// `[t, m, 2]`, each encoded as for `TreeApplyOp` and padded with leaves to a common
+    /// size. Tree outputs are averaged or, when `classify` is set, treated as class labels
+    /// and majority voted, with ties going to the smallest label.
+    pub(crate) fn host_kernel<S: RuntimeSession, T>(
+        _sess: &S,
+        plc: &HostPlacement,
+        classify: bool,
+        x: HostTensor<T>,
+        nodes: HostRing64Tensor,
+        params: HostTensor<T>,
+    ) -> Result<HostTensor<T>>
+    where
+        T: Float + FromPrimitive,
+    {
+        let x = feature_matrix("ForestApplyOp", &x.0)?;
+        let forest = decode_forest("ForestApplyOp", &nodes.0, &params.0, x.ncols())?;
+        let trees = T::from_usize(forest.len()).unwrap();
+
+        let res = x.map_axis(Axis(1), |row| {
+            let outputs = forest.iter().map(|tree| apply_tree(tree, row));
+            if !classify {
+                return outputs.fold(T::zero(), |acc, v| acc + v) / trees;
+            }
+            let mut votes: Vec<(T, usize)> = Vec::new();
+            for v in outputs {
+                match votes.iter_mut().find(|(label, _)| *label == v) {
+                    Some((_, count)) => *count += 1,
+                    None => votes.push((v, 1)),
+                }
+            }
+            votes
+                .into_iter()
+                .fold(
+                    None,
+                    |best: Option<(T, usize)>, (label, count)| match best {
+                        Some((l, c)) if c > count || (c == count && l < label) => Some((l, c)),
+                        _ => Some((label, count)),
+                    },
+                )
+                .map(|(label, _)| label)
+                .unwrap_or_else(T::zero)
+        });
+        Ok(HostTensor(res.into_dyn().into_shared(), plc.clone()))
+    }
+}
+
+impl GbdtApplyOp {
+    /// Raw score of a gradient boosted tree ensemble for every sample in `x`.
+    ///
+    /// The trees are encoded as for `ForestApplyOp` and the result is `base_score` plus
+    /// `learning_rate` times the sum of their outputs.
+    pub(crate) fn host_kernel<S: RuntimeSession, T>(
+        _sess: &S,
+        plc: &HostPlacement,
+        learning_rate: f64,
+        base_score: f64,
+        x: HostTensor<T>,
+        nodes: HostRing64Tensor,
+        params: HostTensor<T>,
+    ) -> Result<HostTensor<T>>
+    where
+        T: Float + FromPrimitive,
+    {
+        let x = feature_matrix("GbdtApplyOp", &x.0)?;
+        let forest = decode_forest("GbdtApplyOp", &nodes.0, &params.0, x.ncols())?;
+        let learning_rate = T::from_f64(learning_rate).unwrap();
+        let base_score = T::from_f64(base_score).unwrap();
+
+        let res = x.map_axis(Axis(1), |row| {
+            forest.iter().fold(base_score, |acc, tree| {
+                acc + learning_rate * apply_tree(tree, row)
+            })
+        });
+        Ok(HostTensor(res.into_dyn().into_shared(), plc.clone()))
+    }
+}
+
+impl GaussianNbOp {
+    /// Most likely class of every sample in `x` under a Gaussian naive Bayes model.
+    ///
+    /// With `x` of shape `[n, f]`, the per-class feature means and variances are stacked in
+    /// `stats` of shape `[2, c, f]` and the class priors are given in `priors` of shape `[c]`.
+    /// `eps` is added to every variance so that constant features remain usable. The result
+    /// has shape `[n]`, with ties in the joint log-likelihood going to the lowest class.
+    pub(crate) fn host_kernel<S: RuntimeSession, T>(
+        _sess: &S,
+        plc: &HostPlacement,
+        eps: f64,
+        x: HostTensor<T>,
+        stats: HostTensor<T>,
+        priors: HostTensor<T>,
+    ) -> Result<HostRing64Tensor>
+    where
+        T: Float + FromPrimitive,
+    {
+        let x = feature_matrix("GaussianNbOp", &x.0)?;
+        let (means, vars) = match stats.0.view().into_dimensionality::<Ix3>() {
+            Ok(s)
+                if s.len_of(Axis(0)) == 2
+                    && s.len_of(Axis(2)) == x.ncols()
+                    && priors.0.shape() == [s.len_of(Axis(1))] =>
+            {
+                (s.index_axis_move(Axis(0), 0), s.index_axis_move(Axis(0), 1))
+            }
+            _ => {
+                return Err(Error::KernelError(format!(
+                    "GaussianNbOp expected features [n, f], stats [2, c, f], and priors [c] but got {:?}, {:?}, and {:?}",
+                    x.shape(),
+                    stats.0.shape(),
+                    priors.0.shape()
+                )))
+            }
+        };
+
+        let eps = T::from_f64(eps).unwrap();
+        let half = T::from_f64(0.5).unwrap();
+        let two_pi = T::from_f64(2.0 * std::f64::consts::PI).unwrap();
+        let vars = vars.mapv(|v| v + eps);
+        // the terms of the log-likelihood that do not depend on the sample
+        let offsets: Vec<T> = vars
+            .outer_iter()
+            .zip(priors.0.iter())
+            .map(|(var, prior)| {
+                prior.ln()
+                    - half
+                        * var
+                            .iter()
+                            .fold(T::zero(), |acc, v| acc + (two_pi * *v).ln())
+            })
+            .collect();
+
+        let predictions = x.map_axis(Axis(1), |row| {
+            let mut best: Option<(usize, T)> = None;
+            for (c, ((mean, var), offset)) in means
+                .outer_iter()
+                .zip(vars.outer_iter())
+                .zip(offsets.iter())
+                .enumerate()
+            {
+                let dist = Zip::from(&row)
+                    .and(&mean)
+                    .and(&var)
+                    .fold(T::zero(), |acc, x, m, v| acc + (*x - *m) * (*x - *m) / *v);
+                let ll = *offset - half * dist;
+                match best {
+                    Some((_, b)) if b >= ll => (),
+                    _ => best = Some((c, ll)),
+                }
+            }
+            Wrapping(best.map(|(c, _)| c as u64).unwrap_or(0))
+        });
+        Ok(HostRingTensor(
+            predictions.into_dyn().into_shared(),
+            plc.clone(),
+        ))
+    }
+}
+
+impl MahalanobisOp {
+    /// Mahalanobis distance of every sample in `x` from `mean` under the inverse covariance `inv_cov`.
+    ///
+    /// With `x` of shape `[n, f]`, `mean` of shape `[f]`, and `inv_cov` of shape `[f, f]` the
+    /// result has shape `[n]`.
+    pub(crate) fn host_kernel<S: RuntimeSession, T>(
+        sess: &S,
+        plc: &HostPlacement,
+        x: HostTensor<T>,
+        mean: HostTensor<T>,
+        inv_cov: HostTensor<T>,
+    ) -> Result<HostTensor<T>>
+    where
+        T: LinalgScalar + Float + FromPrimitive,
+        HostPlacement: PlacementPlace<S, HostTensor<T>>,
+    {
+        let features = feature_matrix("MahalanobisOp", &x.0)?;
+        let f = features.ncols();
+        if mean.0.shape() != [f] || inv_cov.0.shape() != [f, f] {
+            return Err(Error::KernelError(format!(
+                "MahalanobisOp expected features [n, f], mean [f], and inverse covariance [f, f] but got {:?}, {:?}, and {:?}",
+                features.shape(),
+                mean.0.shape(),
+                inv_cov.0.shape()
+            )));
+        }
+
+        let centered = HostTensor((&x.0 - &mean.0).into_shared(), plc.clone());
+        let projected = DotOp::host_kernel(sess, plc, centered.clone(), inv_cov)?;
+        let dists = (&projected.0 * &centered.0)
+            .sum_axis(Axis(1))
+            .mapv(|d| d.max(T::zero()).sqrt());
+        Ok(HostTensor(dists.into_shared(), plc.clone()))
+    }
+}
+
+impl DbscanOp {
+    /// DBSCAN cluster label of every sample given the pairwise `distances` of shape `[n, n]`.
+    ///
+    /// Samples with at least `min_samples` samples, themselves included, within distance `eps`
+    /// are core samples. Clusters are numbered from zero in order of their first core sample,
+    /// and noise is labelled -1, i.e. `2^64 - 1` in the ring.
+    pub(crate) fn host_kernel<S: RuntimeSession, T>(
+        _sess: &S,
+        plc: &HostPlacement,
+        eps: f64,
+        min_samples: usize,
+        distances: HostTensor<T>,
+    ) -> Result<HostRing64Tensor>
+    where
+        T: Float + FromPrimitive,
+    {
+        let distances = match distances.0.view().into_dimensionality::<Ix2>() {
+            Ok(d) if d.is_square() => d,
+            _ => {
+                return Err(Error::KernelError(format!(
+                    "DbscanOp expected distances [n, n] but got {:?}",
+                    distances.0.shape()
+                )))
+            }
+        };
+
+        let eps = T::from_f64(eps).unwrap();
+        let neighbours: Vec<Vec<usize>> = distances
+            .outer_iter()
+            .map(|row| {
+                row.iter()
+                    .enumerate()
+                    .filter(|(_, d)| **d <= eps)
+                    .map(|(j, _)| j)
+                    .collect()
+            })
+            .collect();
+        let is_core = |i: usize| neighbours[i].len() >= min_samples;
+
+        let mut labels: Vec<Option<u64>> = vec![None; neighbours.len()];
+        let mut next_label = 0;
+        for (i, neighbourhood) in neighbours.iter().enumerate() {
+            if labels[i].is_some() || neighbourhood.len() < min_samples {
+                continue;
+            }
+            // expand the cluster through the neighbourhoods of its core samples
+            labels[i] = Some(next_label);
+            let mut frontier = vec![i];
+            while let Some(j) = frontier.pop() {
+                for &k in neighbours[j].iter() {
+                    if labels[k].is_none() {
+                        labels[k] = Some(next_label);
+                        if is_core(k) {
+                            frontier.push(k);
+                        }
+                    }
+                }
+            }
+            next_label += 1;
+        }
+
+        let labels: Vec<Wrapping<u64>> = labels
+            .into_iter()
+            .map(|l| Wrapping(l.unwrap_or(u64::MAX)))
+            .collect();
+        Ok(HostRingTensor(
+            Array::from_vec(labels).into_dyn().into_shared(),
+            plc.clone(),
+        ))
+    }
+}
+
+impl ClosestPairOp {
+    /// Closest pair of distinct clusters given their pairwise `distances` of shape `[n, n]`.
+    ///
+    /// The result has shape `[3]` and holds the indices `i < j` of the pair followed by their
+    /// distance, with ties going to the lexicographically smallest pair. Only the entries above
+    /// the diagonal are inspected.
+    pub(crate) fn host_kernel<S: RuntimeSession, T>(
+        _sess: &S,
+        plc: &HostPlacement,
+        distances: HostTensor<T>,
+    ) -> Result<HostTensor<T>>
+    where
+        T: Float + FromPrimitive,
+    {
+        let distances = match distances.0.view().into_dimensionality::<Ix2>() {
+            Ok(d) if d.is_square() && d.nrows() >= 2 => d,
+            _ => {
+                return Err(Error::KernelError(format!(
+                    "ClosestPairOp expected distances [n, n] with n >= 2 but got {:?}",
+                    distances.0.shape()
+                )))
+            }
+        };
+
+        let mut best: Option<(usize, usize, T)> = None;
+        for ((i, j), d) in distances.indexed_iter() {
+            if j <= i {
+                continue;
+            }
+            match best {
+                Some((_, _, b)) if b <= *d => (),
+                _ => best = Some((i, j, *d)),
+            }
+        }
+        // at least one pair exists since n >= 2
+        let (i, j, d) = best.unwrap();
+        let res = Array::from_vec(vec![
+            T::from_usize(i).unwrap(),
+            T::from_usize(j).unwrap(),
+            d,
+        ]);
+        Ok(HostTensor(res.into_dyn().into_shared(), plc.clone()))
+    }
+}
+
+impl TfidfOp {
+    /// TF-IDF weights `tf * idf` of the term `counts` of shape `[n, t]`.
+    ///
+    /// The document frequencies `doc_freqs` of shape `[t]` are taken over a corpus of `num_docs`
+    /// documents, giving `idf = ln(num_docs / df)`. With `smooth` set, one is added to both the
+    /// number of documents and the frequencies, `idf = ln((1 + num_docs) / (1 + df))`, so that
+    /// terms absent from the corpus remain finite.
+    pub(crate) fn host_kernel<S: RuntimeSession, T>(
+        _sess: &S,
+        plc: &HostPlacement,
+        num_docs: usize,
+        smooth: bool,
+        counts: HostTensor<T>,
+        doc_freqs: HostTensor<T>,
+    ) -> Result<HostTensor<T>>
+    where
+        T: Float + FromPrimitive,
+    {
+        let counts_view = feature_matrix("TfidfOp", &counts.0)?;
+        if doc_freqs.0.shape() != [counts_view.ncols()] {
+            return Err(Error::KernelError(format!(
+                "TfidfOp expected counts [n, t] and document frequencies [t] but got {:?} and {:?}",
+                counts_view.shape(),
+                doc_freqs.0.shape()
+            )));
+        }
+        if !smooth && doc_freqs.0.iter().any(|df| *df <= T::zero()) {
+            return Err(Error::KernelError(
+                "TfidfOp expected positive document frequencies without smoothing".to_string(),
+            ));
+        }
+
+        let offset = if smooth { T::one() } else { T::zero() };
+        let num_docs = T::from_usize(num_docs).unwrap() + offset;
+        let idf = doc_freqs.0.mapv(|df| (num_docs / (df + offset)).ln());
+        let res = &counts.0 * &idf;
+        Ok(HostTensor(res.into_shared(), plc.clone()))
+    }
+}
+
+/// 64-bit FNV-1a hash of a sequence of tokens, stable across platforms and releases.
+fn fnv1a(tokens: &[Wrapping<u64>]) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0100_0000_01b3;
+    tokens
+        .iter()
+        .flat_map(|token| token.0.to_le_bytes())
+        .fold(OFFSET_BASIS, |hash, byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(PRIME)
+        })
+}
+
+impl NgramHashOp {
+    /// Counts of the hashed `n`-grams of every document in `tokens` of shape `[d, l]`.
+    ///
+    /// Every run of `n` consecutive token ids is hashed with FNV-1a into one of `num_buckets`
+    /// buckets, giving counts of shape `[d, num_buckets]`.
+    pub(crate) fn host_kernel<S: RuntimeSession>(
+        _sess: &S,
+        plc: &HostPlacement,
+        n: usize,
+        num_buckets: usize,
+        tokens: HostRing64Tensor,
+    ) -> Result<HostRing64Tensor> {
+        if n == 0 || num_buckets == 0 {
+            return Err(Error::KernelError(format!(
+                "NgramHashOp expected positive n and number of buckets but got {} and {}",
+                n, num_buckets
+            )));
+        }
+        let tokens = tokens.0.view().into_dimensionality::<Ix2>().map_err(|_| {
+            Error::KernelError(format!(
+                "NgramHashOp expected tokens [d, l] but got {:?}",
+                tokens.0.shape()
+            ))
+        })?;
+
+        let mut counts = Array2::from_elem((tokens.nrows(), num_buckets), Wrapping(0));
+        for (doc, mut row) in tokens.outer_iter().zip(counts.outer_iter_mut()) {
+            let doc = doc.to_vec();
+            for ngram in doc.windows(n) {
+                let bucket = (fnv1a(ngram) % num_buckets as u64) as usize;
+                row[bucket] += Wrapping(1);
+            }
+        }
+        Ok(HostRingTensor(counts.into_dyn().into_shared(), plc.clone()))
+    }
+}
+
+impl BagOfWordsOp {
+    /// Counts of every token id of the vocabulary in each document of `tokens` of shape `[d, l]`.
+    ///
+    /// Documents shorter than `l` are padded with the `padding` token id, which is not counted.
+    /// All other token ids must be below `vocab_size`, and the result has shape `[d, vocab_size]`.
+    pub(crate) fn host_kernel<S: RuntimeSession>(
+        _sess: &S,
+        plc: &HostPlacement,
+        vocab_size: usize,
+        padding: u64,
+        tokens: HostRing64Tensor,
+    ) -> Result<HostRing64Tensor> {
+        let tokens = tokens.0.view().into_dimensionality::<Ix2>().map_err(|_| {
+            Error::KernelError(format!(
+                "BagOfWordsOp expected tokens [d, l] but got {:?}",
+                tokens.0.shape()
+            ))
+        })?;
+
+        let mut counts = Array2::from_elem((tokens.nrows(), vocab_size), Wrapping(0));
+        for (doc, mut row) in tokens.outer_iter().zip(counts.outer_iter_mut()) {
+            for token in doc.iter().filter(|token| token.0 != padding) {
+                if token.0 >= vocab_size as u64 {
+                    return Err(Error::KernelError(format!(
+                        "BagOfWordsOp expected token ids below {} but got {}",
+                        vocab_size, token
+                    )));
+                }
+                row[token.0 as usize] += Wrapping(1);
+            }
+        }
+        Ok(HostRingTensor(counts.into_dyn().into_shared(), plc.clone()))
+    }
+}
+
+impl SparseToDenseOp {
+    /// Dense tensor of the given `shape` with `values` placed at the coordinates in `indices`.
+    ///
+    /// With `shape` of rank `r`, `indices` has shape `[k, r]` and `values` shape `[k]`. Values
+    /// at duplicate coordinates are summed, and all other entries are zero.
+    pub(crate) fn host_kernel<S: RuntimeSession, T>(
+        _sess: &S,
+        plc: &HostPlacement,
+        indices: HostRing64Tensor,
+        values: HostTensor<T>,
+        shape: HostShape,
+    ) -> Result<HostTensor<T>>
+    where
+        T: LinalgScalar,
+    {
+        let dims = &shape.0 .0;
+        let coords = match indices.0.view().into_dimensionality::<Ix2>() {
+            Ok(c) if c.ncols() == dims.len() && values.0.shape() == [c.nrows()] => c,
+            _ => {
+                return Err(Error::KernelError(format!(
+                    "SparseToDenseOp expected indices [k, {}] and values [k] but got {:?} and {:?}",
+                    dims.len(),
+                    indices.0.shape(),
+                    values.0.shape()
+                )))
+            }
+        };
+
+        let mut dense = ArrayD::from_elem(IxDyn(dims), T::zero());
+        for (coord, value) in coords.outer_iter().zip(values.0.iter()) {
+            let coord: Vec<usize> = coord.iter().map(|c| c.0 as usize).collect();
+            match dense.get_mut(&coord[..]) {
+                Some(entry) => *entry = *entry + *value,
+                None => {
+                    return Err(Error::KernelError(format!(
+                        "SparseToDenseOp coordinate {:?} is out of range for shape {:?}",
+                        coord, dims
+                    )))
+                }
+            }
+        }
+        Ok(HostTensor(dense.into_shared(), plc.clone()))
+    }
+}
+
+impl DenseToSparseIndicesOp {
+    /// Coordinates of the `k` nonzero entries of `x` of rank `r`, as a tensor of shape `[k, r]`.
+    ///
+    /// Entries are listed in row-major order, matching `DenseToSparseValuesOp`, such that the
+    /// inverse is given by `SparseToDenseOp`.
+    pub(crate) fn host_kernel<S: RuntimeSession, T>(
+        _sess: &S,
+        plc: &HostPlacement,
+        x: HostTensor<T>,
+    ) -> Result<HostRing64Tensor>
+    where
+        T: LinalgScalar + PartialEq,
+    {
+        let rank = x.0.ndim();
+        let k = x.0.iter().filter(|v| **v != T::zero()).count();
+        let coords: Vec<Wrapping<u64>> =
+            x.0.indexed_iter()
+                .filter(|(_, v)| **v != T::zero())
+                .flat_map(|(idx, _)| {
+                    idx.slice()
+                        .iter()
+                        .map(|i| Wrapping(*i as u64))
+                        .collect::<Vec<_>>()
+                })
+                .collect();
+        let res = Array2::from_shape_vec((k, rank), coords)
+            .map_err(|e| Error::KernelError(e.to_string()))?;
+        Ok(HostRingTensor(res.into_dyn().into_shared(), plc.clone()))
+    }
+}
+
+impl DenseToSparseValuesOp {
+    /// Values of the `k` nonzero entries of `x`, as a tensor of shape `[k]`.
+    ///
+    /// Entries are listed in row-major order, matching `DenseToSparseIndicesOp`.
+    pub(crate) fn host_kernel<S: RuntimeSession, T>(
+        _sess: &S,
+        plc: &HostPlacement,
+        x: HostTensor<T>,
+    ) -> Result<HostTensor<T>>
+    where
+        T: LinalgScalar + PartialEq,
+    {
+        let values: Vec<T> = x.0.iter().filter(|v| **v != T::zero()).cloned().collect();
+        Ok(HostTensor(
+            Array::from_vec(values).into_dyn().into_shared(),
+            plc.clone(),
+        ))
+    }
+}
+
+/// Average path length of an unsuccessful search in a binary search tree over `n` samples.
+fn average_path_length(n: usize) -> f64 {
+    // Euler-Mascheroni constant used to approximate the harmonic number
+    const EULER_GAMMA: f64 = 0.577_215_664_901_532_9;
+    match n {
+        0 | 1 => 0.0,
+        2 => 1.0,
+        n => {
+            let n = n as f64;
+            2.0 * ((n - 1.0).ln() + EULER_GAMMA) - 2.0 * (n - 1.0) / n
+        }
+    }
+}
+
+impl IsolationScoreOp {
+    /// Anomaly score of every sample given its path length in each tree of an isolation forest.
+    ///
+    /// With `path_lengths` of shape `[n, t]` the result has shape `[n]` and is computed as
+    /// `2^(-E[h] / c(sample_size))`, where `E[h]` is the average path length of the sample and
+    /// `c` the average path length of an unsuccessful search over the training subsample size.
+    /// Scores close to one indicate anomalies.
+    pub(crate) fn host_kernel<S: RuntimeSession, T>(
+        _sess: &S,
+        plc: &HostPlacement,
+        sample_size: usize,
+        path_lengths: HostTensor<T>,
+    ) -> Result<HostTensor<T>>
+    where
+        T: Float + FromPrimitive,
+    {
+        if sample_size < 2 {
+            return Err(Error::KernelError(format!(
+                "IsolationScoreOp expected a sample size of at least 2 but got {}",
+                sample_size
+            )));
+        }
+        let path_lengths = match path_lengths.0.view().into_dimensionality::<Ix2>() {
+            Ok(h) if h.ncols() > 0 => h,
+            _ => {
+                return Err(Error::KernelError(format!(
+                    "IsolationScoreOp expected path lengths [n, t] with t > 0 but got {:?}",
+                    path_lengths.0.shape()
+                )))
+            }
+        };
+
+        let norm = T::from_f64(average_path_length(sample_size)).unwrap();
+        let two = T::from_f64(2.0).unwrap();
+        let scores = path_lengths
+            .mean_axis(Axis(1))
+            .unwrap()
+            .mapv(|h| two.powf(-h / norm));
+        Ok(HostTensor(scores.into_dyn().into_shared(), plc.clone()))
+    }
+}
+
+#[cfg(feature = "sync_execute")]
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_centroid_dist() {
+        let (sess, plc) = test_session();
+
+        let raw_x = array![[0.0, 0.0], [3.0, 4.0], [1.0, -1.0]];
+        let raw_c = array![[0.0, 0.0], [1.0, 1.0]];
+        let x: HostFloat64Tensor = plc.from_raw(raw_x.clone());
+        let c: HostFloat64Tensor = plc.from_raw(raw_c.clone());
+        let d = plc.centroid_dist(&sess, &x, &c);
+        assert_eq!(d.0.shape(), &[3, 2]);
+
+        // naive reference implementation
+        let expected: Vec<f64> = raw_x
+            .outer_iter()
+            .flat_map(|row| {
+                raw_c
+                    .outer_iter()
+                    .map(|centroid| {
+                        row.iter()
+                            .zip(centroid.iter())
+                            .map(|(a, b)| (a - b) * (a - b))
+                            .sum::<f64>()
+                            .sqrt()
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+        assert_close(&d, &expected);
+        assert_close(&d, &[0.0, 2f64.sqrt(), 5.0, 13f64.sqrt(), 2f64.sqrt(), 2.0]);
+    }
+
+    #[test]
+    fn test_centroid_dist_mismatch() {
+        let (sess, plc) = test_session();
+
+        let x: HostFloat64Tensor = plc.from_raw(array![[0.0, 0.0]]);
+        let c: HostFloat64Tensor = plc.from_raw(array![[0.0, 0.0, 0.0]]);
+        let res = CentroidDistOp::host_kernel(&sess, &plc, x, c);
+        assert!(matches!(res, Err(crate::error::Error::KernelError(_))));
+    }
+
+    #[test]
+    fn test_assign_clusters() {
+        let (sess, plc) = test_session();
+
+        // the last sample is equidistant from both centroids
+        let x: HostFloat64Tensor =
+            plc.from_raw(array![[0.0, 0.0], [3.0, 4.0], [0.9, 1.2], [0.5, 0.5]]);
+        let c: HostFloat64Tensor = plc.from_raw(array![[0.0, 0.0], [1.0, 1.0]]);
+        let assignments: HostRing64Tensor = plc.assign_clusters(&sess, &x, &c);
+
+        let d = plc.centroid_dist(&sess, &x, &c);
+        let expected: Vec<u64> =
+            d.0.outer_iter()
+                .map(|row| {
+                    let min = row.iter().cloned().fold(f64::INFINITY, f64::min);
+                    row.iter().position(|v| *v == min).unwrap() as u64
+                })
+                .collect();
+        assert_eq!(expected, vec![0, 1, 1, 0]);
+        let expected: HostRing64Tensor = plc.from_raw(expected);
+        assert_eq!(assignments, expected);
+    }
+
+    #[test]
+    fn test_silhouette() {
+        let (sess, plc) = test_session();
+
+        // two well-separated clusters and a singleton
+        let x: HostFloat64Tensor = plc.from_raw(array![
+            [0.0, 0.0],
+            [0.0, 1.0],
+            [10.0, 0.0],
+            [10.0, 1.0],
+            [50.0, 50.0]
+        ]);
+        let labels: HostRing64Tensor = plc.from_raw(array![0, 0, 1, 1, 2]);
+
+        // every paired sample is at distance 1 from its partner and at distances 10 and
+        // sqrt(101) from the other cluster
+        let s = 1.0 - 2.0 / (10.0 + 101f64.sqrt());
+
+        let scores = plc.silhouette(&sess, false, &x, &labels);
+        assert_close(&scores, &[s, s, s, s, 0.0]);
+
+        let mean = plc.silhouette(&sess, true, &x, &labels);
+        assert_eq!(mean.0.shape(), &[] as &[usize]);
+        assert_close(&mean, &[4.0 * s / 5.0]);
+    }
+
+    #[test]
+    fn test_silhouette_single_cluster() {
+        let (sess, plc) = test_session();
+
+        let x: HostFloat64Tensor = plc.from_raw(array![[0.0], [1.0]]);
+        let labels: HostRing64Tensor = plc.from_raw(array![3, 3]);
+        let res = SilhouetteOp::host_kernel(&sess, &plc, false, x, labels);
+        assert!(matches!(res, Err(crate::error::Error::KernelError(_))));
+    }
+
+    #[test]
+    fn test_knn() {
+        let (sess, plc) = test_session();
+
+        let raw_x = array![[0.0, 0.0], [4.0, 4.0]];
+        let raw_r = array![[1.0, 0.0], [5.0, 5.0], [0.0, 2.0], [3.0, 3.0], [-1.0, 0.0]];
+        let x: HostFloat64Tensor = plc.from_raw(raw_x.clone());
+        let r: HostFloat64Tensor = plc.from_raw(raw_r.clone());
+        let neighbours: HostRing64Tensor = plc.knn(&sess, 3, &x, &r);
+
+        // brute-force reference with a stable sort, keeping the lowest index on ties
+        let expected: Vec<u64> = raw_x
+            .outer_iter()
+            .flat_map(|q| {
+                let mut order: Vec<(f64, usize)> = raw_r
+                    .outer_iter()
+                    .enumerate()
+                    .map(|(i, p)| ((&q - &p).mapv(|v| v * v).sum(), i))
+                    .collect();
+                order.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+                order.into_iter().take(3).map(|(_, i)| i as u64)
+            })
+            .collect();
+        assert_eq!(expected, vec![0, 4, 2, 1, 3, 2]);
+        let expected: HostRing64Tensor =
+            plc.from_raw(Array::from_shape_vec((2, 3), expected).unwrap());
+        assert_eq!(neighbours, expected);
+    }
+
+    #[test]
+    fn test_knn_vote() {
+        let (sess, plc) = test_session();
+
+        let labels: HostRing64Tensor = plc.from_raw(array![1, 0, 0, 1]);
+        let indices: HostRing64Tensor = plc.from_raw(array![[0, 1, 2], [1, 3, 0]]);
+        let distances: HostFloat64Tensor = plc.from_raw(array![[0.1, 1.0, 2.0], [0.5, 0.6, 0.7]]);
+
+        // the first query has one close neighbour labelled 1 against two far ones labelled 0
+        let uniform: HostRing64Tensor = plc.knn_vote(&sess, false, &indices, &distances, &labels);
+        let expected: HostRing64Tensor = plc.from_raw(array![0, 1]);
+        assert_eq!(uniform, expected);
+
+        let weighted: HostRing64Tensor = plc.knn_vote(&sess, true, &indices, &distances, &labels);
+        let expected: HostRing64Tensor = plc.from_raw(array![1, 1]);
+        assert_eq!(weighted, expected);
+    }
+
+    #[test]
+    fn test_linear_predict() {
+        let (sess, plc) = test_session();
+
+        let raw_x = array![[1.0, 2.0, 3.0], [0.0, -1.0, 0.5]];
+        let raw_w = array![0.5, -1.0, 2.0];
+        let x: HostFloat64Tensor = plc.from_raw(raw_x.clone());
+        let w: HostFloat64Tensor = plc.from_raw(raw_w.clone());
+        let b: HostFloat64Tensor = plc.from_raw(array![0.25]);
+        let y = plc.linear_predict(&sess, &x, &w, &b);
+
+        let expected: Vec<f64> = raw_x
+            .outer_iter()
+            .map(|row| {
+                row.iter()
+                    .zip(raw_w.iter())
+                    .map(|(a, b)| a * b)
+                    .sum::<f64>()
+                    + 0.25
+            })
+            .collect();
+        assert_close(&y, &expected);
+        assert_close(&y, &[4.75, 2.25]);
+    }
+
+    #[test]
+    fn test_linear_predict_mismatch() {
+        let (sess, plc) = test_session();
+
+        let x: HostFloat64Tensor = plc.from_raw(array![[1.0, 2.0]]);
+        let w: HostFloat64Tensor = plc.from_raw(array![1.0, 2.0, 3.0]);
+        let b: HostFloat64Tensor = plc.from_raw(array![0.0]);
+        let res = LinearPredictOp::host_kernel(&sess, &plc, x, w, b);
+        assert!(matches!(res, Err(crate::error::Error::KernelError(_))));
+    }
+
+    #[test]
+    fn test_logistic_predict() {
+        let (sess, plc) = test_session();
+
+        let x: HostFloat64Tensor = plc.from_raw(array![[1.0, 2.0], [-3.0, 0.5], [0.0, 0.0]]);
+        let w: HostFloat64Tensor = plc.from_raw(array![0.5, -0.25]);
+        let b: HostFloat64Tensor = plc.from_raw(array![0.1]);
+
+        let logits: [f64; 3] = [0.5 - 0.5 + 0.1, -1.5 - 0.125 + 0.1, 0.1];
+        let expected: Vec<f64> = logits.iter().map(|z| 1.0 / (1.0 + (-z).exp())).collect();
+        let probs = plc.logistic_predict(&sess, false, &x, &w, &b);
+        assert_close(&probs, &expected);
+
+        let labels = plc.logistic_predict(&sess, true, &x, &w, &b);
+        assert_close(&labels, &[1.0, 0.0, 1.0]);
+    }
+
+    #[test]
+    fn test_tree_apply() {
+        let (sess, plc) = test_session();
+
+        // x0 <= 0.5 ? (x1 <= 1.0 ? 1.0 : 2.0) : 10.0
+        let nodes: HostRing64Tensor = plc.from_raw(array![
+            [0, 1, 2],
+            [1, 3, 4],
+            [0, 0, 0],
+            [0, 0, 0],
+            [0, 0, 0]
+        ]);
+        let params: HostFloat64Tensor = plc.from_raw(array![
+            [0.5, 0.0],
+            [1.0, 0.0],
+            [0.0, 10.0],
+            [0.0, 1.0],
+            [0.0, 2.0]
+        ]);
+        let x: HostFloat64Tensor =
+            plc.from_raw(array![[0.0, 0.0], [0.0, 2.0], [1.0, 0.0], [0.5, 1.0]]);
+        let y = plc.tree_apply(&sess, &x, &nodes, &params);
+        assert_close(&y, &[1.0, 2.0, 10.0, 1.0]);
+    }
+
+    #[test]
+    fn test_tree_apply_malformed() {
+        let (sess, plc) = test_session();
+
+        let x: HostFloat64Tensor = plc.from_raw(array![[0.0, 0.0]]);
+        let params: HostFloat64Tensor = plc.from_raw(array![[0.5, 0.0], [0.0, 1.0], [0.0, 2.0]]);
+
+        // a child pointing back at the root would loop forever
+        let nodes: HostRing64Tensor = plc.from_raw(array![[0, 1, 2], [1, 0, 2], [0, 0, 0]]);
+        let res = TreeApplyOp::host_kernel(&sess, &plc, x.clone(), nodes, params.clone());
+        assert!(matches!(res, Err(crate::error::Error::KernelError(_))));
+
+        // splitting on a feature the samples do not have
+        let nodes: HostRing64Tensor = plc.from_raw(array![[2, 1, 2], [0, 0, 0], [0, 0, 0]]);
+        let res = TreeApplyOp::host_kernel(&sess, &plc, x, nodes, params);
+        assert!(matches!(res, Err(crate::error::Error::KernelError(_))));
+    }
+
+    #[test]
+    fn test_forest_apply() {
+        let (sess, plc) = test_session();
+
+        // x0 <= 0.5 ? 1.0 : 3.0 and x1 <= 0.0 ? 2.0 : 4.0
+        let nodes: HostRing64Tensor = plc.from_raw(array![
+            [[0, 1, 2], [0, 0, 0], [0, 0, 0]],
+            [[1, 1, 2], [0, 0, 0], [0, 0, 0]]
+        ]);
+        let params: HostFloat64Tensor = plc.from_raw(array![
+            [[0.5, 0.0], [0.0, 1.0], [0.0, 3.0]],
+            [[0.0, 0.0], [0.0, 2.0], [0.0, 4.0]]
+        ]);
+        let x: HostFloat64Tensor =
+            plc.from_raw(array![[0.0, 0.0], [1.0, 0.0], [0.0, 1.0], [1.0, 1.0]]);
+
+        let y = plc.forest_apply(&sess, false, &x, &nodes, &params);
+        assert_close(&y, &[1.5, 2.5, 2.5, 3.5]);
+
+        // with two disagreeing trees every vote is a tie
+        let y = plc.forest_apply(&sess, true, &x, &nodes, &params);
+        assert_close(&y, &[1.0, 2.0, 1.0, 3.0]);
+    }
+
+    #[test]
+    fn test_gbdt_apply() {
+        let (sess, plc) = test_session();
+
+        // x0 <= 0.5 ? -1.0 : 2.0 followed by a correction x1 <= 0.0 ? 0.5 : -0.5
+        let nodes: HostRing64Tensor = plc.from_raw(array![
+            [[0, 1, 2], [0, 0, 0], [0, 0, 0]],
+            [[1, 1, 2], [0, 0, 0], [0, 0, 0]]
+        ]);
+        let params: HostFloat64Tensor = plc.from_raw(array![
+            [[0.5, 0.0], [0.0, -1.0], [0.0, 2.0]],
+            [[0.0, 0.0], [0.0, 0.5], [0.0, -0.5]]
+        ]);
+        let x: HostFloat64Tensor =
+            plc.from_raw(array![[0.0, 0.0], [1.0, 0.0], [0.0, 1.0], [1.0, 1.0]]);
+        let y = plc.gbdt_apply(&sess, 0.1, 0.25, &x, &nodes, &params);
+
+        let leaves = [[-1.0, 0.5], [2.0, 0.5], [-1.0, -0.5], [2.0, -0.5]];
+        let expected: Vec<f64> = leaves
+            .iter()
+            .map(|outputs| {
+                let mut acc = 0.25;
+                for v in outputs.iter() {
+                    acc += 0.1 * v;
+                }
+                acc
+            })
+            .collect();
+        assert_close(&y, &expected);
+    }
+
+    #[test]
+    fn test_gaussian_nb() {
+        let (sess, plc) = test_session();
+
+        let raw_x = array![[0.0, 0.0], [3.0, 3.0], [1.4, 1.4], [1.6, 1.6], [0.0, 5.0]];
+        let x: HostFloat64Tensor = plc.from_raw(raw_x.clone());
+        // class 0 is constant in the first feature
+        let stats: HostFloat64Tensor =
+            plc.from_raw(array![[[0.0, 0.0], [3.0, 3.0]], [[0.0, 1.0], [1.0, 1.0]]]);
+        let uniform: HostFloat64Tensor = plc.from_raw(array![0.5, 0.5]);
+        let skewed: HostFloat64Tensor = plc.from_raw(array![0.9, 0.1]);
+
+        // reference joint log-likelihoods, computed as in scikit-learn's GaussianNB
+        let reference = |priors: &[f64]| -> Vec<u64> {
+            let means = [[0.0, 0.0], [3.0, 3.0]];
+            let vars = [[1e-9, 1.0], [1.0, 1.0]];
+            raw_x
+                .outer_iter()
+                .map(|row| {
+                    let ll: Vec<f64> = (0..2)
+                        .map(|c| {
+                            let mut ll = priors[c].ln();
+                            for ((x, m), v) in row.iter().zip(means[c].iter()).zip(vars[c].iter()) {
+                                ll -= 0.5 * (2.0 * std::f64::consts::PI * v).ln();
+                                ll -= 0.5 * (x - m).powi(2) / v;
+                            }
+                            ll
+                        })
+                        .collect();
+                    if ll[1] > ll[0] {
+                        1
+                    } else {
+                        0
+                    }
+                })
+                .collect()
+        };
+
+        let y: HostRing64Tensor = plc.gaussian_nb(&sess, 1e-9, &x, &stats, &uniform);
+        let expected = reference(&[0.5, 0.5]);
+        assert_eq!(expected, vec![0, 1, 1, 1, 0]);
+        let expected: HostRing64Tensor = plc.from_raw(expected);
+        assert_eq!(y, expected);
+
+        let y: HostRing64Tensor = plc.gaussian_nb(&sess, 1e-9, &x, &stats, &skewed);
+        let expected: HostRing64Tensor = plc.from_raw(reference(&[0.9, 0.1]));
+        assert_eq!(y, expected);
+    }
+
+    #[test]
+    fn test_isolation_score() {
+        let (sess, plc) = test_session();
+
+        let h: HostFloat64Tensor =
+            plc.from_raw(array![[2.0, 3.0, 1.0], [9.0, 7.0, 8.0], [5.0, 5.0, 5.0]]);
+        let scores = plc.isolation_score(&sess, 256, &h);
+
+        let c = 2.0 * (255f64.ln() + 0.5772156649015329) - 2.0 * 255.0 / 256.0;
+        let expected: Vec<f64> = [2.0, 8.0, 5.0].iter().map(|h| 2f64.powf(-h / c)).collect();
+        assert_close(&scores, &expected);
+
+        // shorter average paths are more anomalous
+        let s: Vec<f64> = scores.0.iter().cloned().collect();
+        assert!(s[0] > s[2] && s[2] > s[1]);
+    }
+
+    #[test]
+    fn test_isolation_score_sample_size() {
+        let (sess, plc) = test_session();
+
+        let h: HostFloat64Tensor = plc.from_raw(array![[1.0]]);
+        let res = IsolationScoreOp::host_kernel(&sess, &plc, 1, h);
+        assert!(matches!(res, Err(crate::error::Error::KernelError(_))));
+    }
+
+    #[test]
+    fn test_mahalanobis() {
+        let (sess, plc) = test_session();
+
+        let x: HostFloat64Tensor = plc.from_raw(array![[1.0, 2.0], [3.0, 0.0], [2.0, 1.0]]);
+        let mean: HostFloat64Tensor = plc.from_raw(array![2.0, 1.0]);
+        let inv_cov: HostFloat64Tensor = plc.from_raw(array![[2.0, 0.5], [0.5, 1.0]]);
+        let d = plc.mahalanobis(&sess, &x, &mean, &inv_cov);
+
+        // d^2 = 2 dx^2 + dx dy + dy^2 for the deltas (-1, 1), (1, -1), and (0, 0)
+        assert_close(&d, &[2f64.sqrt(), 2f64.sqrt(), 0.0]);
+    }
+
+    #[test]
+    fn test_mahalanobis_mismatch() {
+        let (sess, plc) = test_session();
+
+        let x: HostFloat64Tensor = plc.from_raw(array![[1.0, 2.0]]);
+        let mean: HostFloat64Tensor = plc.from_raw(array![2.0, 1.0]);
+        let inv_cov: HostFloat64Tensor = plc.from_raw(array![[1.0, 0.0, 0.0], [0.0, 1.0, 0.0]]);
+        let res = MahalanobisOp::host_kernel(&sess, &plc, x, mean, inv_cov);
+        assert!(matches!(res, Err(crate::error::Error::KernelError(_))));
+    }
+
+    #[test]
+    fn test_dbscan() {
+        let (sess, plc) = test_session();
+
+        // two dense groups along a line and an outlier
+        let points = [0.0, 0.5, 1.0, 10.0, 10.5, 11.0, 30.0];
+        let distances = Array::from_shape_fn((points.len(), points.len()), |(i, j)| {
+            (points[i] - points[j]).abs()
+        });
+        let d: HostFloat64Tensor = plc.from_raw(distances);
+        let labels: HostRing64Tensor = plc.dbscan(&sess, 0.6, 2, &d);
+
+        let expected: HostRing64Tensor = plc.from_raw(array![0, 0, 0, 1, 1, 1, u64::MAX]);
+        assert_eq!(labels, expected);
+    }
+
+    #[test]
+    fn test_dbscan_not_square() {
+        let (sess, plc) = test_session();
+
+        let d: HostFloat64Tensor = plc.from_raw(array![[0.0, 1.0, 2.0], [1.0, 0.0, 1.0]]);
+        let res = DbscanOp::host_kernel(&sess, &plc, 1.0, 2, d);
+        assert!(matches!(res, Err(crate::error::Error::KernelError(_))));
+    }
+
+    #[test]
+    fn test_closest_pair() {
+        let (sess, plc) = test_session();
+
+        // the diagonal is smaller than every other entry but must be ignored
+        let raw_d = array![
+            [0.0, 4.0, 3.0, 5.0],
+            [4.0, 0.0, 2.5, 1.5],
+            [3.0, 2.5, 0.0, 1.5],
+            [5.0, 1.5, 1.5, 0.0]
+        ];
+        let d: HostFloat64Tensor = plc.from_raw(raw_d);
+        let pair = plc.closest_pair(&sess, &d);
+        assert_close(&pair, &[1.0, 3.0, 1.5]);
+    }
+
+    #[test]
+    fn test_tfidf() {
+        let (sess, plc) = test_session();
+
+        // three terms appearing in 4, 2, and 1 of 4 documents
+        let counts: HostFloat64Tensor = plc.from_raw(array![[1.0, 2.0, 0.0], [3.0, 0.0, 1.0]]);
+        let df: HostFloat64Tensor = plc.from_raw(array![4.0, 2.0, 1.0]);
+
+        let weights = plc.tfidf(&sess, 4, false, &counts, &df);
+        let ln2 = 2f64.ln();
+        let ln4 = 4f64.ln();
+        assert_close(&weights, &[0.0, 2.0 * ln2, 0.0, 0.0, 0.0, ln4]);
+
+        let smoothed = plc.tfidf(&sess, 4, true, &counts, &df);
+        let expected = [
+            1.0 * (5f64 / 5.0).ln(),
+            2.0 * (5f64 / 3.0).ln(),
+            0.0,
+            3.0 * (5f64 / 5.0).ln(),
+            0.0,
+            1.0 * (5f64 / 2.0).ln(),
+        ];
+        assert_close(&smoothed, &expected);
+    }
+
+    #[test]
+    fn test_tfidf_zero_df() {
+        let (sess, plc) = test_session();
+
+        let counts: HostFloat64Tensor = plc.from_raw(array![[1.0, 0.0]]);
+        let df: HostFloat64Tensor = plc.from_raw(array![1.0, 0.0]);
+        let res = TfidfOp::host_kernel(&sess, &plc, 2, false, counts, df);
+        assert!(matches!(res, Err(crate::error::Error::KernelError(_))));
+    }
+
+    #[test]
+    fn test_ngram_hash() {
+        let (sess, plc) = test_session();
+
+        let tokens: HostRing64Tensor = plc.from_raw(array![[1, 2, 3, 1, 2], [2, 3, 4, 5, 6]]);
+        let counts: HostRing64Tensor = plc.ngram_hash(&sess, 2, 8, &tokens);
+
+        // (1, 2) goes to bucket 6 and (2, 3) to bucket 4 in both documents
+        let expected: HostRing64Tensor =
+            plc.from_raw(array![[0, 0, 0, 0, 1, 0, 2, 1], [0, 0, 1, 0, 2, 0, 1, 0]]);
+        assert_eq!(counts, expected);
+        assert_eq!(plc.ngram_hash(&sess, 2, 8, &tokens), counts);
+    }
+
+    #[test]
+    fn test_bag_of_words() {
+        let (sess, plc) = test_session();
+
+        // the second document is padded with 9
+        let tokens: HostRing64Tensor = plc.from_raw(array![[0, 2, 2, 3], [3, 1, 9, 9]]);
+        let counts: HostRing64Tensor = plc.bag_of_words(&sess, 4, 9, &tokens);
+
+        let expected: HostRing64Tensor = plc.from_raw(array![[1, 0, 2, 1], [0, 1, 0, 1]]);
+        assert_eq!(counts, expected);
+    }
+
+    #[test]
+    fn test_bag_of_words_out_of_vocabulary() {
+        let (sess, plc) = test_session();
+
+        let tokens: HostRing64Tensor = plc.from_raw(array![[0, 4]]);
+        let res = BagOfWordsOp::host_kernel(&sess, &plc, 4, 9, tokens);
+        assert!(matches!(res, Err(crate::error::Error::KernelError(_))));
+    }
+
+    #[test]
+    fn test_sparse_to_dense() {
+        let (sess, plc) = test_session();
+
+        // the last two entries share a coordinate
+        let indices: HostRing64Tensor = plc.from_raw(array![[0, 1], [1, 0], [2, 2], [2, 2]]);
+        let values: HostFloat64Tensor = plc.from_raw(array![1.5, -2.0, 1.0, 2.0]);
+        let shape = HostShape(RawShape(vec![3, 3]), plc.clone());
+        let dense = plc.sparse_to_dense(&sess, &indices, &values, &shape);
+
+        let expected: HostFloat64Tensor =
+            plc.from_raw(array![[0.0, 1.5, 0.0], [-2.0, 0.0, 0.0], [0.0, 0.0, 3.0]]);
+        assert_eq!(dense, expected);
+    }
+
+    #[test]
+    fn test_sparse_to_dense_out_of_range() {
+        let (sess, plc) = test_session();
+
+        let indices: HostRing64Tensor = plc.from_raw(array![[0, 3]]);
+        let values: HostFloat64Tensor = plc.from_raw(array![1.0]);
+        let shape = HostShape(RawShape(vec![3, 3]), plc.clone());
+        let res = SparseToDenseOp::host_kernel(&sess, &plc, indices, values, shape);
+        assert!(matches!(res, Err(crate::error::Error::KernelError(_))));
+    }
+
+    #[test]
+    fn test_dense_to_sparse() {
+        let (sess, plc) = test_session();
+
+        let x: HostFloat64Tensor =
+            plc.from_raw(array![[0.0, 1.5, 0.0], [-2.0, 0.0, 0.0], [0.0, 0.0, 3.0]]);
+        let indices: HostRing64Tensor = plc.dense_to_sparse_indices(&sess, &x);
+        let values: HostFloat64Tensor = plc.dense_to_sparse_values(&sess, &x);
+
+        let expected_indices: HostRing64Tensor = plc.from_raw(array![[0, 1], [1, 0], [2, 2]]);
+        let expected_values: HostFloat64Tensor = plc.from_raw(array![1.5, -2.0, 3.0]);
+        assert_eq!(indices, expected_indices);
+        assert_eq!(values, expected_values);
+
+        let shape = plc.shape(&sess, &x);
+        let dense = plc.sparse_to_dense(&sess, &indices, &values, &shape);
+        assert_eq!(dense, x);
+    }
+}
@@ -9,7 +9,7 @@ use ndarray::LinalgScalar;
 use ndarray::Zip;
 #[cfg(feature = "blas")]
 use ndarray_linalg::{Inverse, Lapack};
-use num_traits::{clamp_min, Float, FromPrimitive, Signed, Zero};
+use num_traits::{clamp_min, CheckedRem, Float, FromPrimitive, One, Signed, Zero};
 use std::convert::TryInto;
 use std::marker::PhantomData;
 use std::num::Wrapping;
@@ -232,6 +232,32 @@ impl AbsOp {
             plc.clone(),
         ))
     }
+
+    /// Absolute value of ring elements interpreted as two's complement integers.
+    ///
+    /// As with the signed integers `abs` of the most negative value wraps to itself.
+    pub(crate) fn ring64_kernel<S: RuntimeSession>(
+        _sess: &S,
+        plc: &HostPlacement,
+        x: HostRing64Tensor,
+    ) -> Result<HostRing64Tensor> {
+        let abs =
+            x.0.mapv(|Wrapping(item)| Wrapping((item as i64).wrapping_abs() as u64));
+        Ok(HostRingTensor::<u64>(abs.into_shared(), plc.clone()))
+    }
+
+    /// Absolute value of ring elements interpreted as two's complement integers.
+    ///
+    /// As with the signed integers `abs` of the most negative value wraps to itself.
+    pub(crate) fn ring128_kernel<S: RuntimeSession>(
+        _sess: &S,
+        plc: &HostPlacement,
+        x: HostRing128Tensor,
+    ) -> Result<HostRing128Tensor> {
+        let abs =
+            x.0.mapv(|Wrapping(item)| Wrapping((item as i128).wrapping_abs() as u128));
+        Ok(HostRingTensor::<u128>(abs.into_shared(), plc.clone()))
+    }
 }
 
 impl ReluOp {
@@ -305,6 +331,31 @@ impl MulOp {
     }
 }
 
+impl SquareOp {
+    pub(crate) fn host_kernel<S: RuntimeSession, T: LinalgScalar>(
+        _sess: &S,
+        plc: &HostPlacement,
+        x: HostTensor<T>,
+    ) -> Result<HostTensor<T>> {
+        Ok(HostTensor(x.0.mapv(|v| v * v).into_shared(), plc.clone()))
+    }
+
+    pub(crate) fn ring_kernel<S: RuntimeSession, T>(
+        _sess: &S,
+        plc: &HostPlacement,
+        x: HostRingTensor<T>,
+    ) -> Result<HostRingTensor<T>>
+    where
+        Wrapping<T>: Clone,
+        Wrapping<T>: std::ops::Mul<Wrapping<T>, Output = Wrapping<T>>,
+    {
+        Ok(HostRingTensor(
+            x.0.mapv(|v| v.clone() * v).into_shared(),
+            plc.clone(),
+        ))
+    }
+}
+
 impl DivOp {
     pub(crate) fn host_kernel<S: RuntimeSession, T: LinalgScalar + FromPrimitive>(
         _sess: &S,
@@ -338,6 +389,53 @@ impl DivOp {
     }
 }
 
+impl ModOp {
+    /// Element-wise remainder of `x` divided by `y`, which like `%` takes the sign of `x`.
+    pub(crate) fn host_kernel<S: RuntimeSession, T>(
+        _sess: &S,
+        plc: &HostPlacement,
+        x: HostTensor<T>,
+        y: HostTensor<T>,
+    ) -> Result<HostTensor<T>>
+    where
+        T: Clone + Zero + CheckedRem,
+    {
+        if y.0.iter().any(|m| m.is_zero()) {
+            return Err(Error::KernelError(
+                "ModOp cannot take the remainder modulo zero".to_string(),
+            ));
+        }
+        let (x, y) = broadcast_views(&x.0, &y.0)?;
+        // with zero ruled out only `MIN % -1` overflows, and its remainder is zero
+        let res = Zip::from(&x)
+            .and(&y)
+            .map_collect(|a, m| a.checked_rem(m).unwrap_or_else(T::zero));
+        Ok(HostTensor(res.into_shared(), plc.clone()))
+    }
+
+    /// Element-wise remainder of ring elements interpreted as unsigned integers.
+    pub(crate) fn ring_kernel<S: RuntimeSession, T>(
+        _sess: &S,
+        plc: &HostPlacement,
+        x: HostRingTensor<T>,
+        y: HostRingTensor<T>,
+    ) -> Result<HostRingTensor<T>>
+    where
+        T: Copy + Zero + std::ops::Rem<T, Output = T>,
+    {
+        if y.0.iter().any(|m| m.0.is_zero()) {
+            return Err(Error::KernelError(
+                "ModOp cannot take the remainder modulo zero".to_string(),
+            ));
+        }
+        let (x, y) = broadcast_views(&x.0, &y.0)?;
+        let res = Zip::from(&x)
+            .and(&y)
+            .map_collect(|a, m| Wrapping(a.0 % m.0));
+        Ok(HostRingTensor(res.into_shared(), plc.clone()))
+    }
+}
+
 impl<T> HostTensor<T>
 where
     T: LinalgScalar,
@@ -536,8 +634,30 @@ impl SliceOp {
         info: SliceInfo,
         x: HostShape,
     ) -> Result<HostShape> {
-        let slice =
-            x.0.slice(info.0[0].start as usize, info.0[0].end.unwrap() as usize);
+        // shapes are one-dimensional so exactly one slice element applies
+        let elem = match info.0.as_slice() {
+            [elem] => elem,
+            _ => {
+                return Err(Error::KernelError(format!(
+                    "SliceOp on a shape expects a single slice but got {}",
+                    info.0.len()
+                )))
+            }
+        };
+        let len = x.0 .0.len() as isize;
+        let resolve = |i: isize| if i < 0 { i + len } else { i };
+        let start = resolve(elem.start);
+        let end = elem.end.map(resolve).unwrap_or(len);
+        let step = elem.step.unwrap_or(1);
+        if !(0..=len).contains(&start) || !(start..=len).contains(&end) || step <= 0 {
+            return Err(Error::KernelError(format!(
+                "SliceOp {:?} out of range for shape {:?}",
+                elem, x.0 .0
+            )));
+        }
+
+        let slice = x.0.slice(start as usize, end as usize);
+        let slice = RawShape(slice.0.into_iter().step_by(step as usize).collect());
         Ok(HostShape(slice, plc.clone()))
     }
 }
@@ -735,6 +855,173 @@ impl IndexAxisOp {
     }
 }
 
+/// Reverses the order of the elements of `x` along `axis`.
+fn flip<A: Clone>(x: ArrayViewD<A>, axis: usize) -> Result<ArrayD<A>> {
+    if axis >= x.ndim() {
+        return Err(Error::KernelError(format!(
+            "FlipOp axis {} out of bounds for tensor of rank {}",
+            axis,
+            x.ndim()
+        )));
+    }
+    let mut view = x;
+    view.invert_axis(Axis(axis));
+    // copy into standard layout rather than keeping the negative stride around
+    let values = view.iter().cloned().collect();
+    Array::from_shape_vec(view.raw_dim(), values).map_err(|e| Error::KernelError(e.to_string()))
+}
+
+impl FlipOp {
+    pub(crate) fn host_float_kernel<S: RuntimeSession, T: LinalgScalar>(
+        _sess: &S,
+        plc: &HostPlacement,
+        axis: usize,
+        x: HostTensor<T>,
+    ) -> Result<HostTensor<T>> {
+        let res = flip(x.0.view(), axis)?;
+        Ok(HostTensor(res.into_shared(), plc.clone()))
+    }
+
+    pub(crate) fn host_bit_kernel<S: RuntimeSession>(
+        _sess: &S,
+        plc: &HostPlacement,
+        axis: usize,
+        x: HostBitTensor,
+    ) -> Result<HostBitTensor> {
+        let arr =
+            x.0.into_array::<u8>()
+                .map_err(|e| Error::KernelError(e.to_string()))?;
+        let res = flip(arr.view(), axis)?;
+        let shape = RawShape(res.shape().to_vec());
+        Ok(HostBitTensor(
+            BitArrayRepr::from_vec(res.into_raw_vec(), &shape),
+            plc.clone(),
+        ))
+    }
+
+    pub(crate) fn host_ring_kernel<S: RuntimeSession, T>(
+        _sess: &S,
+        plc: &HostPlacement,
+        axis: usize,
+        x: HostRingTensor<T>,
+    ) -> Result<HostRingTensor<T>>
+    where
+        T: Clone,
+    {
+        let res = flip(x.0.view(), axis)?;
+        Ok(HostRingTensor(res.into_shared(), plc.clone()))
+    }
+}
+
+/// Stacks the slices of `x` along `axis` at the positions given by the 1D `indices`.
+fn gather<A: Clone>(
+    x: &ArcArrayD<A>,
+    axis: usize,
+    indices: &ArcArrayD<Wrapping<u64>>,
+) -> Result<ArcArrayD<A>> {
+    if axis >= x.ndim() {
+        return Err(Error::KernelError(format!(
+            "GatherOp axis {} out of bounds for tensor of rank {}",
+            axis,
+            x.ndim()
+        )));
+    }
+    if indices.ndim() != 1 {
+        return Err(Error::KernelError(format!(
+            "GatherOp expected indices of rank 1 but got rank {}",
+            indices.ndim()
+        )));
+    }
+    let len = x.shape()[axis];
+    let indices = indices
+        .iter()
+        .map(|&Wrapping(i)| match usize::try_from(i) {
+            Ok(i) if i < len => Ok(i),
+            _ => Err(Error::KernelError(format!(
+                "GatherOp index {} out of bounds for axis of length {}",
+                i, len
+            ))),
+        })
+        .collect::<Result<Vec<usize>>>()?;
+    Ok(x.select(Axis(axis), &indices).into_shared())
+}
+
+impl GatherOp {
+    pub(crate) fn host_float_kernel<S: RuntimeSession, T: LinalgScalar>(
+        _sess: &S,
+        plc: &HostPlacement,
+        axis: usize,
+        x: HostTensor<T>,
+        indices: HostRing64Tensor,
+    ) -> Result<HostTensor<T>> {
+        let res = gather(&x.0, axis, &indices.0)?;
+        Ok(HostTensor(res, plc.clone()))
+    }
+
+    pub(crate) fn host_ring_kernel<S: RuntimeSession, T>(
+        _sess: &S,
+        plc: &HostPlacement,
+        axis: usize,
+        x: HostRingTensor<T>,
+        indices: HostRing64Tensor,
+    ) -> Result<HostRingTensor<T>>
+    where
+        T: Clone,
+    {
+        let res = gather(&x.0, axis, &indices.0)?;
+        Ok(HostRingTensor(res, plc.clone()))
+    }
+}
+
+/// Replace every index in `x` by the corresponding entry of the 1D `table`.
+fn lookup<A: Clone>(x: &ArcArrayD<Wrapping<u64>>, table: &ArcArrayD<A>) -> Result<ArcArrayD<A>> {
+    let table = table.view().into_dimensionality::<Ix1>().map_err(|_| {
+        Error::InvalidArgument(format!(
+            "LookupTableOp expected a table of rank 1 but got rank {}",
+            table.ndim()
+        ))
+    })?;
+    let values = x
+        .iter()
+        .map(|index| {
+            usize::try_from(index.0)
+                .ok()
+                .and_then(|i| table.get(i))
+                .cloned()
+                .ok_or_else(|| {
+                    Error::KernelError(format!(
+                        "LookupTableOp index {} out of range for table of length {}",
+                        index.0,
+                        table.len()
+                    ))
+                })
+        })
+        .collect::<Result<Vec<_>>>()?;
+    ArcArrayD::from_shape_vec(x.raw_dim(), values).map_err(|e| Error::KernelError(e.to_string()))
+}
+
+impl LookupTableOp {
+    pub(crate) fn host_float_kernel<S: RuntimeSession, T: Clone>(
+        _sess: &S,
+        plc: &HostPlacement,
+        x: HostRing64Tensor,
+        table: HostTensor<T>,
+    ) -> Result<HostTensor<T>> {
+        let res = lookup(&x.0, &table.0)?;
+        Ok(HostTensor(res, plc.clone()))
+    }
+
+    pub(crate) fn host_ring_kernel<S: RuntimeSession, T: Clone>(
+        _sess: &S,
+        plc: &HostPlacement,
+        x: HostRing64Tensor,
+        table: HostRingTensor<T>,
+    ) -> Result<HostRingTensor<T>> {
+        let res = lookup(&x.0, &table.0)?;
+        Ok(HostRingTensor(res, plc.clone()))
+    }
+}
+
 impl IndexOp {
     pub(crate) fn host_kernel<S: Session, HostBitT, N>(
         sess: &S,
@@ -769,6 +1056,32 @@ impl ShlDimOp {
     }
 }
 
+impl ShrDimOp {
+    pub(crate) fn host_bit_kernel<S: RuntimeSession>(
+        _sess: &S,
+        plc: &HostPlacement,
+        amount: usize,
+        bit_length: usize,
+        x: HostBitTensor,
+    ) -> Result<HostBitTensor> {
+        if amount > bit_length {
+            return Err(Error::KernelError(format!(
+                "ShrDimOp cannot shift by {} with bit length {}",
+                amount, bit_length
+            )));
+        }
+        let height = x.0.dim.default_strides()[0];
+        let mut data = BitVec::with_capacity(height * bit_length);
+        data.extend_from_bitslice(&x.0.data[height * amount..height * bit_length]); // Drop the lowest planes
+        data.resize(height * bit_length, false); // Right portion is zeroes
+        let result = BitArrayRepr {
+            data: std::sync::Arc::new(data),
+            dim: x.0.dim.clone(),
+        };
+        Ok(HostBitTensor(result, plc.clone()))
+    }
+}
+
 impl BitDecomposeOp {
     pub(crate) fn host_ring64_kernel<S: RuntimeSession>(
         _sess: &S,
@@ -853,6 +1166,54 @@ impl BitDecomposeOp {
     }
 }
 
+impl BitComposeOp {
+    pub(crate) fn ring64_kernel<S: RuntimeSession>(
+        _sess: &S,
+        plc: &HostPlacement,
+        x: HostBitTensor,
+    ) -> Result<HostRing64Tensor> {
+        bit_compose(plc, <HostRing64Tensor as Ring>::BitLength::VALUE, x)
+    }
+
+    pub(crate) fn ring128_kernel<S: RuntimeSession>(
+        _sess: &S,
+        plc: &HostPlacement,
+        x: HostBitTensor,
+    ) -> Result<HostRing128Tensor> {
+        bit_compose(plc, <HostRing128Tensor as Ring>::BitLength::VALUE, x)
+    }
+}
+
+/// Fold bits stacked along axis 0 back into ring elements, i.e. `sum_i (bit_i << i)`.
+fn bit_compose<T>(
+    plc: &HostPlacement,
+    bit_length: usize,
+    x: HostBitTensor,
+) -> Result<HostRingTensor<T>>
+where
+    T: Clone + From<u8>,
+    Wrapping<T>: Clone,
+    Wrapping<T>: std::ops::Add<Output = Wrapping<T>>,
+    Wrapping<T>: std::ops::Shl<usize, Output = Wrapping<T>>,
+{
+    let bits =
+        x.0.into_array::<u8>()
+            .map_err(|e| Error::KernelError(e.to_string()))?;
+    if bits.ndim() == 0 || bits.len_of(Axis(0)) > bit_length {
+        return Err(Error::KernelError(format!(
+            "BitComposeOp expected at most {} bits along axis 0 but got shape {:?}",
+            bit_length,
+            bits.shape()
+        )));
+    }
+
+    let mut res = ArrayD::from_elem(&bits.shape()[1..], Wrapping(T::from(0)));
+    for (i, bit) in bits.outer_iter().enumerate() {
+        res = res + &bit.mapv(|b| Wrapping(T::from(b)) << i);
+    }
+    Ok(HostRingTensor(res.into_shared(), plc.clone()))
+}
+
 impl MeanOp {
     pub(crate) fn host_kernel<S: RuntimeSession, T: LinalgScalar + FromPrimitive>(
         _sess: &S,
@@ -889,6 +1250,136 @@ impl MeanOp {
     }
 }
 
+/// Variance along `axis`, or over all elements, with `ddof` delta degrees of freedom.
+pub(super) fn variance<T>(x: &ArcArrayD<T>, axis: Option<u32>, ddof: u32) -> Result<ArrayD<T>>
+where
+    T: 'static + Float + FromPrimitive,
+{
+    let count = match axis {
+        Some(i) if i as usize >= x.ndim() => {
+            return Err(Error::KernelError(format!(
+                "Variance axis {} out of bounds for tensor of rank {}",
+                i,
+                x.ndim()
+            )))
+        }
+        Some(i) => x.len_of(Axis(i as usize)),
+        None => x.len(),
+    };
+    if count == 0 {
+        return Err(Error::KernelError(
+            "Variance cannot reduce over an empty axis.".to_string(),
+        ));
+    }
+    if ddof as usize >= count {
+        return Err(Error::KernelError(format!(
+            "Variance requires more than {} elements but got {}",
+            ddof, count
+        )));
+    }
+    let denom = T::from_usize(count - ddof as usize).ok_or_else(|| {
+        Error::KernelError("Variance could not convert element count".to_string())
+    })?;
+
+    match axis {
+        Some(i) => {
+            let axis = Axis(i as usize);
+            let mean = x
+                .mean_axis(axis)
+                .ok_or_else(|| {
+                    Error::KernelError("Variance cannot reduce over an empty axis.".to_string())
+                })?
+                .insert_axis(axis);
+            let squares = (x - &mean).mapv(|v| v * v);
+            Ok(squares.sum_axis(axis).mapv(|v| v / denom))
+        }
+        None => {
+            let mean = x.mean().ok_or_else(|| {
+                Error::KernelError("Variance cannot reduce over an empty tensor.".to_string())
+            })?;
+            let squares = x.fold(T::zero(), |acc, &v| acc + (v - mean) * (v - mean));
+            Ok(ArrayD::from_elem(IxDyn(&[]), squares / denom))
+        }
+    }
+}
+
+impl VarOp {
+    pub(crate) fn host_kernel<S: RuntimeSession, T>(
+        _sess: &S,
+        plc: &HostPlacement,
+        axis: Option<u32>,
+        ddof: u32,
+        x: HostTensor<T>,
+    ) -> Result<HostTensor<T>>
+    where
+        T: 'static + Float + FromPrimitive,
+    {
+        let var = variance(&x.0, axis, ddof)?;
+        Ok(HostTensor(var.into_shared(), plc.clone()))
+    }
+}
+
+impl StdOp {
+    pub(crate) fn host_kernel<S: RuntimeSession, T>(
+        _sess: &S,
+        plc: &HostPlacement,
+        axis: Option<u32>,
+        ddof: u32,
+        x: HostTensor<T>,
+    ) -> Result<HostTensor<T>>
+    where
+        T: 'static + Float + FromPrimitive,
+    {
+        let var = variance(&x.0, axis, ddof)?;
+        Ok(HostTensor(var.mapv(T::sqrt).into_shared(), plc.clone()))
+    }
+}
+
+impl MovingStdOp {
+    /// Standard deviation over every full window of `window` consecutive entries along `axis`.
+    ///
+    /// The result has `n - window + 1` entries along `axis`, where `n` is the input length.
+    pub(crate) fn host_kernel<S: RuntimeSession, T>(
+        _sess: &S,
+        plc: &HostPlacement,
+        axis: usize,
+        window: usize,
+        ddof: u32,
+        x: HostTensor<T>,
+    ) -> Result<HostTensor<T>>
+    where
+        T: 'static + Float + FromPrimitive,
+    {
+        if axis >= x.0.ndim() {
+            return Err(Error::KernelError(format!(
+                "MovingStdOp axis {} out of bounds for tensor of rank {}",
+                axis,
+                x.0.ndim()
+            )));
+        }
+        let len = x.0.len_of(Axis(axis));
+        if window == 0 || window > len {
+            return Err(Error::KernelError(format!(
+                "MovingStdOp window {} invalid for axis of length {}",
+                window, len
+            )));
+        }
+
+        let stds = (0..=len - window)
+            .map(|start| {
+                let slice =
+                    x.0.slice_axis(Axis(axis), Slice::from(start..start + window))
+                        .to_shared();
+                variance(&slice, Some(axis as u32), ddof).map(|var| var.mapv(T::sqrt))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let views: Vec<_> = stds.iter().map(|std| std.view()).collect();
+        let res =
+            ndarray::stack(Axis(axis), &views).map_err(|e| Error::KernelError(e.to_string()))?;
+        Ok(HostTensor(res.into_shared(), plc.clone()))
+    }
+}
+
 impl SqrtOp {
     pub(crate) fn host_kernel<S: RuntimeSession, T: 'static + Float>(
         _sess: &S,
@@ -903,6 +1394,125 @@ impl SqrtOp {
     }
 }
 
+impl FloorOp {
+    pub(crate) fn host_kernel<S: RuntimeSession, T: 'static + Float>(
+        _sess: &S,
+        plc: &HostPlacement,
+        x: HostTensor<T>,
+    ) -> Result<HostTensor<T>>
+    where
+        HostPlacement: PlacementPlace<S, HostTensor<T>>,
+    {
+        let x_floor = x.0.mapv(T::floor);
+        Ok(HostTensor::place(plc, x_floor.into_shared()))
+    }
+}
+
+impl CeilOp {
+    pub(crate) fn host_kernel<S: RuntimeSession, T: 'static + Float>(
+        _sess: &S,
+        plc: &HostPlacement,
+        x: HostTensor<T>,
+    ) -> Result<HostTensor<T>>
+    where
+        HostPlacement: PlacementPlace<S, HostTensor<T>>,
+    {
+        let x_ceil = x.0.mapv(T::ceil);
+        Ok(HostTensor::place(plc, x_ceil.into_shared()))
+    }
+}
+
+impl RoundOp {
+    pub(crate) fn host_kernel<S: RuntimeSession, T: 'static + Float>(
+        _sess: &S,
+        plc: &HostPlacement,
+        x: HostTensor<T>,
+    ) -> Result<HostTensor<T>>
+    where
+        HostPlacement: PlacementPlace<S, HostTensor<T>>,
+    {
+        let x_round = x.0.mapv(T::round);
+        Ok(HostTensor::place(plc, x_round.into_shared()))
+    }
+}
+
+impl ReciprocalOp {
+    /// Computes `1 / x` element-wise.
+    ///
+    /// Zero entries follow IEEE semantics and yield infinities (or NaN for NaN entries)
+    /// rather than an error, matching the float `DivOp` kernel; callers must mask them.
+    pub(crate) fn host_kernel<S: RuntimeSession, T: 'static + Float>(
+        _sess: &S,
+        plc: &HostPlacement,
+        x: HostTensor<T>,
+    ) -> Result<HostTensor<T>>
+    where
+        HostPlacement: PlacementPlace<S, HostTensor<T>>,
+    {
+        let x_recip = x.0.mapv(|v| T::one() / v);
+        Ok(HostTensor::place(plc, x_recip.into_shared()))
+    }
+}
+
+impl PowOp {
+    /// Raises every element to `exponent`.
+    ///
+    /// Integral exponents are computed with repeated multiplication via `powi`, so that
+    /// small powers such as squares are exact.
+    pub(crate) fn host_kernel<S: RuntimeSession, T: 'static + Float>(
+        _sess: &S,
+        plc: &HostPlacement,
+        exponent: f64,
+        x: HostTensor<T>,
+    ) -> Result<HostTensor<T>> {
+        let res =
+            if exponent.fract() == 0.0 && (i32::MIN as f64..=i32::MAX as f64).contains(&exponent) {
+                let exponent = exponent as i32;
+                x.0.mapv(|v| v.powi(exponent))
+            } else {
+                let exponent = T::from(exponent).ok_or_else(|| {
+                    Error::KernelError(format!("PowOp could not convert exponent={}", exponent))
+                })?;
+                x.0.mapv(|v| v.powf(exponent))
+            };
+        Ok(HostTensor(res.into_shared(), plc.clone()))
+    }
+}
+
+impl ClipOp {
+    pub(crate) fn host_kernel<S: RuntimeSession, T>(
+        _sess: &S,
+        plc: &HostPlacement,
+        min: f64,
+        max: f64,
+        x: HostTensor<T>,
+    ) -> Result<HostTensor<T>>
+    where
+        T: LinalgScalar + PartialOrd + FromPrimitive,
+    {
+        if min > max {
+            return Err(Error::KernelError(format!(
+                "ClipOp expected min <= max but got min={} and max={}",
+                min, max
+            )));
+        }
+        let min = T::from_f64(min)
+            .ok_or_else(|| Error::KernelError(format!("ClipOp could not convert min={}", min)))?;
+        let max = T::from_f64(max)
+            .ok_or_else(|| Error::KernelError(format!("ClipOp could not convert max={}", max)))?;
+        let res = x.0.mapv(|v| {
+            if v < min {
+                min
+            } else if v > max {
+                max
+            } else {
+                v
+            }
+        });
+        Ok(HostTensor(res.into_shared(), plc.clone()))
+    }
+}
+
 impl SigmoidOp {
     pub(crate) fn host_kernel<S: RuntimeSession, T: 'static + Float>(
         _sess: &S,
@@ -1000,6 +1610,121 @@ impl SumOp {
     }
 }
 
+/// Product of all elements, or of all elements along `axis` if given.
+fn product<A>(x: &ArcArrayD<A>, axis: Option<usize>, one: A) -> Result<ArcArrayD<A>>
+where
+    A: Clone + std::ops::Mul<Output = A>,
+{
+    match axis {
+        Some(i) if i >= x.ndim() => Err(Error::KernelError(format!(
+            "ProdOp axis {} out of bounds for tensor of rank {}",
+            i,
+            x.ndim()
+        ))),
+        Some(i) => Ok(x
+            .fold_axis(Axis(i), one, |acc, v| acc.clone() * v.clone())
+            .into_shared()),
+        None => {
+            let total = x.fold(one, |acc, v| acc * v.clone());
+            Ok(ArcArrayD::from_elem(IxDyn(&[]), total))
+        }
+    }
+}
+
+impl ProdOp {
+    pub(crate) fn host_float_kernel<S: RuntimeSession, T: LinalgScalar>(
+        _sess: &S,
+        plc: &HostPlacement,
+        axis: Option<usize>,
+        x: HostTensor<T>,
+    ) -> Result<HostTensor<T>> {
+        let res = product(&x.0, axis, T::one())?;
+        Ok(HostTensor(res, plc.clone()))
+    }
+
+    pub(crate) fn host_ring_kernel<S: RuntimeSession, T>(
+        _sess: &S,
+        plc: &HostPlacement,
+        axis: Option<usize>,
+        x: HostRingTensor<T>,
+    ) -> Result<HostRingTensor<T>>
+    where
+        T: Clone + One,
+        Wrapping<T>: std::ops::Mul<Wrapping<T>, Output = Wrapping<T>>,
+    {
+        let res = product(&x.0, axis, Wrapping(T::one()))?;
+        Ok(HostRingTensor(res, plc.clone()))
+    }
+}
+
+/// Running sums of `x` along `axis`.
+fn cumulative_sum<A>(x: &ArcArrayD<A>, axis: usize) -> Result<ArcArrayD<A>>
+where
+    A: Clone + std::ops::Add<Output = A>,
+{
+    if axis >= x.ndim() {
+        return Err(Error::KernelError(format!(
+            "CumsumOp axis {} out of bounds for tensor of rank {}",
+            axis,
+            x.ndim()
+        )));
+    }
+    let mut res = x.to_owned();
+    res.accumulate_axis_inplace(Axis(axis), |prev, curr| *curr = prev.clone() + curr.clone());
+    Ok(res.into_shared())
+}
+
+impl CumsumOp {
+    pub(crate) fn host_kernel<S: RuntimeSession, T: LinalgScalar>(
+        _sess: &S,
+        plc: &HostPlacement,
+        axis: usize,
+        x: HostTensor<T>,
+    ) -> Result<HostTensor<T>> {
+        let res = cumulative_sum(&x.0, axis)?;
+        Ok(HostTensor(res, plc.clone()))
+    }
+
+    pub(crate) fn host_ring_kernel<S: RuntimeSession, T>(
+        _sess: &S,
+        plc: &HostPlacement,
+        axis: usize,
+        x: HostRingTensor<T>,
+    ) -> Result<HostRingTensor<T>>
+    where
+        T: Clone,
+        Wrapping<T>: std::ops::Add<Wrapping<T>, Output = Wrapping<T>>,
+    {
+        let res = cumulative_sum(&x.0, axis)?;
+        Ok(HostRingTensor(res, plc.clone()))
+    }
+}
+
+impl CountDistinctOp {
+    pub(crate) fn host_ring_kernel<S: RuntimeSession, T>(
+        _sess: &S,
+        plc: &HostPlacement,
+        axis: usize,
+        x: HostRingTensor<T>,
+    ) -> Result<HostRing64Tensor>
+    where
+        T: std::hash::Hash + Eq,
+    {
+        if axis >= x.0.ndim() {
+            return Err(Error::KernelError(format!(
+                "CountDistinctOp axis {} out of bounds for tensor of rank {}",
+                axis,
+                x.0.ndim()
+            )));
+        }
+        let counts = x.0.map_axis(Axis(axis), |lane| {
+            let distinct: std::collections::HashSet<_> = lane.iter().collect();
+            Wrapping(distinct.len() as u64)
+        });
+        Ok(HostRingTensor(counts.into_shared(), plc.clone()))
+    }
+}
+
 impl AddNOp {
     pub(crate) fn host_kernel<S: RuntimeSession, T>(
         _sess: &S,
@@ -1015,6 +1740,7 @@ impl AddNOp {
                 "cannot reduce on empty array of tensors".to_string(),
             ))
         } else {
+            ensure_equal_shapes("AddNOp", xs.iter().map(|x| x.0.shape()))?;
             let base = xs[0].0.clone();
             let sum = xs[1..].iter().fold(base, |acc, item| acc + &item.0);
             Ok(HostRingTensor(sum, plc.clone()))
@@ -1034,6 +1760,7 @@ impl AddNOp {
                 "cannot reduce on empty array of tensors".to_string(),
             ))
         } else {
+            ensure_equal_shapes("AddNOp", xs.iter().map(|x| x.0.shape()))?;
             let base = xs[0].0.clone();
             let sum = xs[1..].iter().fold(base, |acc, item| acc + &item.0);
             Ok(HostTensor(sum, plc.clone()))
@@ -1041,6 +1768,27 @@ impl AddNOp {
     }
 }
 
+/// Ensure that all tensors of a variadic operation have the shape of the first one.
+fn ensure_equal_shapes<'a, I>(op: &str, mut shapes: I) -> Result<()>
+where
+    I: Iterator<Item = &'a [usize]>,
+{
+    if let Some(first) = shapes.next() {
+        for (i, shape) in shapes.enumerate() {
+            if shape != first {
+                return Err(Error::KernelError(format!(
+                    "{} expected all tensors to have shape {:?} but tensor {} has shape {:?}",
+                    op,
+                    first,
+                    i + 1,
+                    shape
+                )));
+            }
+        }
+    }
+    Ok(())
+}
+
 // TODO(Morten) inline
 impl<T: LinalgScalar> HostTensor<T> {
     fn expand_dims(self, mut axis: Vec<usize>) -> Self {
@@ -1174,15 +1922,176 @@ impl ConcatOp {
         for x in xs {
             data.extend_from_bitslice(&x.0.data);
         }
-        // Computing the dimension
-        let mut res_dim = xs[0].0.shape().to_vec();
-        let stacked_dim: usize = xs.iter().fold(0, |acc, a| acc + a.0.shape()[axis as usize]);
-        res_dim[axis as usize] = stacked_dim;
-
-        Ok(HostBitTensor(
-            BitArrayRepr::from_raw(data, IxDyn(&res_dim)),
-            plc.clone(),
-        ))
+        // Computing the dimension
+        let mut res_dim = xs[0].0.shape().to_vec();
+        let stacked_dim: usize = xs.iter().fold(0, |acc, a| acc + a.0.shape()[axis as usize]);
+        res_dim[axis as usize] = stacked_dim;
+
+        Ok(HostBitTensor(
+            BitArrayRepr::from_raw(data, IxDyn(&res_dim)),
+            plc.clone(),
+        ))
+    }
+}
+
+/// Stacks arrays of identical shape along a new `axis`.
+fn stack<A: Clone>(axis: usize, xs: &[&ArcArrayD<A>]) -> Result<ArcArrayD<A>> {
+    let first = xs
+        .first()
+        .ok_or_else(|| Error::KernelError("StackOp requires at least one input".to_string()))?;
+    if let Some(x) = xs.iter().find(|x| x.shape() != first.shape()) {
+        return Err(Error::KernelError(format!(
+            "StackOp expected inputs of equal shape but got {:?} and {:?}",
+            first.shape(),
+            x.shape()
+        )));
+    }
+    if axis > first.ndim() {
+        return Err(Error::KernelError(format!(
+            "StackOp axis {} out of bounds for inputs of rank {}",
+            axis,
+            first.ndim()
+        )));
+    }
+    let views: Vec<_> = xs.iter().map(|x| x.view()).collect();
+    let stacked =
+        ndarray::stack(Axis(axis), &views).map_err(|e| Error::KernelError(e.to_string()))?;
+    Ok(stacked.into_shared())
+}
+
+impl StackOp {
+    pub(crate) fn host_kernel<S: RuntimeSession, T: LinalgScalar>(
+        _sess: &S,
+        plc: &HostPlacement,
+        axis: usize,
+        xs: &[HostTensor<T>],
+    ) -> Result<HostTensor<T>> {
+        let arrs: Vec<_> = xs.iter().map(|x| &x.0).collect();
+        let stacked = stack(axis, &arrs)?;
+        Ok(HostTensor(stacked, plc.clone()))
+    }
+
+    pub(crate) fn ring_kernel<S: RuntimeSession, T>(
+        _sess: &S,
+        plc: &HostPlacement,
+        axis: usize,
+        xs: &[HostRingTensor<T>],
+    ) -> Result<HostRingTensor<T>>
+    where
+        T: Clone,
+    {
+        let arrs: Vec<_> = xs.iter().map(|x| &x.0).collect();
+        let stacked = stack(axis, &arrs)?;
+        Ok(HostRingTensor(stacked, plc.clone()))
+    }
+}
+
+/// Concatenates `reps` copies of `x` along `axis`.
+fn tile<A: Clone>(x: &ArcArrayD<A>, axis: usize, reps: usize) -> Result<ArcArrayD<A>> {
+    if axis >= x.ndim() {
+        return Err(Error::KernelError(format!(
+            "TileOp axis {} out of bounds for tensor of rank {}",
+            axis,
+            x.ndim()
+        )));
+    }
+    let ax = Axis(axis);
+    if reps == 0 {
+        return Ok(x.slice_axis(ax, Slice::from(0..0)).to_owned().into_shared());
+    }
+    let views = vec![x.view(); reps];
+    let tiled = ndarray::concatenate(ax, &views).map_err(|e| Error::KernelError(e.to_string()))?;
+    Ok(tiled.into_shared())
+}
+
+impl TileOp {
+    pub(crate) fn host_kernel<S: RuntimeSession, T: LinalgScalar>(
+        _sess: &S,
+        plc: &HostPlacement,
+        axis: usize,
+        reps: usize,
+        x: HostTensor<T>,
+    ) -> Result<HostTensor<T>> {
+        let tiled = tile(&x.0, axis, reps)?;
+        Ok(HostTensor(tiled, plc.clone()))
+    }
+
+    pub(crate) fn ring_kernel<S: RuntimeSession, T>(
+        _sess: &S,
+        plc: &HostPlacement,
+        axis: usize,
+        reps: usize,
+        x: HostRingTensor<T>,
+    ) -> Result<HostRingTensor<T>>
+    where
+        T: Clone,
+    {
+        let tiled = tile(&x.0, axis, reps)?;
+        Ok(HostRingTensor(tiled, plc.clone()))
+    }
+}
+
+/// Surrounds `x` with `value`, using `pad_width[2 * i]` and `pad_width[2 * i + 1]` entries
+/// before and after the data along axis `i`.
+fn pad<A: Clone>(x: &ArcArrayD<A>, pad_width: &[usize], value: A) -> Result<ArcArrayD<A>> {
+    if pad_width.len() != 2 * x.ndim() {
+        return Err(Error::KernelError(format!(
+            "PadOp expected {} pad widths for tensor of rank {} but got {}",
+            2 * x.ndim(),
+            x.ndim(),
+            pad_width.len()
+        )));
+    }
+    let shape: Vec<usize> = x
+        .shape()
+        .iter()
+        .enumerate()
+        .map(|(i, len)| pad_width[2 * i] + len + pad_width[2 * i + 1])
+        .collect();
+    let mut res = ArrayD::from_elem(IxDyn(&shape), value);
+    let mut interior = res.view_mut();
+    for (i, len) in x.shape().iter().enumerate() {
+        let before = pad_width[2 * i];
+        interior.slice_axis_inplace(Axis(i), Slice::from(before..before + len));
+    }
+    interior.assign(x);
+    Ok(res.into_shared())
+}
+
+impl PadOp {
+    pub(crate) fn host_kernel<S: RuntimeSession, T: LinalgScalar + FromPrimitive>(
+        _sess: &S,
+        plc: &HostPlacement,
+        pad_width: Vec<usize>,
+        pad_value: f64,
+        x: HostTensor<T>,
+    ) -> Result<HostTensor<T>> {
+        let pad_value = T::from_f64(pad_value).ok_or_else(|| {
+            Error::KernelError(format!("PadOp could not convert pad_value={}", pad_value))
+        })?;
+        let res = pad(&x.0, &pad_width, pad_value)?;
+        Ok(HostTensor(res, plc.clone()))
+    }
+
+    /// Pads ring tensors with zeros; other padding values are rejected.
+    pub(crate) fn ring_kernel<S: RuntimeSession, T>(
+        _sess: &S,
+        plc: &HostPlacement,
+        pad_width: Vec<usize>,
+        pad_value: f64,
+        x: HostRingTensor<T>,
+    ) -> Result<HostRingTensor<T>>
+    where
+        T: Clone + Zero,
+    {
+        if pad_value != 0.0 {
+            return Err(Error::KernelError(format!(
+                "PadOp only supports zero padding for ring tensors but got pad_value={}",
+                pad_value
+            )));
+        }
+        let res = pad(&x.0, &pad_width, Wrapping(T::zero()))?;
+        Ok(HostRingTensor(res, plc.clone()))
     }
 }
 
@@ -1257,7 +2166,7 @@ impl InverseOp {
         _x: HostTensor<T>,
     ) -> Result<HostTensor<T>> {
         Err(Error::UnimplementedOperator(
-            "Please enable 'blas' feature".to_string(),
+            "matrix inverse requires the 'blas' feature".to_string(),
         ))
     }
 }
@@ -1445,6 +2354,19 @@ impl XorOp {
         let arr = &x.0 ^ &y.0;
         Ok(HostBitTensor(arr, plc.clone()))
     }
+
+    pub(crate) fn host_ring_kernel<S: RuntimeSession, T>(
+        _sess: &S,
+        plc: &HostPlacement,
+        x: HostRingTensor<T>,
+        y: HostRingTensor<T>,
+    ) -> Result<HostRingTensor<T>>
+    where
+        Wrapping<T>: Clone,
+        Wrapping<T>: std::ops::BitXor<Wrapping<T>, Output = Wrapping<T>>,
+    {
+        Ok(HostRingTensor(x.0 ^ y.0, plc.clone()))
+    }
 }
 
 impl NegOp {
@@ -1456,6 +2378,17 @@ impl NegOp {
         let arr = !(&x.0);
         Ok(HostBitTensor(arr, plc.clone()))
     }
+
+    pub(crate) fn host_kernel<S: RuntimeSession, T>(
+        _sess: &S,
+        plc: &HostPlacement,
+        x: HostTensor<T>,
+    ) -> Result<HostTensor<T>>
+    where
+        T: LinalgScalar + std::ops::Neg<Output = T>,
+    {
+        Ok(HostTensor(x.0.mapv(|v| -v).into_shared(), plc.clone()))
+    }
 }
 
 impl AndOp {
@@ -1493,6 +2426,62 @@ impl OrOp {
         let arr = &x.0 | &y.0;
         Ok(HostBitTensor(arr, plc.clone()))
     }
+
+    pub(crate) fn host_ring_kernel<S: RuntimeSession, T>(
+        _sess: &S,
+        plc: &HostPlacement,
+        x: HostRingTensor<T>,
+        y: HostRingTensor<T>,
+    ) -> Result<HostRingTensor<T>>
+    where
+        Wrapping<T>: Clone,
+        Wrapping<T>: std::ops::BitOr<Wrapping<T>, Output = Wrapping<T>>,
+    {
+        Ok(HostRingTensor(x.0 | y.0, plc.clone()))
+    }
+}
+
+impl NotOp {
+    pub(crate) fn host_kernel<S: RuntimeSession>(
+        _sess: &S,
+        plc: &HostPlacement,
+        x: HostBitTensor,
+    ) -> Result<HostBitTensor> {
+        let arr = !(&x.0);
+        Ok(HostBitTensor(arr, plc.clone()))
+    }
+
+    pub(crate) fn host_ring_kernel<S: RuntimeSession, T>(
+        _sess: &S,
+        plc: &HostPlacement,
+        x: HostRingTensor<T>,
+    ) -> Result<HostRingTensor<T>>
+    where
+        T: Clone + std::ops::Not<Output = T>,
+    {
+        let arr = x.0.mapv(|Wrapping(v)| Wrapping(!v));
+        Ok(HostRingTensor(arr.into_shared(), plc.clone()))
+    }
+}
+
+impl PopcountOp {
+    pub(crate) fn ring64_kernel<S: RuntimeSession>(
+        _sess: &S,
+        plc: &HostPlacement,
+        x: HostRing64Tensor,
+    ) -> Result<HostRing64Tensor> {
+        let res = x.0.mapv(|v| Wrapping(v.0.count_ones() as u64));
+        Ok(HostRingTensor(res.into_shared(), plc.clone()))
+    }
+
+    pub(crate) fn ring128_kernel<S: RuntimeSession>(
+        _sess: &S,
+        plc: &HostPlacement,
+        x: HostRing128Tensor,
+    ) -> Result<HostRing64Tensor> {
+        let res = x.0.mapv(|v| Wrapping(v.0.count_ones() as u64));
+        Ok(HostRingTensor(res.into_shared(), plc.clone()))
+    }
 }
 
 impl BitExtractOp {
@@ -1597,17 +2586,18 @@ impl ShapeOp {
 }
 
 impl BroadcastOp {
-    pub(crate) fn host_ring_kernel<S: RuntimeSession, T: Clone + std::fmt::Debug>(
+    pub(crate) fn host_ring_kernel<S: RuntimeSession, T: Clone>(
         _sess: &S,
         plc: &HostPlacement,
         s: HostShape,
         x: HostRingTensor<T>,
     ) -> Result<HostRingTensor<T>> {
-        match x.0.broadcast(s.clone().0 .0) {
+        match x.0.broadcast(s.0 .0.as_slice()) {
             Some(y) => Ok(HostRingTensor(y.to_owned().into_shared(), plc.clone())),
             None => Err(Error::KernelError(format!(
-                "Tensor {:?} not broadcastable to shape {:?}.",
-                x, s
+                "Tensor of shape {:?} not broadcastable to shape {:?}.",
+                x.0.shape(),
+                s.0 .0
             ))),
         }
     }
@@ -1816,6 +2806,42 @@ impl ShrOp {
     }
 }
 
+impl RotateOp {
+    /// Rotate the bits of every element by `amount`, to the left if `left` is set.
+    pub(crate) fn ring64_kernel<S: RuntimeSession>(
+        _sess: &S,
+        plc: &HostPlacement,
+        amount: usize,
+        left: bool,
+        x: HostRing64Tensor,
+    ) -> Result<HostRing64Tensor> {
+        let amount = (amount % 64) as u32;
+        let res = if left {
+            x.0.mapv(|v| Wrapping(v.0.rotate_left(amount)))
+        } else {
+            x.0.mapv(|v| Wrapping(v.0.rotate_right(amount)))
+        };
+        Ok(HostRingTensor(res.into_shared(), plc.clone()))
+    }
+
+    /// Rotate the bits of every element by `amount`, to the left if `left` is set.
+    pub(crate) fn ring128_kernel<S: RuntimeSession>(
+        _sess: &S,
+        plc: &HostPlacement,
+        amount: usize,
+        left: bool,
+        x: HostRing128Tensor,
+    ) -> Result<HostRing128Tensor> {
+        let amount = (amount % 128) as u32;
+        let res = if left {
+            x.0.mapv(|v| Wrapping(v.0.rotate_left(amount)))
+        } else {
+            x.0.mapv(|v| Wrapping(v.0.rotate_right(amount)))
+        };
+        Ok(HostRingTensor(res.into_shared(), plc.clone()))
+    }
+}
+
 impl SampleOp {
     pub(crate) fn ring64_kernel<S: RuntimeSession>(
         _sess: &S,
@@ -2119,6 +3145,101 @@ impl GreaterOp {
     }
 }
 
+/// Applies the element-wise predicate `f` to `x` and `y`, collecting the results as bits.
+///
+/// One of the operands may be broadcast to the shape of the other.
+fn compare<A, B, F>(
+    plc: &HostPlacement,
+    x: &ArcArrayD<A>,
+    y: &ArcArrayD<B>,
+    f: F,
+) -> Result<HostBitTensor>
+where
+    F: Fn(&A, &B) -> bool,
+{
+    use bitvec::prelude::*;
+    let (x, y) = broadcast_views(x, y)?;
+    let dim = x.raw_dim();
+    let bits = Zip::from(&x).and(&y).map_collect(&f);
+    let data: BitVec<u8, Lsb0> = bits.iter().copied().collect();
+    let result = BitArrayRepr::from_raw(data, dim);
+    Ok(HostBitTensor(result, plc.clone()))
+}
+
+impl EqualOp {
+    pub(crate) fn host_float_kernel<S: RuntimeSession, T: LinalgScalar + PartialEq>(
+        _sess: &S,
+        plc: &HostPlacement,
+        x: HostTensor<T>,
+        y: HostTensor<T>,
+    ) -> Result<HostBitTensor> {
+        compare(plc, &x.0, &y.0, |x, y| x == y)
+    }
+
+    pub(crate) fn host_ring_kernel<S: RuntimeSession, T: PartialEq>(
+        _sess: &S,
+        plc: &HostPlacement,
+        x: HostRingTensor<T>,
+        y: HostRingTensor<T>,
+    ) -> Result<HostBitTensor> {
+        compare(plc, &x.0, &y.0, |x, y| x == y)
+    }
+}
+
+impl NotEqualOp {
+    pub(crate) fn host_float_kernel<S: RuntimeSession, T: LinalgScalar + PartialEq>(
+        _sess: &S,
+        plc: &HostPlacement,
+        x: HostTensor<T>,
+        y: HostTensor<T>,
+    ) -> Result<HostBitTensor> {
+        compare(plc, &x.0, &y.0, |x, y| x != y)
+    }
+
+    pub(crate) fn host_ring_kernel<S: RuntimeSession, T: PartialEq>(
+        _sess: &S,
+        plc: &HostPlacement,
+        x: HostRingTensor<T>,
+        y: HostRingTensor<T>,
+    ) -> Result<HostBitTensor> {
+        compare(plc, &x.0, &y.0, |x, y| x != y)
+    }
+}
+
+impl GreaterEqualOp {
+    /// Compares the ring elements as signed integers via the sign of `x - y`, like
+    /// `LessOp`; the result is only meaningful when `x - y` does not overflow, i.e. when
+    /// the two values are less than `2^63` apart.
+    pub(crate) fn host_ring64_kernel<S: RuntimeSession>(
+        _sess: &S,
+        plc: &HostPlacement,
+        x: HostRing64Tensor,
+        y: HostRing64Tensor,
+    ) -> Result<HostBitTensor> {
+        compare(plc, &x.0, &y.0, |&x, &y| ((x - y).0 as i64) >= 0)
+    }
+
+    /// See `host_ring64_kernel`; values must be less than `2^127` apart.
+    pub(crate) fn host_ring128_kernel<S: RuntimeSession>(
+        _sess: &S,
+        plc: &HostPlacement,
+        x: HostRing128Tensor,
+        y: HostRing128Tensor,
+    ) -> Result<HostBitTensor> {
+        compare(plc, &x.0, &y.0, |&x, &y| ((x - y).0 as i128) >= 0)
+    }
+
+    /// Compares the elements directly, so NaN is never greater than or equal to anything.
+    pub(crate) fn host_float_kernel<S: RuntimeSession, T: LinalgScalar + PartialOrd>(
+        _sess: &S,
+        plc: &HostPlacement,
+        x: HostTensor<T>,
+        y: HostTensor<T>,
+    ) -> Result<HostBitTensor> {
+        compare(plc, &x.0, &y.0, |x, y| x >= y)
+    }
+}
+
 impl IdentityOp {
     pub(crate) fn host_kernel<S: Session, HostRingT>(
         sess: &S,
@@ -2188,6 +3309,56 @@ impl MuxOp {
     }
 }
 
+/// Picks entries of `x` where `s` is set and entries of `y` elsewhere.
+///
+/// Unlike the arithmetic used by `MuxOp`, the unselected operand never influences the
+/// result, so e.g. infinities in it do not turn into NaN.
+fn select<A: Clone>(s: &HostBitTensor, x: &ArcArrayD<A>, y: &ArcArrayD<A>) -> Result<ArcArrayD<A>> {
+    if s.0.shape() != x.shape() || s.0.shape() != y.shape() {
+        return Err(Error::KernelError(format!(
+            "WhereOp expected operands of equal shape but got {:?}, {:?}, and {:?}",
+            s.0.shape(),
+            x.shape(),
+            y.shape()
+        )));
+    }
+    let s =
+        s.0.into_array::<u8>()
+            .map_err(|e| Error::KernelError(e.to_string()))?;
+    let res = Zip::from(&s)
+        .and(x)
+        .and(y)
+        .map_collect(|&s, x, y| if s != 0 { x.clone() } else { y.clone() });
+    Ok(res.into_shared())
+}
+
+impl WhereOp {
+    pub(crate) fn host_kernel<S: RuntimeSession, T: LinalgScalar>(
+        _sess: &S,
+        plc: &HostPlacement,
+        s: HostBitTensor,
+        x: HostTensor<T>,
+        y: HostTensor<T>,
+    ) -> Result<HostTensor<T>> {
+        let res = select(&s, &x.0, &y.0)?;
+        Ok(HostTensor(res, plc.clone()))
+    }
+
+    pub(crate) fn host_ring_kernel<S: RuntimeSession, T>(
+        _sess: &S,
+        plc: &HostPlacement,
+        s: HostBitTensor,
+        x: HostRingTensor<T>,
+        y: HostRingTensor<T>,
+    ) -> Result<HostRingTensor<T>>
+    where
+        T: Clone,
+    {
+        let res = select(&s, &x.0, &y.0)?;
+        Ok(HostRingTensor(res, plc.clone()))
+    }
+}
+
 impl CastOp {
     pub(crate) fn no_op_reduction_kernel<S: RuntimeSession, T>(
         sess: &S,
@@ -2389,6 +3560,72 @@ impl ArgmaxOp {
         let arg_out = plc.argmax(sess, axis, upmost_index, &x.tensor);
         Ok(plc.cast(sess, &arg_out))
     }
+
+    /// Indices of the largest values along `axis`; ties resolve to the lowest index.
+    pub(crate) fn host_float_kernel<S: RuntimeSession, T>(
+        _sess: &S,
+        plc: &HostPlacement,
+        axis: usize,
+        _upmost_index: usize,
+        x: HostTensor<T>,
+    ) -> Result<HostUint64Tensor>
+    where
+        T: Clone + PartialOrd,
+    {
+        if axis >= x.0.ndim() || x.0.len_of(Axis(axis)) == 0 {
+            return Err(Error::KernelError(format!(
+                "ArgmaxOp cannot reduce along axis {} of tensor with shape {:?}",
+                axis,
+                x.0.shape()
+            )));
+        }
+        let axis = Axis(axis);
+
+        let mut current_max = x.0.index_axis(axis, 0).to_owned();
+        let mut current_index = current_max.mapv(|_| 0_u64);
+
+        for (index, subview) in x.0.axis_iter(axis).enumerate().skip(1) {
+            let index = index as u64;
+            Zip::from(&mut current_max)
+                .and(&mut current_index)
+                .and(&subview)
+                .for_each(|max_entry, index_entry, subview_entry| {
+                    if *max_entry < *subview_entry {
+                        *max_entry = subview_entry.clone();
+                        *index_entry = index;
+                    }
+                });
+        }
+        Ok(HostTensor(current_index.into_shared(), plc.clone()))
+    }
+}
+
+/// Element-wise fold over a non-empty sequence of arrays.
+///
+/// Operands are broadcast against the running result, which means that e.g. scalar
+/// tensors may be mixed with tensors of higher rank.
+fn broadcasting_fold<'a, T, I, F>(xs: I, f: F) -> Result<ArcArrayD<T>>
+where
+    T: 'a + Clone,
+    I: IntoIterator<Item = &'a ArcArrayD<T>>,
+    F: Fn(&mut T, &T),
+{
+    let mut xs = xs.into_iter();
+    let mut acc = match xs.next() {
+        Some(x) => x.clone(),
+        None => {
+            return Err(Error::InvalidArgument(
+                "cannot reduce on empty array of tensors".to_string(),
+            ))
+        }
+    };
+    for item in xs {
+        let (acc_view, item_view) = broadcast_views(&acc, item)?;
+        let mut next = acc_view.to_owned();
+        Zip::from(&mut next).and(&item_view).for_each(&f);
+        acc = next.into_shared();
+    }
+    Ok(acc)
 }
 
 impl MaximumOp {
@@ -2398,23 +3635,14 @@ impl MaximumOp {
         xs: &[HostTensor<T>],
     ) -> Result<HostTensor<T>>
     where
-        T: Clone + std::cmp::PartialOrd + Copy,
+        T: Clone + std::cmp::PartialOrd,
     {
-        if xs.is_empty() {
-            Err(Error::InvalidArgument(
-                "cannot reduce on empty array of tensors".to_string(),
-            ))
-        } else {
-            let mut init = xs[0].0.clone();
-            for item in xs.iter() {
-                Zip::from(&mut init).and(&item.0).for_each(|a, &b| {
-                    if *a < b {
-                        *a = b
-                    }
-                });
+        let res = broadcasting_fold(xs.iter().map(|x| &x.0), |a, b| {
+            if *a < *b {
+                *a = b.clone()
             }
-            Ok(HostTensor(init, plc.clone()))
-        }
+        })?;
+        Ok(HostTensor(res, plc.clone()))
     }
 
     pub(crate) fn host_ring_kernel<S: RuntimeSession, T>(
@@ -2424,23 +3652,49 @@ impl MaximumOp {
     ) -> Result<HostRingTensor<T>>
     where
         T: Clone,
-        Wrapping<T>: std::cmp::PartialOrd + Copy,
+        Wrapping<T>: std::cmp::PartialOrd,
     {
-        if xs.is_empty() {
-            Err(Error::InvalidArgument(
-                "cannot reduce on empty array of tensors".to_string(),
-            ))
-        } else {
-            let mut init = xs[0].0.clone();
-            for item in xs.iter() {
-                Zip::from(&mut init).and(&item.0).for_each(|a, &b| {
-                    if *a < b {
-                        *a = b
-                    }
-                });
+        let res = broadcasting_fold(xs.iter().map(|x| &x.0), |a, b| {
+            if *a < *b {
+                *a = b.clone()
             }
-            Ok(HostRingTensor(init, plc.clone()))
-        }
+        })?;
+        Ok(HostRingTensor(res, plc.clone()))
+    }
+}
+
+impl MinimumOp {
+    pub(crate) fn host_kernel<S: Session, T>(
+        _sess: &S,
+        plc: &HostPlacement,
+        xs: &[HostTensor<T>],
+    ) -> Result<HostTensor<T>>
+    where
+        T: Clone + std::cmp::PartialOrd,
+    {
+        let res = broadcasting_fold(xs.iter().map(|x| &x.0), |a, b| {
+            if *a > *b {
+                *a = b.clone()
+            }
+        })?;
+        Ok(HostTensor(res, plc.clone()))
+    }
+
+    pub(crate) fn host_ring_kernel<S: RuntimeSession, T>(
+        _sess: &S,
+        plc: &HostPlacement,
+        xs: &[HostRingTensor<T>],
+    ) -> Result<HostRingTensor<T>>
+    where
+        T: Clone,
+        Wrapping<T>: std::cmp::PartialOrd,
+    {
+        let res = broadcasting_fold(xs.iter().map(|x| &x.0), |a, b| {
+            if *a > *b {
+                *a = b.clone()
+            }
+        })?;
+        Ok(HostRingTensor(res, plc.clone()))
     }
 }
 
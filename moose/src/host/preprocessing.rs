@@ -0,0 +1,952 @@
+//! Host kernels for transforming scores, labels, and features, and for resampling rows.
+
+use super::ops::variance;
+use super::*;
+use crate::error::{Error, Result};
+use crate::execution::RuntimeSession;
+use aes_prng::AesRng;
+use ndarray::Zip;
+use num_traits::{Float, FromPrimitive};
+use std::collections::BTreeMap;
+use std::num::Wrapping;
+
+impl NormalizeProbOp {
+    /// Divide nonnegative scores by their sum along `axis`.
+    ///
+    /// Lanes summing to zero are filled with `1 / n` when `uniform_if_zero` is set
+    /// and left at zero otherwise.
+    pub(crate) fn host_kernel<S: RuntimeSession, T>(
+        _sess: &S,
+        plc: &HostPlacement,
+        axis: usize,
+        uniform_if_zero: bool,
+        x: HostTensor<T>,
+    ) -> Result<HostTensor<T>>
+    where
+        T: LinalgScalar + Float + FromPrimitive,
+    {
+        if axis >= x.0.ndim() {
+            return Err(Error::KernelError(format!(
+                "NormalizeProbOp axis {} out of bounds for tensor of rank {}",
+                axis,
+                x.0.ndim()
+            )));
+        }
+        if x.0.iter().any(|v| *v < T::zero()) {
+            return Err(Error::KernelError(
+                "NormalizeProbOp expected nonnegative scores".to_string(),
+            ));
+        }
+
+        let mut res = x.0.to_owned();
+        let n = T::from_usize(res.len_of(Axis(axis))).unwrap();
+        for mut lane in res.lanes_mut(Axis(axis)) {
+            let sum = lane.sum();
+            if sum == T::zero() {
+                lane.fill(if uniform_if_zero {
+                    T::one() / n
+                } else {
+                    T::zero()
+                });
+            } else {
+                lane.mapv_inplace(|v| v / sum);
+            }
+        }
+        Ok(HostTensor(res.into_shared(), plc.clone()))
+    }
+}
+
+impl TemperatureScaleOp {
+    /// Softmax of `x / temperature` along `axis`.
+    ///
+    /// Temperatures above one soften the resulting distribution, temperatures below one
+    /// sharpen it.
+    pub(crate) fn host_kernel<S: RuntimeSession, T>(
+        sess: &S,
+        plc: &HostPlacement,
+        axis: usize,
+        temperature: f64,
+        x: HostTensor<T>,
+    ) -> Result<HostTensor<T>>
+    where
+        T: 'static + Float + ndarray::ScalarOperand,
+        HostPlacement: PlacementPlace<S, HostTensor<T>>,
+    {
+        if temperature <= 0.0 {
+            return Err(Error::KernelError(format!(
+                "TemperatureScaleOp expected positive temperature but got {}",
+                temperature
+            )));
+        }
+        if axis >= x.0.ndim() || x.0.len_of(Axis(axis)) == 0 {
+            return Err(Error::KernelError(format!(
+                "TemperatureScaleOp cannot apply softmax along axis {} of tensor with shape {:?}",
+                axis,
+                x.0.shape()
+            )));
+        }
+        let temperature = T::from(temperature).unwrap();
+        let scaled = HostTensor(x.0.mapv(|v| v / temperature).into_shared(), x.1);
+        SoftmaxOp::host_kernel(sess, plc, axis, 0, scaled)
+    }
+}
+
+impl LabelSmoothingOp {
+    /// Smoothed one-hot encoding of `label` over `classes` classes.
+    ///
+    /// The true class receives `1 - eps` and every other class `eps / (classes - 1)`,
+    /// yielding a tensor with an additional trailing axis of length `classes`.
+    pub(crate) fn host_kernel<S: RuntimeSession>(
+        _sess: &S,
+        plc: &HostPlacement,
+        classes: usize,
+        eps: f64,
+        label: HostUint64Tensor,
+    ) -> Result<HostFloat64Tensor> {
+        if classes < 2 {
+            return Err(Error::KernelError(format!(
+                "LabelSmoothingOp expected at least two classes but got {}",
+                classes
+            )));
+        }
+        if !(0.0..=1.0).contains(&eps) {
+            return Err(Error::KernelError(format!(
+                "LabelSmoothingOp expected eps in [0, 1] but got {}",
+                eps
+            )));
+        }
+        if let Some(l) = label.0.iter().find(|l| **l as usize >= classes) {
+            return Err(Error::KernelError(format!(
+                "LabelSmoothingOp label {} out of range for {} classes",
+                l, classes
+            )));
+        }
+
+        let off = eps / (classes - 1) as f64;
+        let mut shape = label.0.shape().to_vec();
+        shape.push(classes);
+        let mut res = ArrayD::from_elem(shape, off);
+        for (mut row, l) in res
+            .lanes_mut(Axis(label.0.ndim()))
+            .into_iter()
+            .zip(label.0.iter())
+        {
+            row[*l as usize] = 1.0 - eps;
+        }
+        Ok(HostTensor(res.into_shared(), plc.clone()))
+    }
+}
+
+impl MixupOp {
+    /// Convex combination `lambda * x + (1 - lambda) * y` of two batches.
+    ///
+    /// Features and (one-hot or smoothed) labels are mixed by applying the operation to
+    /// each pair with the same `lambda`. Both operands must have the same shape.
+    pub(crate) fn host_kernel<S: RuntimeSession, T>(
+        _sess: &S,
+        plc: &HostPlacement,
+        lambda: f64,
+        x: HostTensor<T>,
+        y: HostTensor<T>,
+    ) -> Result<HostTensor<T>>
+    where
+        T: LinalgScalar + Float,
+    {
+        if !(0.0..=1.0).contains(&lambda) {
+            return Err(Error::KernelError(format!(
+                "MixupOp expected lambda in [0, 1] but got {}",
+                lambda
+            )));
+        }
+        if x.0.shape() != y.0.shape() {
+            return Err(Error::KernelError(format!(
+                "MixupOp expected tensors of equal shape but got {:?} and {:?}",
+                x.0.shape(),
+                y.0.shape()
+            )));
+        }
+        let lambda = T::from(lambda).unwrap();
+        let res = Zip::from(&x.0)
+            .and(&y.0)
+            .map_collect(|&a, &b| lambda * a + (T::one() - lambda) * b);
+        Ok(HostTensor(res.into_shared(), plc.clone()))
+    }
+}
+
+impl StratifiedSampleOp {
+    /// Draw `count` distinct row indices for every group in `label`, seeded by `seed`.
+    ///
+    /// Groups appear in ascending label order with their indices sorted; the result may be
+    /// used with `GatherOp` to select the corresponding rows of the data.
+    pub(crate) fn host_kernel<S: RuntimeSession>(
+        _sess: &S,
+        plc: &HostPlacement,
+        count: usize,
+        label: HostUint64Tensor,
+        seed: HostSeed,
+    ) -> Result<HostRing64Tensor> {
+        if label.0.ndim() != 1 {
+            return Err(Error::KernelError(format!(
+                "StratifiedSampleOp expected labels of rank 1 but got rank {}",
+                label.0.ndim()
+            )));
+        }
+
+        let mut groups: BTreeMap<u64, Vec<usize>> = BTreeMap::new();
+        for (i, l) in label.0.iter().enumerate() {
+            groups.entry(*l).or_default().push(i);
+        }
+
+        let mut rng = AesRng::from_seed(seed.0 .0);
+        let mut indices = Vec::with_capacity(groups.len() * count);
+        for (l, rows) in groups {
+            if rows.len() < count {
+                return Err(Error::KernelError(format!(
+                    "StratifiedSampleOp requested {} rows but group {} only has {}",
+                    count,
+                    l,
+                    rows.len()
+                )));
+            }
+            let mut selected: Vec<usize> = rows.choose_multiple(&mut rng, count).cloned().collect();
+            selected.sort_unstable();
+            indices.extend(selected.into_iter().map(|i| Wrapping(i as u64)));
+        }
+        Ok(HostRingTensor(
+            Array::from_vec(indices).into_dyn().into_shared(),
+            plc.clone(),
+        ))
+    }
+}
+
+impl BootstrapOp {
+    /// Draw `size` row indices with replacement from the leading axis of `shape`.
+    pub(crate) fn host_kernel<S: RuntimeSession>(
+        _sess: &S,
+        plc: &HostPlacement,
+        size: usize,
+        shape: HostShape,
+        seed: HostSeed,
+    ) -> Result<HostRing64Tensor> {
+        let rows = match shape.0 .0.first() {
+            Some(rows) if *rows > 0 => *rows as u64,
+            _ => {
+                return Err(Error::KernelError(format!(
+                    "BootstrapOp cannot resample from shape {:?}",
+                    shape.0 .0
+                )))
+            }
+        };
+        let mut rng = AesRng::from_seed(seed.0 .0);
+        let indices: Vec<_> = (0..size)
+            .map(|_| Wrapping(rng.gen_range(0..rows)))
+            .collect();
+        Ok(HostRingTensor(
+            Array::from_vec(indices).into_dyn().into_shared(),
+            plc.clone(),
+        ))
+    }
+}
+
+impl KFoldOp {
+    /// Row indices of the train or test split of `fold` out of `k` folds.
+    ///
+    /// Rows along the leading axis of `shape` are shuffled using `seed` and cut into `k`
+    /// folds of near equal size; `test` selects the held out fold, otherwise the remaining
+    /// rows are returned. Indices are sorted, and the same seed yields the same partition
+    /// across folds.
+    pub(crate) fn host_kernel<S: RuntimeSession>(
+        _sess: &S,
+        plc: &HostPlacement,
+        k: usize,
+        fold: usize,
+        test: bool,
+        shape: HostShape,
+        seed: HostSeed,
+    ) -> Result<HostRing64Tensor> {
+        let rows = shape.0 .0.first().cloned().unwrap_or(0);
+        if k < 2 || k > rows || fold >= k {
+            return Err(Error::KernelError(format!(
+                "KFoldOp cannot select fold {} of {} folds over {} rows",
+                fold, k, rows
+            )));
+        }
+
+        let mut rng = AesRng::from_seed(seed.0 .0);
+        let mut perm: Vec<usize> = (0..rows).collect();
+        perm.shuffle(&mut rng);
+
+        let start = fold * rows / k;
+        let end = (fold + 1) * rows / k;
+        let mut indices: Vec<usize> = if test {
+            perm[start..end].to_vec()
+        } else {
+            perm[..start].iter().chain(&perm[end..]).cloned().collect()
+        };
+        indices.sort_unstable();
+        let indices: Vec<_> = indices.into_iter().map(|i| Wrapping(i as u64)).collect();
+        Ok(HostRingTensor(
+            Array::from_vec(indices).into_dyn().into_shared(),
+            plc.clone(),
+        ))
+    }
+}
+
+impl RankNormOp {
+    /// Replace values by their normalized rank `(r - 0.5) / n` along `axis`.
+    ///
+    /// Ranks are one-based with ties averaged, so the result lies strictly between zero and
+    /// one. With `gaussianize` set the ranks are mapped through the inverse normal CDF.
+    pub(crate) fn host_kernel<S: RuntimeSession, T>(
+        _sess: &S,
+        plc: &HostPlacement,
+        axis: usize,
+        gaussianize: bool,
+        x: HostTensor<T>,
+    ) -> Result<HostTensor<T>>
+    where
+        T: LinalgScalar + Float,
+    {
+        if axis >= x.0.ndim() {
+            return Err(Error::KernelError(format!(
+                "RankNormOp axis {} out of bounds for tensor of rank {}",
+                axis,
+                x.0.ndim()
+            )));
+        }
+
+        let mut res = x.0.to_owned();
+        let n = res.len_of(Axis(axis)) as f64;
+        for mut lane in res.lanes_mut(Axis(axis)) {
+            let values: Vec<T> = lane.iter().cloned().collect();
+            for (v, r) in lane.iter_mut().zip(average_ranks(&values)) {
+                let u = (r - 0.5) / n;
+                *v = T::from(if gaussianize { probit(u) } else { u }).unwrap();
+            }
+        }
+        Ok(HostTensor(res.into_shared(), plc.clone()))
+    }
+}
+
+impl PolyFeaturesOp {
+    /// Products of the columns of the matrix `x` up to total degree `degree`.
+    ///
+    /// Columns are ordered by degree and then lexicographically by feature index, matching
+    /// scikit-learn's `PolynomialFeatures`; a leading column of ones is added when
+    /// `include_bias` is set.
+    pub(crate) fn host_kernel<S: RuntimeSession, T>(
+        _sess: &S,
+        plc: &HostPlacement,
+        degree: usize,
+        include_bias: bool,
+        x: HostTensor<T>,
+    ) -> Result<HostTensor<T>>
+    where
+        T: LinalgScalar + Float,
+    {
+        let x = x
+            .0
+            .into_dimensionality::<Ix2>()
+            .map_err(|e| Error::KernelError(format!("PolyFeaturesOp expected a matrix: {}", e)))?;
+        if degree == 0 && !include_bias {
+            return Err(Error::KernelError(
+                "PolyFeaturesOp would produce no features".to_string(),
+            ));
+        }
+
+        // feature index combinations with replacement, one degree at a time
+        let mut terms: Vec<Vec<usize>> = if include_bias { vec![vec![]] } else { vec![] };
+        let mut previous: Vec<Vec<usize>> = vec![vec![]];
+        for _ in 0..degree {
+            let current: Vec<Vec<usize>> = previous
+                .iter()
+                .flat_map(|term| {
+                    let start = term.last().cloned().unwrap_or(0);
+                    (start..x.ncols()).map(move |j| {
+                        let mut next = term.clone();
+                        next.push(j);
+                        next
+                    })
+                })
+                .collect();
+            terms.extend(current.iter().cloned());
+            previous = current;
+        }
+
+        let res = Array2::from_shape_fn((x.nrows(), terms.len()), |(i, k)| {
+            terms[k].iter().fold(T::one(), |acc, &j| acc * x[[i, j]])
+        });
+        Ok(HostTensor(res.into_dyn().into_shared(), plc.clone()))
+    }
+}
+
+impl StandardScalerFitOp {
+    /// Column means and standard deviations of the matrix `x`, stacked as a `[2, f]` tensor.
+    ///
+    /// Row 0 holds the means and row 1 the standard deviations with `ddof` delta degrees
+    /// of freedom.
+    pub(crate) fn host_kernel<S: RuntimeSession, T>(
+        _sess: &S,
+        plc: &HostPlacement,
+        ddof: u32,
+        x: HostTensor<T>,
+    ) -> Result<HostTensor<T>>
+    where
+        T: LinalgScalar + Float + FromPrimitive,
+    {
+        if x.0.ndim() != 2 {
+            return Err(Error::KernelError(format!(
+                "StandardScalerFitOp expected a matrix but got tensor of rank {}",
+                x.0.ndim()
+            )));
+        }
+        let mean = x.0.mean_axis(Axis(0)).ok_or_else(|| {
+            Error::KernelError("StandardScalerFitOp cannot fit on zero rows".to_string())
+        })?;
+        let std = variance(&x.0, Some(0), ddof)?.mapv(T::sqrt);
+        let res = ndarray::stack(Axis(0), &[mean.view(), std.view()])
+            .map_err(|e| Error::KernelError(e.to_string()))?;
+        Ok(HostTensor(res.into_shared(), plc.clone()))
+    }
+}
+
+impl MinMaxScalerFitOp {
+    /// Column minima and maxima of the matrix `x`, stacked as a `[2, f]` tensor.
+    ///
+    /// Row 0 holds the minima and row 1 the maxima.
+    pub(crate) fn host_kernel<S: RuntimeSession, T>(
+        _sess: &S,
+        plc: &HostPlacement,
+        x: HostTensor<T>,
+    ) -> Result<HostTensor<T>>
+    where
+        T: LinalgScalar + Float,
+    {
+        if x.0.ndim() != 2 || x.0.len_of(Axis(0)) == 0 {
+            return Err(Error::KernelError(format!(
+                "MinMaxScalerFitOp expected a non-empty matrix but got shape {:?}",
+                x.0.shape()
+            )));
+        }
+        let min = x.0.fold_axis(Axis(0), T::infinity(), |acc, v| acc.min(*v));
+        let max =
+            x.0.fold_axis(Axis(0), T::neg_infinity(), |acc, v| acc.max(*v));
+        let res = ndarray::stack(Axis(0), &[min.view(), max.view()])
+            .map_err(|e| Error::KernelError(e.to_string()))?;
+        Ok(HostTensor(res.into_shared(), plc.clone()))
+    }
+}
+
+impl PcaProjectOp {
+    /// Project the rows of `x`, centered by `mean`, onto the rows of `components`.
+    ///
+    /// With `x` of shape `[n, f]`, `mean` of shape `[f]` and `components` of shape `[k, f]`
+    /// the result is `(x - mean) . components^T` of shape `[n, k]`.
+    pub(crate) fn host_kernel<S: RuntimeSession, T>(
+        _sess: &S,
+        plc: &HostPlacement,
+        x: HostTensor<T>,
+        mean: HostTensor<T>,
+        components: HostTensor<T>,
+    ) -> Result<HostTensor<T>>
+    where
+        T: LinalgScalar,
+    {
+        let (x, mean, components) = pca_operands("PcaProjectOp", &x, &mean, &components)?;
+        let centered = &x - &mean;
+        let res = centered.dot(&components.t());
+        Ok(HostTensor(res.into_dyn().into_shared(), plc.clone()))
+    }
+}
+
+/// Matrix, mean vector, and component matrix views with matching feature dimensions.
+fn pca_operands<'a, T>(
+    op: &str,
+    x: &'a HostTensor<T>,
+    mean: &'a HostTensor<T>,
+    components: &'a HostTensor<T>,
+) -> Result<(ArrayView2<'a, T>, ArrayView1<'a, T>, ArrayView2<'a, T>)> {
+    let x = x.0.view().into_dimensionality::<Ix2>();
+    let mean = mean.0.view().into_dimensionality::<Ix1>();
+    let components = components.0.view().into_dimensionality::<Ix2>();
+    match (x, mean, components) {
+        (Ok(x), Ok(mean), Ok(components))
+            if x.ncols() == mean.len() && components.ncols() == mean.len() =>
+        {
+            Ok((x, mean, components))
+        }
+        _ => Err(Error::KernelError(format!(
+            "{} expected data [n, f], mean [f] and components [k, f] with matching f",
+            op
+        ))),
+    }
+}
+
+impl WhitenOp {
+    /// Whiten the centered matrix `x` given the eigendecomposition of its covariance.
+    ///
+    /// With `x` of shape `[n, f]`, `eigenvalues` of shape `[k]` and `eigenvectors` holding
+    /// one eigenvector per row in shape `[k, f]`, the data is projected onto the
+    /// eigenvectors and scaled by the inverse square root of the eigenvalues, giving shape
+    /// `[n, k]`. With `zca` set the result is rotated back into feature space, giving shape
+    /// `[n, f]`.
+    pub(crate) fn host_kernel<S: RuntimeSession, T>(
+        _sess: &S,
+        plc: &HostPlacement,
+        zca: bool,
+        x: HostTensor<T>,
+        eigenvalues: HostTensor<T>,
+        eigenvectors: HostTensor<T>,
+    ) -> Result<HostTensor<T>>
+    where
+        T: LinalgScalar + Float,
+    {
+        let x = x.0.view().into_dimensionality::<Ix2>();
+        let eigenvalues = eigenvalues.0.view().into_dimensionality::<Ix1>();
+        let eigenvectors = eigenvectors.0.view().into_dimensionality::<Ix2>();
+        let (x, eigenvalues, eigenvectors) = match (x, eigenvalues, eigenvectors) {
+            (Ok(x), Ok(values), Ok(vectors))
+                if vectors.nrows() == values.len() && vectors.ncols() == x.ncols() =>
+            {
+                (x, values, vectors)
+            }
+            _ => {
+                return Err(Error::KernelError(
+                    "WhitenOp expected data [n, f], eigenvalues [k] and eigenvectors [k, f]"
+                        .to_string(),
+                ))
+            }
+        };
+        if eigenvalues.iter().any(|v| *v <= T::zero()) {
+            return Err(Error::KernelError(
+                "WhitenOp expected positive eigenvalues".to_string(),
+            ));
+        }
+
+        let scale = eigenvalues.mapv(|v| T::one() / v.sqrt());
+        let projected = x.dot(&eigenvectors.t()) * &scale;
+        let res = if zca {
+            projected.dot(&eigenvectors)
+        } else {
+            projected
+        };
+        Ok(HostTensor(res.into_dyn().into_shared(), plc.clone()))
+    }
+}
+
+/// Inverse of the standard normal CDF for `p` in `(0, 1)`.
+///
+/// Uses Acklam's rational approximation, with a relative error below `1.2e-9`.
+fn probit(p: f64) -> f64 {
+    const A: [f64; 6] = [
+        -3.969683028665376e+01,
+        2.209460984245205e+02,
+        -2.759285104469687e+02,
+        1.38357751867269e+02,
+        -3.066479806614716e+01,
+        2.506628277459239e+00,
+    ];
+    const B: [f64; 5] = [
+        -5.447609879822406e+01,
+        1.615858368580409e+02,
+        -1.556989798598866e+02,
+        6.680131188771972e+01,
+        -1.328068155288572e+01,
+    ];
+    const C: [f64; 6] = [
+        -7.784894002430293e-03,
+        -3.223964580411365e-01,
+        -2.400758277161838e+00,
+        -2.549732539343734e+00,
+        4.374664141464968e+00,
+        2.938163982698783e+00,
+    ];
+    const D: [f64; 4] = [
+        7.784695709041462e-03,
+        3.224671290700398e-01,
+        2.445134137142996e+00,
+        3.754408661907416e+00,
+    ];
+    const P_LOW: f64 = 0.02425;
+
+    let tail = |q: f64| {
+        (((((C[0] * q + C[1]) * q + C[2]) * q + C[3]) * q + C[4]) * q + C[5])
+            / ((((D[0] * q + D[1]) * q + D[2]) * q + D[3]) * q + 1.0)
+    };
+    if p < P_LOW {
+        tail((-2.0 * p.ln()).sqrt())
+    } else if p > 1.0 - P_LOW {
+        -tail((-2.0 * (1.0 - p).ln()).sqrt())
+    } else {
+        let q = p - 0.5;
+        let r = q * q;
+        (((((A[0] * r + A[1]) * r + A[2]) * r + A[3]) * r + A[4]) * r + A[5]) * q
+            / (((((B[0] * r + B[1]) * r + B[2]) * r + B[3]) * r + B[4]) * r + 1.0)
+    }
+}
+
+#[cfg(feature = "sync_execute")]
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_prob() {
+        let (sess, plc) = test_session();
+
+        let x: HostFloat64Tensor = plc.from_raw(array![[1.0, 3.0], [0.0, 0.0], [2.0, 2.0]]);
+        let y = plc.normalize_prob(&sess, 1, true, &x);
+        assert_close(&y, &[0.25, 0.75, 0.5, 0.5, 0.5, 0.5]);
+        for row in y.0.outer_iter() {
+            assert!((row.sum() - 1.0).abs() < 1e-9);
+        }
+
+        let z = plc.normalize_prob(&sess, 1, false, &x);
+        assert_close(&z, &[0.25, 0.75, 0.0, 0.0, 0.5, 0.5]);
+
+        let w = plc.normalize_prob(&sess, 0, true, &x);
+        assert_close(&w, &[1.0 / 3.0, 0.6, 0.0, 0.0, 2.0 / 3.0, 0.4]);
+    }
+
+    #[test]
+    fn test_normalize_prob_negative() {
+        let (sess, plc) = test_session();
+
+        let x: HostFloat64Tensor = plc.from_raw(array![[1.0, -1.0]]);
+        let res = NormalizeProbOp::host_kernel(&sess, &plc, 1, true, x);
+        assert!(matches!(res, Err(crate::error::Error::KernelError(_))));
+    }
+
+    #[test]
+    fn test_temperature_scale() {
+        let (sess, plc) = test_session();
+
+        let x: HostFloat64Tensor = plc.from_raw(array![[2.0, 1.0, 0.1], [0.5, 3.0, -1.0]]);
+        let sharp = plc.softmax(&sess, 1, 0, &x);
+        assert_eq!(plc.temperature_scale(&sess, 1, 1.0, &x), sharp);
+
+        let soft = plc.temperature_scale(&sess, 1, 2.5, &x);
+        for (s, p) in soft.0.outer_iter().zip(sharp.0.outer_iter()) {
+            assert!((s.sum() - 1.0).abs() < 1e-9);
+            // the most likely class loses mass, the least likely gains it
+            let s_max = s.iter().cloned().fold(f64::MIN, f64::max);
+            let p_max = p.iter().cloned().fold(f64::MIN, f64::max);
+            let s_min = s.iter().cloned().fold(f64::MAX, f64::min);
+            let p_min = p.iter().cloned().fold(f64::MAX, f64::min);
+            assert!(s_max < p_max);
+            assert!(s_min > p_min);
+        }
+    }
+
+    #[test]
+    fn test_temperature_scale_non_positive() {
+        let (sess, plc) = test_session();
+
+        let x: HostFloat64Tensor = plc.from_raw(array![[1.0, 2.0]]);
+        let res = TemperatureScaleOp::host_kernel(&sess, &plc, 1, 0.0, x);
+        assert!(matches!(res, Err(crate::error::Error::KernelError(_))));
+    }
+
+    #[test]
+    fn test_label_smoothing() {
+        let (sess, plc) = test_session();
+
+        let label: HostUint64Tensor = plc.from_raw(array![0, 2, 1]);
+        let y = plc.label_smoothing(&sess, 3, 0.1, &label);
+        assert_eq!(y.0.shape(), &[3, 3]);
+        assert_close(&y, &[0.9, 0.05, 0.05, 0.05, 0.05, 0.9, 0.05, 0.9, 0.05]);
+        for (row, l) in y.0.outer_iter().zip(label.0.iter()) {
+            assert!((row.sum() - 1.0).abs() < 1e-9);
+            assert!((row[[*l as usize]] - 0.9).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_label_smoothing_out_of_range() {
+        let (sess, plc) = test_session();
+
+        let label: HostUint64Tensor = plc.from_raw(array![0, 3]);
+        let res = LabelSmoothingOp::host_kernel(&sess, &plc, 3, 0.1, label);
+        assert!(matches!(res, Err(crate::error::Error::KernelError(_))));
+    }
+
+    #[test]
+    fn test_mixup() {
+        let (sess, plc) = test_session();
+
+        let x_a: HostFloat64Tensor = plc.from_raw(array![[1.0, 2.0], [3.0, 4.0]]);
+        let x_b: HostFloat64Tensor = plc.from_raw(array![[3.0, 0.0], [-1.0, 4.0]]);
+        let y_a: HostFloat64Tensor = plc.from_raw(array![[1.0, 0.0], [0.0, 1.0]]);
+        let y_b: HostFloat64Tensor = plc.from_raw(array![[0.0, 1.0], [0.0, 1.0]]);
+
+        let x = plc.mixup(&sess, 0.5, &x_a, &x_b);
+        assert_close(&x, &[2.0, 1.0, 1.0, 4.0]);
+        let y = plc.mixup(&sess, 0.5, &y_a, &y_b);
+        assert_close(&y, &[0.5, 0.5, 0.0, 1.0]);
+
+        assert_eq!(plc.mixup(&sess, 1.0, &x_a, &x_b), x_a);
+        assert_eq!(plc.mixup(&sess, 0.0, &x_a, &x_b), x_b);
+    }
+
+    #[test]
+    fn test_mixup_shape_mismatch() {
+        let (sess, plc) = test_session();
+
+        let x: HostFloat64Tensor = plc.from_raw(array![[1.0, 2.0]]);
+        let y: HostFloat64Tensor = plc.from_raw(array![1.0, 2.0]);
+        let res = MixupOp::host_kernel(&sess, &plc, 0.5, x, y);
+        assert!(matches!(res, Err(crate::error::Error::KernelError(_))));
+    }
+
+    #[test]
+    fn test_stratified_sample() {
+        let (sess, plc) = test_session();
+
+        let label: HostUint64Tensor = plc.from_raw(array![0, 1, 0, 1, 2, 2, 0, 1, 2, 0]);
+        let seed: HostSeed = plc.from_raw(RawSeed([7u8; 16]));
+        let idx = plc.stratified_sample(&sess, 2, &label, &seed);
+        assert_eq!(idx.0.len(), 6);
+
+        let selected: Vec<usize> = idx.0.iter().map(|i| i.0 as usize).collect();
+        let mut counts = [0; 3];
+        for i in &selected {
+            counts[label.0[[*i]] as usize] += 1;
+        }
+        assert_eq!(counts, [2, 2, 2]);
+        let mut distinct = selected.clone();
+        distinct.dedup();
+        assert_eq!(distinct, selected);
+
+        // same seed, same sample
+        assert_eq!(plc.stratified_sample(&sess, 2, &label, &seed), idx);
+    }
+
+    #[test]
+    fn test_stratified_sample_small_group() {
+        let (sess, plc) = test_session();
+
+        let label: HostUint64Tensor = plc.from_raw(array![0, 1, 0]);
+        let seed: HostSeed = plc.from_raw(RawSeed([0u8; 16]));
+        let res = StratifiedSampleOp::host_kernel(&sess, &plc, 2, label, seed);
+        assert!(matches!(res, Err(crate::error::Error::KernelError(_))));
+    }
+
+    #[test]
+    fn test_bootstrap() {
+        let (sess, plc) = test_session();
+
+        let shape: HostShape = plc.from_raw(RawShape(vec![5, 3]));
+        let seed: HostSeed = plc.from_raw(RawSeed([3u8; 16]));
+        let idx = plc.bootstrap(&sess, 20, &shape, &seed);
+        assert_eq!(idx.0.shape(), &[20]);
+        assert!(idx.0.iter().all(|i| i.0 < 5));
+
+        // same seed, same resample
+        assert_eq!(plc.bootstrap(&sess, 20, &shape, &seed), idx);
+        let other: HostSeed = plc.from_raw(RawSeed([4u8; 16]));
+        assert_ne!(plc.bootstrap(&sess, 20, &shape, &other), idx);
+    }
+
+    #[test]
+    fn test_k_fold() {
+        let (sess, plc) = test_session();
+
+        let shape: HostShape = plc.from_raw(RawShape(vec![10, 2]));
+        let seed: HostSeed = plc.from_raw(RawSeed([1u8; 16]));
+
+        let mut seen = vec![0; 10];
+        for fold in 0..3 {
+            let test = plc.k_fold(&sess, 3, fold, true, &shape, &seed);
+            let train = plc.k_fold(&sess, 3, fold, false, &shape, &seed);
+            assert_eq!(test.0.len() + train.0.len(), 10);
+            for i in test.0.iter() {
+                assert!(!train.0.iter().any(|j| j == i));
+                seen[i.0 as usize] += 1;
+            }
+        }
+        // every row is held out exactly once
+        assert_eq!(seen, vec![1; 10]);
+    }
+
+    #[test]
+    fn test_k_fold_invalid() {
+        let (sess, plc) = test_session();
+
+        let shape: HostShape = plc.from_raw(RawShape(vec![4]));
+        let seed: HostSeed = plc.from_raw(RawSeed([0u8; 16]));
+        let res = KFoldOp::host_kernel(&sess, &plc, 3, 3, true, shape, seed);
+        assert!(matches!(res, Err(crate::error::Error::KernelError(_))));
+    }
+
+    #[test]
+    fn test_rank_norm() {
+        let (sess, plc) = test_session();
+
+        let x: HostFloat64Tensor =
+            plc.from_raw(array![[10.0, 1.0], [30.0, 1.0], [20.0, 5.0], [40.0, 3.0]]);
+        let u = plc.rank_norm(&sess, 0, false, &x);
+        // ties share the average rank
+        assert_close(&u, &[0.125, 0.25, 0.625, 0.25, 0.375, 0.875, 0.875, 0.625]);
+
+        let z = plc.rank_norm(&sess, 0, true, &x);
+        assert!((z.0[[0, 0]] + 1.1503493803760079).abs() < 1e-6);
+        assert!((z.0[[2, 0]] + 0.3186393639643752).abs() < 1e-6);
+        assert!((z.0[[0, 0]] + z.0[[3, 0]]).abs() < 1e-9);
+        assert!((z.0[[1, 0]] + z.0[[2, 0]]).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_rank_norm_distribution() {
+        let (sess, plc) = test_session();
+
+        let n = 1000;
+        let raw: Vec<f64> = (0..n).map(|i| ((i * 7919) % 1009) as f64).collect();
+        let x: HostFloat64Tensor = plc.from_raw(Array::from_vec(raw));
+
+        // normalized ranks are evenly spaced on (0, 1)
+        let u = plc.rank_norm(&sess, 0, false, &x);
+        let mut sorted: Vec<f64> = u.0.iter().cloned().collect();
+        sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        for (i, v) in sorted.iter().enumerate() {
+            assert!((v - (i as f64 + 0.5) / n as f64).abs() < 1e-12);
+        }
+
+        // gaussianized ranks have roughly zero mean and unit variance
+        let z = plc.rank_norm(&sess, 0, true, &x);
+        let mean = z.0.sum() / n as f64;
+        let var = z.0.iter().map(|v| (v - mean) * (v - mean)).sum::<f64>() / n as f64;
+        assert!(mean.abs() < 1e-9);
+        assert!((var - 1.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_poly_features() {
+        let (sess, plc) = test_session();
+
+        let x: HostFloat64Tensor = plc.from_raw(array![[0.0, 1.0], [2.0, 3.0], [4.0, 5.0]]);
+
+        // PolynomialFeatures(degree=2).fit_transform(x)
+        let y = plc.poly_features(&sess, 2, true, &x);
+        let expected: HostFloat64Tensor = plc.from_raw(array![
+            [1.0, 0.0, 1.0, 0.0, 0.0, 1.0],
+            [1.0, 2.0, 3.0, 4.0, 6.0, 9.0],
+            [1.0, 4.0, 5.0, 16.0, 20.0, 25.0]
+        ]);
+        assert_eq!(y, expected);
+
+        // PolynomialFeatures(degree=2, include_bias=False).fit_transform(x)
+        let y = plc.poly_features(&sess, 2, false, &x);
+        let expected: HostFloat64Tensor = plc.from_raw(array![
+            [0.0, 1.0, 0.0, 0.0, 1.0],
+            [2.0, 3.0, 4.0, 6.0, 9.0],
+            [4.0, 5.0, 16.0, 20.0, 25.0]
+        ]);
+        assert_eq!(y, expected);
+
+        // (n + d choose d) columns, including the bias
+        let y = plc.poly_features(&sess, 3, true, &x);
+        assert_eq!(y.0.shape(), &[3, 10]);
+    }
+
+    #[test]
+    fn test_standard_scaler_fit() {
+        let (sess, plc) = test_session();
+
+        let x: HostFloat64Tensor =
+            plc.from_raw(array![[1.0, 10.0], [2.0, 20.0], [3.0, 60.0], [6.0, 10.0]]);
+        let stats = plc.standard_scaler_fit(&sess, 1, &x);
+        assert_eq!(stats.0.shape(), &[2, 2]);
+
+        let mean = plc.mean(&sess, Some(0), &x);
+        let std = plc.std(&sess, Some(0), 1, &x);
+        assert_close(&stats, &[mean.0[[0]], mean.0[[1]], std.0[[0]], std.0[[1]]]);
+        assert_close(
+            &stats,
+            &[3.0, 25.0, (14.0f64 / 3.0).sqrt(), (1700.0f64 / 3.0).sqrt()],
+        );
+    }
+
+    #[test]
+    fn test_min_max_scaler_fit() {
+        let (sess, plc) = test_session();
+
+        let raw_x = array![[1.0, -10.0, 4.0], [7.0, 20.0, 4.0], [-3.0, 5.0, 4.0]];
+        let x: HostFloat64Tensor = plc.from_raw(raw_x.clone());
+        let stats = plc.min_max_scaler_fit(&sess, &x);
+        assert_eq!(stats.0.shape(), &[2, 3]);
+
+        for (j, col) in raw_x.axis_iter(Axis(1)).enumerate() {
+            let min = col.iter().cloned().fold(f64::INFINITY, f64::min);
+            let max = col.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+            assert_eq!(stats.0[[0, j]], min);
+            assert_eq!(stats.0[[1, j]], max);
+        }
+        assert_close(&stats, &[-3.0, -10.0, 4.0, 7.0, 20.0, 4.0]);
+    }
+
+    #[test]
+    fn test_pca_project() {
+        let (sess, plc) = test_session();
+
+        let raw_x = array![[1.0, 2.0, 3.0], [4.0, 0.0, -1.0], [2.0, 2.0, 2.0]];
+        let raw_mean = array![2.0, 1.0, 0.5];
+        let raw_components = array![[0.6, 0.8, 0.0], [0.0, 0.0, 1.0]];
+
+        let x: HostFloat64Tensor = plc.from_raw(raw_x.clone());
+        let mean: HostFloat64Tensor = plc.from_raw(raw_mean.clone());
+        let components: HostFloat64Tensor = plc.from_raw(raw_components.clone());
+        let y = plc.pca_project(&sess, &x, &mean, &components);
+        assert_eq!(y.0.shape(), &[3, 2]);
+
+        let expected = (raw_x - raw_mean).dot(&raw_components.t());
+        assert_close(&y, expected.as_slice().unwrap());
+        assert_close(&y, &[0.2, 2.5, 0.4, -1.5, 0.8, 1.5]);
+    }
+
+    #[test]
+    fn test_pca_project_mismatch() {
+        let (sess, plc) = test_session();
+
+        let x: HostFloat64Tensor = plc.from_raw(array![[1.0, 2.0, 3.0]]);
+        let mean: HostFloat64Tensor = plc.from_raw(array![0.0, 0.0]);
+        let components: HostFloat64Tensor = plc.from_raw(array![[1.0, 0.0, 0.0]]);
+        let res = PcaProjectOp::host_kernel(&sess, &plc, x, mean, components);
+        assert!(matches!(res, Err(crate::error::Error::KernelError(_))));
+    }
+
+    #[test]
+    fn test_whiten() {
+        let (sess, plc) = test_session();
+
+        // data with covariance r^T diag(9, 1) r for a 45 degree rotation r
+        let h = 0.5f64.sqrt();
+        let rotation = array![[h, h], [-h, h]];
+        let z = array![[1.0, 1.0], [1.0, -1.0], [-1.0, 1.0], [-1.0, -1.0]];
+        let raw_x = z.dot(&array![[3.0, 0.0], [0.0, 1.0]]).dot(&rotation);
+
+        let x: HostFloat64Tensor = plc.from_raw(raw_x);
+        let eigenvalues: HostFloat64Tensor = plc.from_raw(array![9.0, 1.0]);
+        let eigenvectors: HostFloat64Tensor = plc.from_raw(rotation);
+
+        for zca in [false, true] {
+            let y = plc.whiten(&sess, zca, &x, &eigenvalues, &eigenvectors);
+            let y = y.0.into_dimensionality::<Ix2>().unwrap();
+            let cov = y.t().dot(&y) / 4.0;
+            for ((i, j), c) in cov.indexed_iter() {
+                let expected = if i == j { 1.0 } else { 0.0 };
+                assert!((c - expected).abs() < 1e-9, "{} != {}", c, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_whiten_non_positive_eigenvalue() {
+        let (sess, plc) = test_session();
+
+        let x: HostFloat64Tensor = plc.from_raw(array![[1.0, 2.0]]);
+        let eigenvalues: HostFloat64Tensor = plc.from_raw(array![1.0, 0.0]);
+        let eigenvectors: HostFloat64Tensor = plc.from_raw(array![[1.0, 0.0], [0.0, 1.0]]);
+        let res = WhitenOp::host_kernel(&sess, &plc, false, x, eigenvalues, eigenvectors);
+        assert!(matches!(res, Err(crate::error::Error::KernelError(_))));
+    }
+}
@@ -109,6 +109,10 @@ modelled_kernel! {
     PlacementNeg::neg, NegOp,
     [
         (HostPlacement, (HostBitTensor) -> HostBitTensor => [runtime] Self::bit_kernel),
+        (HostPlacement, (HostFloat32Tensor) -> HostFloat32Tensor => [runtime] Self::host_kernel),
+        (HostPlacement, (HostFloat64Tensor) -> HostFloat64Tensor => [runtime] Self::host_kernel),
+        (HostPlacement, (Float32Tensor) -> Float32Tensor => [concrete] Self::float_host_kernel),
+        (HostPlacement, (Float64Tensor) -> Float64Tensor => [concrete] Self::float_host_kernel),
         (HostPlacement, (HostRing64Tensor) -> HostRing64Tensor => [runtime] Self::ring_kernel),
         (HostPlacement, (HostRing128Tensor) -> HostRing128Tensor => [runtime] Self::ring_kernel),
         (ReplicatedPlacement, (ReplicatedFixed64Tensor) -> ReplicatedFixed64Tensor => [concrete] Self::repfixed_kernel),
@@ -119,6 +123,25 @@ modelled_kernel! {
     ]
 }
 
+/// Square
+pub trait PlacementSquare<S: Session, T, O> {
+    fn square(&self, sess: &S, x: &T) -> O;
+}
+
+modelled_kernel! {
+    PlacementSquare::square, SquareOp,
+    [
+        (HostPlacement, (HostFloat32Tensor) -> HostFloat32Tensor => [runtime] Self::host_kernel),
+        (HostPlacement, (HostFloat64Tensor) -> HostFloat64Tensor => [runtime] Self::host_kernel),
+        (HostPlacement, (HostInt8Tensor) -> HostInt8Tensor => [runtime] Self::host_kernel),
+        (HostPlacement, (HostInt16Tensor) -> HostInt16Tensor => [runtime] Self::host_kernel),
+        (HostPlacement, (HostInt32Tensor) -> HostInt32Tensor => [runtime] Self::host_kernel),
+        (HostPlacement, (HostInt64Tensor) -> HostInt64Tensor => [runtime] Self::host_kernel),
+        (HostPlacement, (HostRing64Tensor) -> HostRing64Tensor => [runtime] Self::ring_kernel),
+        (HostPlacement, (HostRing128Tensor) -> HostRing128Tensor => [runtime] Self::ring_kernel),
+    ]
+}
+
 /// Multiplication
 pub trait PlacementMul<S: Session, T, U, O> {
     fn mul(&self, sess: &S, x: &T, y: &U) -> O;
@@ -198,6 +221,27 @@ modelled_kernel! {
     ]
 }
 
+/// Remainder
+pub trait PlacementMod<S: Session, T, U, O> {
+    fn modulo(&self, sess: &S, x: &T, y: &U) -> O;
+}
+
+modelled_kernel! {
+    PlacementMod::modulo, ModOp,
+    [
+        (HostPlacement, (HostInt8Tensor, HostInt8Tensor) -> HostInt8Tensor => [runtime] Self::host_kernel),
+        (HostPlacement, (HostInt16Tensor, HostInt16Tensor) -> HostInt16Tensor => [runtime] Self::host_kernel),
+        (HostPlacement, (HostInt32Tensor, HostInt32Tensor) -> HostInt32Tensor => [runtime] Self::host_kernel),
+        (HostPlacement, (HostInt64Tensor, HostInt64Tensor) -> HostInt64Tensor => [runtime] Self::host_kernel),
+        (HostPlacement, (HostUint8Tensor, HostUint8Tensor) -> HostUint8Tensor => [runtime] Self::host_kernel),
+        (HostPlacement, (HostUint16Tensor, HostUint16Tensor) -> HostUint16Tensor => [runtime] Self::host_kernel),
+        (HostPlacement, (HostUint32Tensor, HostUint32Tensor) -> HostUint32Tensor => [runtime] Self::host_kernel),
+        (HostPlacement, (HostUint64Tensor, HostUint64Tensor) -> HostUint64Tensor => [runtime] Self::host_kernel),
+        (HostPlacement, (HostRing64Tensor, HostRing64Tensor) -> HostRing64Tensor => [runtime] Self::ring_kernel),
+        (HostPlacement, (HostRing128Tensor, HostRing128Tensor) -> HostRing128Tensor => [runtime] Self::ring_kernel),
+    ]
+}
+
 /// Dot product
 pub trait PlacementDot<S: Session, T, U, O> {
     fn dot(&self, sess: &S, x: &T, y: &U) -> O;
@@ -257,6 +301,19 @@ modelled_kernel! {
     ]
 }
 
+/// Cyclic bit rotation
+pub trait PlacementRotate<S: Session, T, O> {
+    fn rotate(&self, sess: &S, amount: usize, left: bool, x: &T) -> O;
+}
+
+modelled_kernel! {
+    PlacementRotate::rotate, RotateOp{amount: usize, left: bool},
+    [
+        (HostPlacement, (HostRing64Tensor) -> HostRing64Tensor => [runtime] Self::ring64_kernel),
+        (HostPlacement, (HostRing128Tensor) -> HostRing128Tensor => [runtime] Self::ring128_kernel),
+    ]
+}
+
 /// Square root
 pub trait PlacementSqrt<S: Session, T, O> {
     fn sqrt(&self, sess: &S, x: &T) -> O;
@@ -278,6 +335,58 @@ modelled_kernel! {
     ]
 }
 
+/// Element-wise floor
+pub trait PlacementFloor<S: Session, T, O> {
+    fn floor(&self, sess: &S, x: &T) -> O;
+}
+
+modelled_kernel! {
+    PlacementFloor::floor, FloorOp,
+    [
+        (HostPlacement, (HostFloat32Tensor) -> HostFloat32Tensor => [runtime] Self::host_kernel),
+        (HostPlacement, (HostFloat64Tensor) -> HostFloat64Tensor => [runtime] Self::host_kernel),
+    ]
+}
+
+/// Element-wise ceiling
+pub trait PlacementCeil<S: Session, T, O> {
+    fn ceil(&self, sess: &S, x: &T) -> O;
+}
+
+modelled_kernel! {
+    PlacementCeil::ceil, CeilOp,
+    [
+        (HostPlacement, (HostFloat32Tensor) -> HostFloat32Tensor => [runtime] Self::host_kernel),
+        (HostPlacement, (HostFloat64Tensor) -> HostFloat64Tensor => [runtime] Self::host_kernel),
+    ]
+}
+
+/// Element-wise rounding, with halves rounded away from zero
+pub trait PlacementRound<S: Session, T, O> {
+    fn round(&self, sess: &S, x: &T) -> O;
+}
+
+modelled_kernel! {
+    PlacementRound::round, RoundOp,
+    [
+        (HostPlacement, (HostFloat32Tensor) -> HostFloat32Tensor => [runtime] Self::host_kernel),
+        (HostPlacement, (HostFloat64Tensor) -> HostFloat64Tensor => [runtime] Self::host_kernel),
+    ]
+}
+
+/// Element-wise reciprocal
+pub trait PlacementReciprocal<S: Session, T, O> {
+    fn reciprocal(&self, sess: &S, x: &T) -> O;
+}
+
+modelled_kernel! {
+    PlacementReciprocal::reciprocal, ReciprocalOp,
+    [
+        (HostPlacement, (HostFloat32Tensor) -> HostFloat32Tensor => [runtime] Self::host_kernel),
+        (HostPlacement, (HostFloat64Tensor) -> HostFloat64Tensor => [runtime] Self::host_kernel),
+    ]
+}
+
 /// Variadic addition
 pub trait PlacementAddN<S: Session, T, O> {
     fn add_n(&self, sess: &S, x: &[T]) -> O;
@@ -336,6 +445,47 @@ modelled_kernel! {
     ]
 }
 
+pub trait PlacementProd<S: Session, T, O> {
+    fn prod(&self, sess: &S, axis: Option<usize>, x: &T) -> O;
+}
+
+modelled_kernel! {
+    PlacementProd::prod, ProdOp{axis: Option<usize>},
+    [
+        (HostPlacement, (HostFloat32Tensor) -> HostFloat32Tensor => [runtime] Self::host_float_kernel),
+        (HostPlacement, (HostFloat64Tensor) -> HostFloat64Tensor => [runtime] Self::host_float_kernel),
+        (HostPlacement, (HostRing64Tensor) -> HostRing64Tensor => [runtime] Self::host_ring_kernel),
+        (HostPlacement, (HostRing128Tensor) -> HostRing128Tensor => [runtime] Self::host_ring_kernel),
+    ]
+}
+
+/// Cumulative sum along an axis
+pub trait PlacementCumsum<S: Session, T, O> {
+    fn cumsum(&self, sess: &S, axis: usize, x: &T) -> O;
+}
+
+modelled_kernel! {
+    PlacementCumsum::cumsum, CumsumOp{axis: usize},
+    [
+        (HostPlacement, (HostFloat32Tensor) -> HostFloat32Tensor => [runtime] Self::host_kernel),
+        (HostPlacement, (HostFloat64Tensor) -> HostFloat64Tensor => [runtime] Self::host_kernel),
+        (HostPlacement, (HostRing64Tensor) -> HostRing64Tensor => [runtime] Self::host_ring_kernel),
+        (HostPlacement, (HostRing128Tensor) -> HostRing128Tensor => [runtime] Self::host_ring_kernel),
+    ]
+}
+
+pub trait PlacementCountDistinct<S: Session, T, O> {
+    fn count_distinct(&self, sess: &S, axis: usize, x: &T) -> O;
+}
+
+modelled_kernel! {
+    PlacementCountDistinct::count_distinct, CountDistinctOp{axis: usize},
+    [
+        (HostPlacement, (HostRing64Tensor) -> HostRing64Tensor => [runtime] Self::host_ring_kernel),
+        (HostPlacement, (HostRing128Tensor) -> HostRing64Tensor => [runtime] Self::host_ring_kernel),
+    ]
+}
+
 pub trait PlacementPow2<S: Session, T, O> {
     fn pow2(&self, sess: &S, x: &T) -> O;
 }
@@ -350,6 +500,19 @@ modelled_kernel! {
     ]
 }
 
+/// Element-wise power with a public exponent
+pub trait PlacementPow<S: Session, T, O> {
+    fn pow(&self, sess: &S, exponent: f64, x: &T) -> O;
+}
+
+modelled_kernel! {
+    PlacementPow::pow, PowOp{exponent: f64},
+    [
+        (HostPlacement, (HostFloat32Tensor) -> HostFloat32Tensor => [runtime] Self::host_kernel),
+        (HostPlacement, (HostFloat64Tensor) -> HostFloat64Tensor => [runtime] Self::host_kernel),
+    ]
+}
+
 pub trait PlacementExp<S: Session, T, O> {
     fn exp(&self, sess: &S, x: &T) -> O;
 }
@@ -435,6 +598,58 @@ modelled_kernel! {
     ]
 }
 
+pub trait PlacementVar<S: Session, T, O> {
+    fn var(&self, sess: &S, axis: Option<u32>, ddof: u32, x: &T) -> O;
+}
+
+modelled_kernel! {
+    PlacementVar::var, VarOp{axis: Option<u32>, ddof: u32},
+    [
+        (HostPlacement, (HostFloat32Tensor) -> HostFloat32Tensor => [runtime] Self::host_kernel),
+        (HostPlacement, (HostFloat64Tensor) -> HostFloat64Tensor => [runtime] Self::host_kernel),
+    ]
+}
+
+pub trait PlacementStd<S: Session, T, O> {
+    fn std(&self, sess: &S, axis: Option<u32>, ddof: u32, x: &T) -> O;
+}
+
+modelled_kernel! {
+    PlacementStd::std, StdOp{axis: Option<u32>, ddof: u32},
+    [
+        (HostPlacement, (HostFloat32Tensor) -> HostFloat32Tensor => [runtime] Self::host_kernel),
+        (HostPlacement, (HostFloat64Tensor) -> HostFloat64Tensor => [runtime] Self::host_kernel),
+    ]
+}
+
+/// Moving standard deviation
+pub trait PlacementMovingStd<S: Session, T, O> {
+    fn moving_std(&self, sess: &S, axis: usize, window: usize, ddof: u32, x: &T) -> O;
+}
+
+modelled_kernel! {
+    PlacementMovingStd::moving_std, MovingStdOp{axis: usize, window: usize, ddof: u32},
+    [
+        (HostPlacement, (HostFloat32Tensor) -> HostFloat32Tensor => [runtime] Self::host_kernel),
+        (HostPlacement, (HostFloat64Tensor) -> HostFloat64Tensor => [runtime] Self::host_kernel),
+    ]
+}
+
+/// Element-wise clipping to the interval `[min, max]`
+pub trait PlacementClip<S: Session, T, O> {
+    fn clip(&self, sess: &S, min: f64, max: f64, x: &T) -> O;
+}
+
+modelled_kernel! {
+    PlacementClip::clip, ClipOp{min: f64, max: f64},
+    [
+        (HostPlacement, (Float32Tensor) -> Float32Tensor => [concrete] Self::float_host_kernel),
+        (HostPlacement, (Float64Tensor) -> Float64Tensor => [concrete] Self::float_host_kernel),
+        (HostPlacement, (HostFloat32Tensor) -> HostFloat32Tensor => [runtime] Self::host_kernel),
+        (HostPlacement, (HostFloat64Tensor) -> HostFloat64Tensor => [runtime] Self::host_kernel),
+    ]
+}
+
 pub trait PlacementMeanAsFixedpoint<S: Session, T, O> {
     fn mean_as_fixedpoint(
         &self,
@@ -488,6 +703,20 @@ modelled_kernel! {
     ]
 }
 
+pub trait PlacementMinimum<S: Session, TS, O> {
+    fn minimum(&self, sess: &S, x: &[TS]) -> O;
+}
+
+modelled_kernel! {
+    PlacementMinimum::minimum, MinimumOp,
+    [
+        (HostPlacement, vec[HostFloat32Tensor] -> HostFloat32Tensor => [runtime] Self::host_kernel),
+        (HostPlacement, vec[HostFloat64Tensor] -> HostFloat64Tensor => [runtime] Self::host_kernel),
+        (HostPlacement, vec[HostRing64Tensor] -> HostRing64Tensor => [runtime] Self::host_ring_kernel),
+        (HostPlacement, vec[HostRing128Tensor] -> HostRing128Tensor => [runtime] Self::host_ring_kernel),
+    ]
+}
+
 pub trait PlacementAbs<S: Session, T, O> {
     fn abs(&self, sess: &S, x: &T) -> O;
 }
@@ -504,6 +733,8 @@ modelled_kernel! {
         (HostPlacement, (HostInt16Tensor) -> HostInt16Tensor => [runtime] Self::host_kernel),
         (HostPlacement, (HostInt32Tensor) -> HostInt32Tensor => [runtime] Self::host_kernel),
         (HostPlacement, (HostInt64Tensor) -> HostInt64Tensor => [runtime] Self::host_kernel),
+        (HostPlacement, (HostRing64Tensor) -> HostRing64Tensor => [runtime] Self::ring64_kernel),
+        (HostPlacement, (HostRing128Tensor) -> HostRing128Tensor => [runtime] Self::ring128_kernel),
         (ReplicatedPlacement, (Tensor) -> Tensor => [concrete] Self::rep_logical_kernel),
         (ReplicatedPlacement, (Fixed64Tensor) -> Fixed64Tensor => [concrete] Self::fixed_rep_kernel),
         (ReplicatedPlacement, (Fixed128Tensor) -> Fixed128Tensor => [concrete] Self::fixed_rep_kernel),
@@ -615,6 +846,8 @@ modelled_kernel! {
     [
         (HostPlacement, (HostFixed64Tensor) -> HostUint64Tensor => [hybrid] Self::host_fixed_uint_kernel),
         (HostPlacement, (HostFixed128Tensor) -> HostUint64Tensor => [hybrid] Self::host_fixed_uint_kernel),
+        (HostPlacement, (HostFloat32Tensor) -> HostUint64Tensor => [runtime] Self::host_float_kernel),
+        (HostPlacement, (HostFloat64Tensor) -> HostUint64Tensor => [runtime] Self::host_float_kernel),
         (HostPlacement, (Fixed64Tensor) -> Uint64Tensor => [concrete] Self::fixed_host_kernel),
         (HostPlacement, (Fixed128Tensor) -> Uint64Tensor => [concrete] Self::fixed_host_kernel),
         (HostPlacement, (Tensor) -> Tensor => [concrete] Self::logical_host_kernel),
@@ -9,6 +9,8 @@ modelled_kernel! {
     PlacementXor::xor, XorOp,
     [
         (HostPlacement, (HostBitTensor, HostBitTensor) -> HostBitTensor => [runtime] Self::host_kernel),
+        (HostPlacement, (HostRing64Tensor, HostRing64Tensor) -> HostRing64Tensor => [runtime] Self::host_ring_kernel),
+        (HostPlacement, (HostRing128Tensor, HostRing128Tensor) -> HostRing128Tensor => [runtime] Self::host_ring_kernel),
         (ReplicatedPlacement, (ReplicatedBitTensor, ReplicatedBitTensor) -> ReplicatedBitTensor => [transparent] Self::rep_kernel),
         (ReplicatedPlacement, (Mirrored3BitTensor, ReplicatedBitTensor) -> ReplicatedBitTensor => [transparent] Self::rep_kernel),
         (ReplicatedPlacement, (ReplicatedBitTensor, Mirrored3BitTensor) -> ReplicatedBitTensor => [transparent] Self::rep_kernel),
@@ -77,6 +79,34 @@ modelled_kernel! {
         (HostPlacement, (Tensor, Tensor) -> Tensor => [concrete] Self::logical_host_kernel),
         (HostPlacement, (BooleanTensor, BooleanTensor) -> BooleanTensor => [concrete] Self::bool_kernel),
         (HostPlacement, (HostBitTensor, HostBitTensor) -> HostBitTensor => [runtime] Self::host_kernel),
+        (HostPlacement, (HostRing64Tensor, HostRing64Tensor) -> HostRing64Tensor => [runtime] Self::host_ring_kernel),
+        (HostPlacement, (HostRing128Tensor, HostRing128Tensor) -> HostRing128Tensor => [runtime] Self::host_ring_kernel),
+    ]
+}
+
+/// Bitwise complement
+pub trait PlacementNot<S: Session, T, O> {
+    fn not(&self, sess: &S, x: &T) -> O;
+}
+
+modelled_kernel! {
+    PlacementNot::not, NotOp,
+    [
+        (HostPlacement, (HostBitTensor) -> HostBitTensor => [runtime] Self::host_kernel),
+        (HostPlacement, (HostRing64Tensor) -> HostRing64Tensor => [runtime] Self::host_ring_kernel),
+        (HostPlacement, (HostRing128Tensor) -> HostRing128Tensor => [runtime] Self::host_ring_kernel),
+    ]
+}
+
+pub trait PlacementPopcount<S: Session, T, O> {
+    fn popcount(&self, sess: &S, x: &T) -> O;
+}
+
+modelled_kernel! {
+    PlacementPopcount::popcount, PopcountOp,
+    [
+        (HostPlacement, (HostRing64Tensor) -> HostRing64Tensor => [runtime] Self::ring64_kernel),
+        (HostPlacement, (HostRing128Tensor) -> HostRing64Tensor => [runtime] Self::ring128_kernel),
     ]
 }
 
@@ -134,6 +164,8 @@ modelled_kernel! {
     [
         (ReplicatedPlacement, (ReplicatedBitArray64) -> ReplicatedRing64Tensor => [transparent] Self::rep_kernel),
         (ReplicatedPlacement, (ReplicatedBitArray128) -> ReplicatedRing128Tensor => [transparent] Self::rep_kernel),
+        (HostPlacement, (HostBitTensor) -> HostRing64Tensor => [runtime] Self::ring64_kernel),
+        (HostPlacement, (HostBitTensor) -> HostRing128Tensor => [runtime] Self::ring128_kernel),
     ]
 }
 
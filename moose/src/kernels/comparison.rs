@@ -72,6 +72,21 @@ modelled_kernel! {
     ]
 }
 
+/// Greater than or equal
+pub trait PlacementGreaterEqual<S: Session, T, U, O> {
+    fn greater_equal(&self, sess: &S, x: &T, y: &U) -> O;
+}
+
+modelled_kernel! {
+    PlacementGreaterEqual::greater_equal, GreaterEqualOp,
+    [
+        (HostPlacement, (HostFloat32Tensor, HostFloat32Tensor) -> HostBitTensor => [runtime] Self::host_float_kernel),
+        (HostPlacement, (HostFloat64Tensor, HostFloat64Tensor) -> HostBitTensor => [runtime] Self::host_float_kernel),
+        (HostPlacement, (HostRing64Tensor, HostRing64Tensor) -> HostBitTensor => [runtime] Self::host_ring64_kernel),
+        (HostPlacement, (HostRing128Tensor, HostRing128Tensor) -> HostBitTensor => [runtime] Self::host_ring128_kernel),
+    ]
+}
+
 /// Equality
 pub trait PlacementEqual<S: Session, T, U, O> {
     fn equal(&self, sess: &S, x: &T, y: &U) -> O;
@@ -80,6 +95,10 @@ pub trait PlacementEqual<S: Session, T, U, O> {
 modelled_kernel! {
     PlacementEqual::equal, EqualOp,
     [
+        (HostPlacement, (HostFloat32Tensor, HostFloat32Tensor) -> HostBitTensor => [runtime] Self::host_float_kernel),
+        (HostPlacement, (HostFloat64Tensor, HostFloat64Tensor) -> HostBitTensor => [runtime] Self::host_float_kernel),
+        (HostPlacement, (HostRing64Tensor, HostRing64Tensor) -> HostBitTensor => [runtime] Self::host_ring_kernel),
+        (HostPlacement, (HostRing128Tensor, HostRing128Tensor) -> HostBitTensor => [runtime] Self::host_ring_kernel),
         (ReplicatedPlacement, (ReplicatedRing64Tensor, ReplicatedRing64Tensor) -> ReplicatedBitTensor => [transparent] Self::rep_kernel),
         (ReplicatedPlacement, (ReplicatedRing128Tensor, ReplicatedRing128Tensor) -> ReplicatedBitTensor => [transparent] Self::rep_kernel),
         (ReplicatedPlacement, (ReplicatedRing64Tensor, ReplicatedRing64Tensor) -> ReplicatedRing64Tensor => [transparent] Self::rep_ring_kernel),
@@ -87,6 +106,21 @@ modelled_kernel! {
     ]
 }
 
+/// Inequality
+pub trait PlacementNotEqual<S: Session, T, U, O> {
+    fn not_equal(&self, sess: &S, x: &T, y: &U) -> O;
+}
+
+modelled_kernel! {
+    PlacementNotEqual::not_equal, NotEqualOp,
+    [
+        (HostPlacement, (HostFloat32Tensor, HostFloat32Tensor) -> HostBitTensor => [runtime] Self::host_float_kernel),
+        (HostPlacement, (HostFloat64Tensor, HostFloat64Tensor) -> HostBitTensor => [runtime] Self::host_float_kernel),
+        (HostPlacement, (HostRing64Tensor, HostRing64Tensor) -> HostBitTensor => [runtime] Self::host_ring_kernel),
+        (HostPlacement, (HostRing128Tensor, HostRing128Tensor) -> HostBitTensor => [runtime] Self::host_ring_kernel),
+    ]
+}
+
 pub trait PlacementEqualZero<S: Session, T, O> {
     fn equal_zero(&self, sess: &S, x: &T) -> O;
 }
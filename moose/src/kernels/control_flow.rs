@@ -35,3 +35,18 @@ modelled_kernel! {
         (ReplicatedPlacement, (ReplicatedBitTensor, ReplicatedRing64Tensor, ReplicatedRing64Tensor) -> ReplicatedRing64Tensor => [concrete] Self::rep_bit_selector_kernel),
     ]
 }
+
+/// Element-wise selection
+pub trait PlacementWhere<S: Session, T, U, V, O> {
+    fn where_(&self, sess: &S, s: &T, x: &U, y: &V) -> O;
+}
+
+modelled_kernel! {
+    PlacementWhere::where_, WhereOp,
+    [
+        (HostPlacement, (HostBitTensor, HostFloat32Tensor, HostFloat32Tensor) -> HostFloat32Tensor => [runtime] Self::host_kernel),
+        (HostPlacement, (HostBitTensor, HostFloat64Tensor, HostFloat64Tensor) -> HostFloat64Tensor => [runtime] Self::host_kernel),
+        (HostPlacement, (HostBitTensor, HostRing64Tensor, HostRing64Tensor) -> HostRing64Tensor => [runtime] Self::host_ring_kernel),
+        (HostPlacement, (HostBitTensor, HostRing128Tensor, HostRing128Tensor) -> HostRing128Tensor => [runtime] Self::host_ring_kernel),
+    ]
+}
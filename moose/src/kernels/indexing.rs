@@ -33,6 +33,51 @@ modelled_kernel! {
     ]
 }
 
+/// Reversal of a tensor along an axis
+pub trait PlacementFlip<S: Session, T, O> {
+    fn flip(&self, sess: &S, axis: usize, x: &T) -> O;
+}
+
+modelled_kernel! {
+    PlacementFlip::flip, FlipOp{axis: usize},
+    [
+        (HostPlacement, (HostBitTensor) -> HostBitTensor => [runtime] Self::host_bit_kernel),
+        (HostPlacement, (HostFloat32Tensor) -> HostFloat32Tensor => [runtime] Self::host_float_kernel),
+        (HostPlacement, (HostFloat64Tensor) -> HostFloat64Tensor => [runtime] Self::host_float_kernel),
+        (HostPlacement, (HostRing64Tensor) -> HostRing64Tensor => [runtime] Self::host_ring_kernel),
+        (HostPlacement, (HostRing128Tensor) -> HostRing128Tensor => [runtime] Self::host_ring_kernel),
+    ]
+}
+
+/// Selection of slices along an axis by an index tensor
+pub trait PlacementGather<S: Session, T, U, O> {
+    fn gather(&self, sess: &S, axis: usize, x: &T, indices: &U) -> O;
+}
+
+modelled_kernel! {
+    PlacementGather::gather, GatherOp{axis: usize},
+    [
+        (HostPlacement, (HostFloat32Tensor, HostRing64Tensor) -> HostFloat32Tensor => [runtime] Self::host_float_kernel),
+        (HostPlacement, (HostFloat64Tensor, HostRing64Tensor) -> HostFloat64Tensor => [runtime] Self::host_float_kernel),
+        (HostPlacement, (HostRing64Tensor, HostRing64Tensor) -> HostRing64Tensor => [runtime] Self::host_ring_kernel),
+        (HostPlacement, (HostRing128Tensor, HostRing64Tensor) -> HostRing128Tensor => [runtime] Self::host_ring_kernel),
+    ]
+}
+
+pub trait PlacementLookupTable<S: Session, T, U, O> {
+    fn lookup_table(&self, sess: &S, x: &T, table: &U) -> O;
+}
+
+modelled_kernel! {
+    PlacementLookupTable::lookup_table, LookupTableOp,
+    [
+        (HostPlacement, (HostRing64Tensor, HostFloat32Tensor) -> HostFloat32Tensor => [runtime] Self::host_float_kernel),
+        (HostPlacement, (HostRing64Tensor, HostFloat64Tensor) -> HostFloat64Tensor => [runtime] Self::host_float_kernel),
+        (HostPlacement, (HostRing64Tensor, HostRing64Tensor) -> HostRing64Tensor => [runtime] Self::host_ring_kernel),
+        (HostPlacement, (HostRing64Tensor, HostRing128Tensor) -> HostRing128Tensor => [runtime] Self::host_ring_kernel),
+    ]
+}
+
 pub trait PlacementIndex<S: Session, T, O> {
     fn index(&self, sess: &S, index: usize, x: &T) -> O;
 }
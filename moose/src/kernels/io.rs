@@ -8,6 +8,15 @@ pub trait PlacementReceive<S: Session, O> {
     fn receive(&self, sess: &S, rendezvous_key: RendezvousKey, sender: Role) -> O;
 }
 
+// Networking kernels are implemented directly by the executing sessions
+impl KernelPlacements for SendOp {
+    const KERNEL_PLACEMENTS: &'static [PlacementTy] = &[PlacementTy::Host];
+}
+
+impl KernelPlacements for ReceiveOp {
+    const KERNEL_PLACEMENTS: &'static [PlacementTy] = &[PlacementTy::Host];
+}
+
 pub trait PlacementInput<S: Session, O> {
     fn input(&self, sess: &S, arg_name: String) -> O;
 }
@@ -0,0 +1,205 @@
+use super::*;
+
+/// Cosine similarity
+pub trait PlacementCosineSimilarity<S: Session, T, U, O> {
+    fn cosine_similarity(&self, sess: &S, axis: usize, x: &T, y: &U) -> O;
+}
+
+modelled_kernel! {
+    PlacementCosineSimilarity::cosine_similarity, CosineSimilarityOp{axis: usize},
+    [
+        (HostPlacement, (HostFloat32Tensor, HostFloat32Tensor) -> HostFloat32Tensor => [runtime] Self::host_kernel),
+        (HostPlacement, (HostFloat64Tensor, HostFloat64Tensor) -> HostFloat64Tensor => [runtime] Self::host_kernel),
+    ]
+}
+
+/// Jaccard index
+pub trait PlacementJaccard<S: Session, T, U, O> {
+    fn jaccard(&self, sess: &S, axis: usize, zero_if_empty: bool, x: &T, y: &U) -> O;
+}
+
+modelled_kernel! {
+    PlacementJaccard::jaccard, JaccardOp{axis: usize, zero_if_empty: bool},
+    [
+        (HostPlacement, (HostBitTensor, HostBitTensor) -> HostFloat64Tensor => [runtime] Self::host_kernel),
+    ]
+}
+
+/// Precision and recall of binary predictions
+pub trait PlacementPrecisionRecall<S: Session, T, U, O> {
+    fn precision_recall(&self, sess: &S, prediction: &T, label: &U) -> O;
+}
+
+modelled_kernel! {
+    PlacementPrecisionRecall::precision_recall, PrecisionRecallOp,
+    [
+        (HostPlacement, (HostBitTensor, HostBitTensor) -> HostFloat64Tensor => [runtime] Self::host_kernel),
+    ]
+}
+
+/// F1 score of binary predictions
+pub trait PlacementF1<S: Session, T, U, O> {
+    fn f1(&self, sess: &S, prediction: &T, label: &U) -> O;
+}
+
+modelled_kernel! {
+    PlacementF1::f1, F1Op,
+    [
+        (HostPlacement, (HostBitTensor, HostBitTensor) -> HostFloat64Tensor => [runtime] Self::host_kernel),
+    ]
+}
+
+/// Matthews correlation coefficient of binary predictions
+pub trait PlacementMcc<S: Session, T, U, O> {
+    fn mcc(&self, sess: &S, prediction: &T, label: &U) -> O;
+}
+
+modelled_kernel! {
+    PlacementMcc::mcc, MccOp,
+    [
+        (HostPlacement, (HostBitTensor, HostBitTensor) -> HostFloat64Tensor => [runtime] Self::host_kernel),
+    ]
+}
+
+/// Area under the ROC curve
+pub trait PlacementRocAuc<S: Session, T, U, O> {
+    fn roc_auc(&self, sess: &S, score: &T, label: &U) -> O;
+}
+
+modelled_kernel! {
+    PlacementRocAuc::roc_auc, RocAucOp,
+    [
+        (HostPlacement, (HostFloat32Tensor, HostBitTensor) -> HostFloat64Tensor => [runtime] Self::host_kernel),
+        (HostPlacement, (HostFloat64Tensor, HostBitTensor) -> HostFloat64Tensor => [runtime] Self::host_kernel),
+    ]
+}
+
+/// Brier score of class probabilities
+pub trait PlacementBrierScore<S: Session, T, U, O> {
+    fn brier_score(&self, sess: &S, prob: &T, label: &U) -> O;
+}
+
+modelled_kernel! {
+    PlacementBrierScore::brier_score, BrierScoreOp,
+    [
+        (HostPlacement, (HostFloat32Tensor, HostUint64Tensor) -> HostFloat32Tensor => [runtime] Self::host_kernel),
+        (HostPlacement, (HostFloat64Tensor, HostUint64Tensor) -> HostFloat64Tensor => [runtime] Self::host_kernel),
+    ]
+}
+
+/// Log loss of class probabilities
+pub trait PlacementLogLoss<S: Session, T, U, O> {
+    fn log_loss(&self, sess: &S, eps: f64, prob: &T, label: &U) -> O;
+}
+
+modelled_kernel! {
+    PlacementLogLoss::log_loss, LogLossOp{eps: f64},
+    [
+        (HostPlacement, (HostFloat32Tensor, HostUint64Tensor) -> HostFloat32Tensor => [runtime] Self::host_kernel),
+        (HostPlacement, (HostFloat64Tensor, HostUint64Tensor) -> HostFloat64Tensor => [runtime] Self::host_kernel),
+    ]
+}
+
+/// Calibration curve of binary probabilities
+pub trait PlacementCalibration<S: Session, T, U, O> {
+    fn calibration(&self, sess: &S, bins: usize, prob: &T, label: &U) -> O;
+}
+
+modelled_kernel! {
+    PlacementCalibration::calibration, CalibrationOp{bins: usize},
+    [
+        (HostPlacement, (HostFloat32Tensor, HostBitTensor) -> HostFloat32Tensor => [runtime] Self::host_kernel),
+        (HostPlacement, (HostFloat64Tensor, HostBitTensor) -> HostFloat64Tensor => [runtime] Self::host_kernel),
+    ]
+}
+
+/// Class-weighted cross-entropy of class probabilities
+pub trait PlacementWeightedCrossEntropy<S: Session, T, U, O> {
+    fn weighted_cross_entropy(&self, sess: &S, weights: Vec<f64>, prob: &T, label: &U) -> O;
+}
+
+modelled_kernel! {
+    PlacementWeightedCrossEntropy::weighted_cross_entropy, WeightedCrossEntropyOp{weights: Vec<f64>},
+    [
+        (HostPlacement, (HostFloat32Tensor, HostUint64Tensor) -> HostFloat32Tensor => [runtime] Self::host_kernel),
+        (HostPlacement, (HostFloat64Tensor, HostUint64Tensor) -> HostFloat64Tensor => [runtime] Self::host_kernel),
+    ]
+}
+
+/// Focal loss of class probabilities
+pub trait PlacementFocalLoss<S: Session, T, U, O> {
+    fn focal_loss(&self, sess: &S, gamma: f64, eps: f64, prob: &T, label: &U) -> O;
+}
+
+modelled_kernel! {
+    PlacementFocalLoss::focal_loss, FocalLossOp{gamma: f64, eps: f64},
+    [
+        (HostPlacement, (HostFloat32Tensor, HostUint64Tensor) -> HostFloat32Tensor => [runtime] Self::host_kernel),
+        (HostPlacement, (HostFloat64Tensor, HostUint64Tensor) -> HostFloat64Tensor => [runtime] Self::host_kernel),
+    ]
+}
+
+/// Huber loss
+pub trait PlacementHuberLoss<S: Session, T, U, O> {
+    fn huber_loss(&self, sess: &S, axis: usize, delta: f64, prediction: &T, target: &U) -> O;
+}
+
+modelled_kernel! {
+    PlacementHuberLoss::huber_loss, HuberLossOp{axis: usize, delta: f64},
+    [
+        (HostPlacement, (HostFloat32Tensor, HostFloat32Tensor) -> HostFloat32Tensor => [runtime] Self::host_kernel),
+        (HostPlacement, (HostFloat64Tensor, HostFloat64Tensor) -> HostFloat64Tensor => [runtime] Self::host_kernel),
+    ]
+}
+
+/// Mean squared error
+pub trait PlacementMse<S: Session, T, U, O> {
+    fn mse(&self, sess: &S, axis: Option<usize>, prediction: &T, target: &U) -> O;
+}
+
+modelled_kernel! {
+    PlacementMse::mse, MseOp{axis: Option<usize>},
+    [
+        (HostPlacement, (HostFloat32Tensor, HostFloat32Tensor) -> HostFloat32Tensor => [runtime] Self::host_kernel),
+        (HostPlacement, (HostFloat64Tensor, HostFloat64Tensor) -> HostFloat64Tensor => [runtime] Self::host_kernel),
+    ]
+}
+
+/// Mean absolute error
+pub trait PlacementMae<S: Session, T, U, O> {
+    fn mae(&self, sess: &S, axis: Option<usize>, prediction: &T, target: &U) -> O;
+}
+
+modelled_kernel! {
+    PlacementMae::mae, MaeOp{axis: Option<usize>},
+    [
+        (HostPlacement, (HostFloat32Tensor, HostFloat32Tensor) -> HostFloat32Tensor => [runtime] Self::host_kernel),
+        (HostPlacement, (HostFloat64Tensor, HostFloat64Tensor) -> HostFloat64Tensor => [runtime] Self::host_kernel),
+    ]
+}
+
+/// Coefficient of determination
+pub trait PlacementR2<S: Session, T, U, O> {
+    fn r2(&self, sess: &S, prediction: &T, target: &U) -> O;
+}
+
+modelled_kernel! {
+    PlacementR2::r2, R2Op,
+    [
+        (HostPlacement, (HostFloat32Tensor, HostFloat32Tensor) -> HostFloat32Tensor => [runtime] Self::host_kernel),
+        (HostPlacement, (HostFloat64Tensor, HostFloat64Tensor) -> HostFloat64Tensor => [runtime] Self::host_kernel),
+    ]
+}
+
+/// Explained variance score
+pub trait PlacementExplainedVariance<S: Session, T, U, O> {
+    fn explained_variance(&self, sess: &S, prediction: &T, target: &U) -> O;
+}
+
+modelled_kernel! {
+    PlacementExplainedVariance::explained_variance, ExplainedVarianceOp,
+    [
+        (HostPlacement, (HostFloat32Tensor, HostFloat32Tensor) -> HostFloat32Tensor => [runtime] Self::host_kernel),
+        (HostPlacement, (HostFloat64Tensor, HostFloat64Tensor) -> HostFloat64Tensor => [runtime] Self::host_kernel),
+    ]
+}
@@ -17,6 +17,9 @@ mod control_flow;
 mod conversion;
 mod indexing;
 mod io;
+mod metrics;
+mod models;
+mod preprocessing;
 mod sampling;
 mod shapes;
 
@@ -28,6 +31,9 @@ pub use control_flow::*;
 pub use conversion::*;
 pub use indexing::*;
 pub use io::*;
+pub use metrics::*;
+pub use models::*;
+pub use preprocessing::*;
 pub use sampling::*;
 pub use shapes::*;
 
@@ -35,6 +41,14 @@ pub trait DispatchKernel<S: Session, V> {
     fn compile(&self, plc: &Placement) -> Result<Kernel<S, V>>;
 }
 
+/// Placement types for which an operator has at least one kernel.
+///
+/// Note that having a kernel for a placement does not imply that the operator
+/// supports every signature on that placement.
+pub trait KernelPlacements {
+    const KERNEL_PLACEMENTS: &'static [PlacementTy];
+}
+
 pub enum Kernel<S: Session, V> {
     Nullary { closure: NullaryKernel<S, V> },
     Unary { closure: UnaryKernel<S, V> },
@@ -102,6 +116,9 @@ pub(crate) type TypedUnaryKernel<S, P, X0, Y> = Box<dyn Fn(&S, &P, X0) -> Result
 pub(crate) type TypedBinaryKernel<S, P, X0, X1, Y> =
     Box<dyn Fn(&S, &P, X0, X1) -> Result<Y> + Send + Sync>;
 
+pub(crate) type TypedTernaryKernel<S, P, X0, X1, X2, Y> =
+    Box<dyn Fn(&S, &P, X0, X1, X2) -> Result<Y> + Send + Sync>;
+
 pub(crate) type TypedVariadicKernel<S, P, XS, Y> =
     Box<dyn Fn(&S, &P, &[XS]) -> Result<Y> + Send + Sync>;
 
@@ -200,6 +217,17 @@ modelled_kernel! {
     ]
 }
 
+pub trait PlacementShrDim<S: Session, T, O> {
+    fn shr_dim(&self, sess: &S, amount: usize, ring_size: usize, x: &T) -> O;
+}
+
+modelled_kernel! {
+    PlacementShrDim::shr_dim, ShrDimOp{amount: usize, bit_length: usize},
+    [
+        (HostPlacement, (HostBitTensor) -> HostBitTensor => [runtime] Self::host_bit_kernel),
+    ]
+}
+
 pub trait PlacementSoftmax<S: Session, T, O> {
     fn softmax(&self, sess: &S, axis: usize, upmost_index: usize, x: &T) -> O;
 }
@@ -0,0 +1,287 @@
+use super::*;
+
+/// Euclidean distances between samples and centroids
+pub trait PlacementCentroidDist<S: Session, T, U, O> {
+    fn centroid_dist(&self, sess: &S, x: &T, centroids: &U) -> O;
+}
+
+modelled_kernel! {
+    PlacementCentroidDist::centroid_dist, CentroidDistOp,
+    [
+        (HostPlacement, (HostFloat32Tensor, HostFloat32Tensor) -> HostFloat32Tensor => [runtime] Self::host_kernel),
+        (HostPlacement, (HostFloat64Tensor, HostFloat64Tensor) -> HostFloat64Tensor => [runtime] Self::host_kernel),
+    ]
+}
+
+/// Index of the nearest centroid for every sample
+pub trait PlacementAssignClusters<S: Session, T, U, O> {
+    fn assign_clusters(&self, sess: &S, x: &T, centroids: &U) -> O;
+}
+
+modelled_kernel! {
+    PlacementAssignClusters::assign_clusters, AssignClustersOp,
+    [
+        (HostPlacement, (HostFloat32Tensor, HostFloat32Tensor) -> HostRing64Tensor => [runtime] Self::host_kernel),
+        (HostPlacement, (HostFloat64Tensor, HostFloat64Tensor) -> HostRing64Tensor => [runtime] Self::host_kernel),
+    ]
+}
+
+/// Silhouette scores of a clustering
+pub trait PlacementSilhouette<S: Session, T, U, O> {
+    fn silhouette(&self, sess: &S, average: bool, x: &T, labels: &U) -> O;
+}
+
+modelled_kernel! {
+    PlacementSilhouette::silhouette, SilhouetteOp{average: bool},
+    [
+        (HostPlacement, (HostFloat32Tensor, HostRing64Tensor) -> HostFloat32Tensor => [runtime] Self::host_kernel),
+        (HostPlacement, (HostFloat64Tensor, HostRing64Tensor) -> HostFloat64Tensor => [runtime] Self::host_kernel),
+    ]
+}
+
+/// Indices of the nearest reference rows for every query row
+pub trait PlacementKnn<S: Session, T, U, O> {
+    fn knn(&self, sess: &S, k: usize, x: &T, reference: &U) -> O;
+}
+
+modelled_kernel! {
+    PlacementKnn::knn, KnnOp{k: usize},
+    [
+        (HostPlacement, (HostFloat32Tensor, HostFloat32Tensor) -> HostRing64Tensor => [runtime] Self::host_kernel),
+        (HostPlacement, (HostFloat64Tensor, HostFloat64Tensor) -> HostRing64Tensor => [runtime] Self::host_kernel),
+    ]
+}
+
+/// Majority vote over the labels of nearest neighbours
+pub trait PlacementKnnVote<S: Session, IndT, T, LabelT, O> {
+    fn knn_vote(
+        &self,
+        sess: &S,
+        distance: bool,
+        indices: &IndT,
+        distances: &T,
+        labels: &LabelT,
+    ) -> O;
+}
+
+modelled_kernel! {
+    PlacementKnnVote::knn_vote, KnnVoteOp{distance: bool},
+    [
+        (HostPlacement, (HostRing64Tensor, HostFloat32Tensor, HostRing64Tensor) -> HostRing64Tensor => [runtime] Self::host_kernel),
+        (HostPlacement, (HostRing64Tensor, HostFloat64Tensor, HostRing64Tensor) -> HostRing64Tensor => [runtime] Self::host_kernel),
+    ]
+}
+
+/// Predictions of a linear model
+pub trait PlacementLinearPredict<S: Session, T, W, B, O> {
+    fn linear_predict(&self, sess: &S, x: &T, weights: &W, bias: &B) -> O;
+}
+
+modelled_kernel! {
+    PlacementLinearPredict::linear_predict, LinearPredictOp,
+    [
+        (HostPlacement, (HostFloat32Tensor, HostFloat32Tensor, HostFloat32Tensor) -> HostFloat32Tensor => [runtime] Self::host_kernel),
+        (HostPlacement, (HostFloat64Tensor, HostFloat64Tensor, HostFloat64Tensor) -> HostFloat64Tensor => [runtime] Self::host_kernel),
+    ]
+}
+
+/// Predictions of a logistic regression model
+pub trait PlacementLogisticPredict<S: Session, T, W, B, O> {
+    fn logistic_predict(&self, sess: &S, threshold: bool, x: &T, weights: &W, bias: &B) -> O;
+}
+
+modelled_kernel! {
+    PlacementLogisticPredict::logistic_predict, LogisticPredictOp{threshold: bool},
+    [
+        (HostPlacement, (HostFloat32Tensor, HostFloat32Tensor, HostFloat32Tensor) -> HostFloat32Tensor => [runtime] Self::host_kernel),
+        (HostPlacement, (HostFloat64Tensor, HostFloat64Tensor, HostFloat64Tensor) -> HostFloat64Tensor => [runtime] Self::host_kernel),
+    ]
+}
+
+/// Outputs of a decision tree
+pub trait PlacementTreeApply<S: Session, T, N, P, O> {
+    fn tree_apply(&self, sess: &S, x: &T, nodes: &N, params: &P) -> O;
+}
+
+modelled_kernel! {
+    PlacementTreeApply::tree_apply, TreeApplyOp,
+    [
+        (HostPlacement, (HostFloat32Tensor, HostRing64Tensor, HostFloat32Tensor) -> HostFloat32Tensor => [runtime] Self::host_kernel),
+        (HostPlacement, (HostFloat64Tensor, HostRing64Tensor, HostFloat64Tensor) -> HostFloat64Tensor => [runtime] Self::host_kernel),
+    ]
+}
+
+/// Combined outputs of an ensemble of decision trees
+pub trait PlacementForestApply<S: Session, T, N, P, O> {
+    fn forest_apply(&self, sess: &S, classify: bool, x: &T, nodes: &N, params: &P) -> O;
+}
+
+modelled_kernel! {
+    PlacementForestApply::forest_apply, ForestApplyOp{classify: bool},
+    [
+        (HostPlacement, (HostFloat32Tensor, HostRing64Tensor, HostFloat32Tensor) -> HostFloat32Tensor => [runtime] Self::host_kernel),
+        (HostPlacement, (HostFloat64Tensor, HostRing64Tensor, HostFloat64Tensor) -> HostFloat64Tensor => [runtime] Self::host_kernel),
+    ]
+}
+
+/// Outputs of a gradient boosted tree ensemble
+pub trait PlacementGbdtApply<S: Session, T, N, P, O> {
+    fn gbdt_apply(
+        &self,
+        sess: &S,
+        learning_rate: f64,
+        base_score: f64,
+        x: &T,
+        nodes: &N,
+        params: &P,
+    ) -> O;
+}
+
+modelled_kernel! {
+    PlacementGbdtApply::gbdt_apply, GbdtApplyOp{learning_rate: f64, base_score: f64},
+    [
+        (HostPlacement, (HostFloat32Tensor, HostRing64Tensor, HostFloat32Tensor) -> HostFloat32Tensor => [runtime] Self::host_kernel),
+        (HostPlacement, (HostFloat64Tensor, HostRing64Tensor, HostFloat64Tensor) -> HostFloat64Tensor => [runtime] Self::host_kernel),
+    ]
+}
+
+/// Class predictions of a Gaussian naive Bayes model
+pub trait PlacementGaussianNb<S: Session, T, U, V, O> {
+    fn gaussian_nb(&self, sess: &S, eps: f64, x: &T, stats: &U, priors: &V) -> O;
+}
+
+modelled_kernel! {
+    PlacementGaussianNb::gaussian_nb, GaussianNbOp{eps: f64},
+    [
+        (HostPlacement, (HostFloat32Tensor, HostFloat32Tensor, HostFloat32Tensor) -> HostRing64Tensor => [runtime] Self::host_kernel),
+        (HostPlacement, (HostFloat64Tensor, HostFloat64Tensor, HostFloat64Tensor) -> HostRing64Tensor => [runtime] Self::host_kernel),
+    ]
+}
+
+/// Isolation forest anomaly scores from per-tree path lengths
+pub trait PlacementIsolationScore<S: Session, T, O> {
+    fn isolation_score(&self, sess: &S, sample_size: usize, path_lengths: &T) -> O;
+}
+
+modelled_kernel! {
+    PlacementIsolationScore::isolation_score, IsolationScoreOp{sample_size: usize},
+    [
+        (HostPlacement, (HostFloat32Tensor) -> HostFloat32Tensor => [runtime] Self::host_kernel),
+        (HostPlacement, (HostFloat64Tensor) -> HostFloat64Tensor => [runtime] Self::host_kernel),
+    ]
+}
+
+/// Mahalanobis distances between samples and a mean
+pub trait PlacementMahalanobis<S: Session, T, U, V, O> {
+    fn mahalanobis(&self, sess: &S, x: &T, mean: &U, inv_cov: &V) -> O;
+}
+
+modelled_kernel! {
+    PlacementMahalanobis::mahalanobis, MahalanobisOp,
+    [
+        (HostPlacement, (HostFloat32Tensor, HostFloat32Tensor, HostFloat32Tensor) -> HostFloat32Tensor => [runtime] Self::host_kernel),
+        (HostPlacement, (HostFloat64Tensor, HostFloat64Tensor, HostFloat64Tensor) -> HostFloat64Tensor => [runtime] Self::host_kernel),
+    ]
+}
+
+/// DBSCAN cluster labels from pairwise distances
+pub trait PlacementDbscan<S: Session, T, O> {
+    fn dbscan(&self, sess: &S, eps: f64, min_samples: usize, distances: &T) -> O;
+}
+
+modelled_kernel! {
+    PlacementDbscan::dbscan, DbscanOp{eps: f64, min_samples: usize},
+    [
+        (HostPlacement, (HostFloat32Tensor) -> HostRing64Tensor => [runtime] Self::host_kernel),
+        (HostPlacement, (HostFloat64Tensor) -> HostRing64Tensor => [runtime] Self::host_kernel),
+    ]
+}
+
+/// Closest pair of clusters for a single agglomerative linkage step
+pub trait PlacementClosestPair<S: Session, T, O> {
+    fn closest_pair(&self, sess: &S, distances: &T) -> O;
+}
+
+modelled_kernel! {
+    PlacementClosestPair::closest_pair, ClosestPairOp,
+    [
+        (HostPlacement, (HostFloat32Tensor) -> HostFloat32Tensor => [runtime] Self::host_kernel),
+        (HostPlacement, (HostFloat64Tensor) -> HostFloat64Tensor => [runtime] Self::host_kernel),
+    ]
+}
+
+/// TF-IDF weighting of term counts
+pub trait PlacementTfidf<S: Session, T, U, O> {
+    fn tfidf(&self, sess: &S, num_docs: usize, smooth: bool, counts: &T, doc_freqs: &U) -> O;
+}
+
+modelled_kernel! {
+    PlacementTfidf::tfidf, TfidfOp{num_docs: usize, smooth: bool},
+    [
+        (HostPlacement, (HostFloat32Tensor, HostFloat32Tensor) -> HostFloat32Tensor => [runtime] Self::host_kernel),
+        (HostPlacement, (HostFloat64Tensor, HostFloat64Tensor) -> HostFloat64Tensor => [runtime] Self::host_kernel),
+    ]
+}
+
+/// Hashed n-gram counts of token sequences
+pub trait PlacementNgramHash<S: Session, T, O> {
+    fn ngram_hash(&self, sess: &S, n: usize, num_buckets: usize, tokens: &T) -> O;
+}
+
+modelled_kernel! {
+    PlacementNgramHash::ngram_hash, NgramHashOp{n: usize, num_buckets: usize},
+    [
+        (HostPlacement, (HostRing64Tensor) -> HostRing64Tensor => [runtime] Self::host_kernel),
+    ]
+}
+
+/// Bag-of-words counts of padded token sequences
+pub trait PlacementBagOfWords<S: Session, T, O> {
+    fn bag_of_words(&self, sess: &S, vocab_size: usize, padding: u64, tokens: &T) -> O;
+}
+
+modelled_kernel! {
+    PlacementBagOfWords::bag_of_words, BagOfWordsOp{vocab_size: usize, padding: u64},
+    [
+        (HostPlacement, (HostRing64Tensor) -> HostRing64Tensor => [runtime] Self::host_kernel),
+    ]
+}
+
+/// Dense tensor from coordinates and values of its nonzero entries
+pub trait PlacementSparseToDense<S: Session, T, U, V, O> {
+    fn sparse_to_dense(&self, sess: &S, indices: &T, values: &U, shape: &V) -> O;
+}
+
+modelled_kernel! {
+    PlacementSparseToDense::sparse_to_dense, SparseToDenseOp,
+    [
+        (HostPlacement, (HostRing64Tensor, HostFloat32Tensor, HostShape) -> HostFloat32Tensor => [runtime] Self::host_kernel),
+        (HostPlacement, (HostRing64Tensor, HostFloat64Tensor, HostShape) -> HostFloat64Tensor => [runtime] Self::host_kernel),
+    ]
+}
+
+/// Coordinates of the nonzero entries of a tensor, listed in the same order as their values
+pub trait PlacementDenseToSparseIndices<S: Session, T, O> {
+    fn dense_to_sparse_indices(&self, sess: &S, x: &T) -> O;
+}
+
+modelled_kernel! {
+    PlacementDenseToSparseIndices::dense_to_sparse_indices, DenseToSparseIndicesOp,
+    [
+        (HostPlacement, (HostFloat32Tensor) -> HostRing64Tensor => [runtime] Self::host_kernel),
+        (HostPlacement, (HostFloat64Tensor) -> HostRing64Tensor => [runtime] Self::host_kernel),
+    ]
+}
+
+/// Values of the nonzero entries of a tensor, listed in the same order as their coordinates
+pub trait PlacementDenseToSparseValues<S: Session, T, O> {
+    fn dense_to_sparse_values(&self, sess: &S, x: &T) -> O;
+}
+
+modelled_kernel! {
+    PlacementDenseToSparseValues::dense_to_sparse_values, DenseToSparseValuesOp,
+    [
+        (HostPlacement, (HostFloat32Tensor) -> HostFloat32Tensor => [runtime] Self::host_kernel),
+        (HostPlacement, (HostFloat64Tensor) -> HostFloat64Tensor => [runtime] Self::host_kernel),
+    ]
+}
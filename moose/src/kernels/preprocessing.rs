@@ -0,0 +1,130 @@
+use super::*;
+
+/// Normalize nonnegative scores to probabilities
+pub trait PlacementNormalizeProb<S: Session, T, O> {
+    fn normalize_prob(&self, sess: &S, axis: usize, uniform_if_zero: bool, x: &T) -> O;
+}
+
+modelled_kernel! {
+    PlacementNormalizeProb::normalize_prob, NormalizeProbOp{axis: usize, uniform_if_zero: bool},
+    [
+        (HostPlacement, (HostFloat32Tensor) -> HostFloat32Tensor => [runtime] Self::host_kernel),
+        (HostPlacement, (HostFloat64Tensor) -> HostFloat64Tensor => [runtime] Self::host_kernel),
+    ]
+}
+
+/// Temperature scaling of logits followed by softmax
+pub trait PlacementTemperatureScale<S: Session, T, O> {
+    fn temperature_scale(&self, sess: &S, axis: usize, temperature: f64, x: &T) -> O;
+}
+
+modelled_kernel! {
+    PlacementTemperatureScale::temperature_scale, TemperatureScaleOp{axis: usize, temperature: f64},
+    [
+        (HostPlacement, (HostFloat32Tensor) -> HostFloat32Tensor => [runtime] Self::host_kernel),
+        (HostPlacement, (HostFloat64Tensor) -> HostFloat64Tensor => [runtime] Self::host_kernel),
+    ]
+}
+
+/// Label smoothing of integer class labels
+pub trait PlacementLabelSmoothing<S: Session, T, O> {
+    fn label_smoothing(&self, sess: &S, classes: usize, eps: f64, label: &T) -> O;
+}
+
+modelled_kernel! {
+    PlacementLabelSmoothing::label_smoothing, LabelSmoothingOp{classes: usize, eps: f64},
+    [
+        (HostPlacement, (HostUint64Tensor) -> HostFloat64Tensor => [runtime] Self::host_kernel),
+    ]
+}
+
+/// Mixup interpolation between two batches
+pub trait PlacementMixup<S: Session, T, U, O> {
+    fn mixup(&self, sess: &S, lambda: f64, x: &T, y: &U) -> O;
+}
+
+modelled_kernel! {
+    PlacementMixup::mixup, MixupOp{lambda: f64},
+    [
+        (HostPlacement, (HostFloat32Tensor, HostFloat32Tensor) -> HostFloat32Tensor => [runtime] Self::host_kernel),
+        (HostPlacement, (HostFloat64Tensor, HostFloat64Tensor) -> HostFloat64Tensor => [runtime] Self::host_kernel),
+    ]
+}
+
+/// Rank-based normalization
+pub trait PlacementRankNorm<S: Session, T, O> {
+    fn rank_norm(&self, sess: &S, axis: usize, gaussianize: bool, x: &T) -> O;
+}
+
+modelled_kernel! {
+    PlacementRankNorm::rank_norm, RankNormOp{axis: usize, gaussianize: bool},
+    [
+        (HostPlacement, (HostFloat32Tensor) -> HostFloat32Tensor => [runtime] Self::host_kernel),
+        (HostPlacement, (HostFloat64Tensor) -> HostFloat64Tensor => [runtime] Self::host_kernel),
+    ]
+}
+
+/// Polynomial feature expansion
+pub trait PlacementPolyFeatures<S: Session, T, O> {
+    fn poly_features(&self, sess: &S, degree: usize, include_bias: bool, x: &T) -> O;
+}
+
+modelled_kernel! {
+    PlacementPolyFeatures::poly_features, PolyFeaturesOp{degree: usize, include_bias: bool},
+    [
+        (HostPlacement, (HostFloat32Tensor) -> HostFloat32Tensor => [runtime] Self::host_kernel),
+        (HostPlacement, (HostFloat64Tensor) -> HostFloat64Tensor => [runtime] Self::host_kernel),
+    ]
+}
+
+/// Per-column mean and standard deviation of a feature matrix
+pub trait PlacementStandardScalerFit<S: Session, T, O> {
+    fn standard_scaler_fit(&self, sess: &S, ddof: u32, x: &T) -> O;
+}
+
+modelled_kernel! {
+    PlacementStandardScalerFit::standard_scaler_fit, StandardScalerFitOp{ddof: u32},
+    [
+        (HostPlacement, (HostFloat32Tensor) -> HostFloat32Tensor => [runtime] Self::host_kernel),
+        (HostPlacement, (HostFloat64Tensor) -> HostFloat64Tensor => [runtime] Self::host_kernel),
+    ]
+}
+
+/// Per-column minimum and maximum of a feature matrix
+pub trait PlacementMinMaxScalerFit<S: Session, T, O> {
+    fn min_max_scaler_fit(&self, sess: &S, x: &T) -> O;
+}
+
+modelled_kernel! {
+    PlacementMinMaxScalerFit::min_max_scaler_fit, MinMaxScalerFitOp,
+    [
+        (HostPlacement, (HostFloat32Tensor) -> HostFloat32Tensor => [runtime] Self::host_kernel),
+        (HostPlacement, (HostFloat64Tensor) -> HostFloat64Tensor => [runtime] Self::host_kernel),
+    ]
+}
+
+/// Projection onto principal components
+pub trait PlacementPcaProject<S: Session, T, U, V, O> {
+    fn pca_project(&self, sess: &S, x: &T, mean: &U, components: &V) -> O;
+}
+
+modelled_kernel! {
+    PlacementPcaProject::pca_project, PcaProjectOp,
+    [
+        (HostPlacement, (HostFloat32Tensor, HostFloat32Tensor, HostFloat32Tensor) -> HostFloat32Tensor => [runtime] Self::host_kernel),
+        (HostPlacement, (HostFloat64Tensor, HostFloat64Tensor, HostFloat64Tensor) -> HostFloat64Tensor => [runtime] Self::host_kernel),
+    ]
+}
+
+/// PCA or ZCA whitening
+pub trait PlacementWhiten<S: Session, T, U, V, O> {
+    fn whiten(&self, sess: &S, zca: bool, x: &T, eigenvalues: &U, eigenvectors: &V) -> O;
+}
+
+modelled_kernel! {
+    PlacementWhiten::whiten, WhitenOp{zca: bool},
+    [
+        (HostPlacement, (HostFloat32Tensor, HostFloat32Tensor, HostFloat32Tensor) -> HostFloat32Tensor => [runtime] Self::host_kernel),
+        (HostPlacement, (HostFloat64Tensor, HostFloat64Tensor, HostFloat64Tensor) -> HostFloat64Tensor => [runtime] Self::host_kernel),
+    ]
+}
@@ -89,3 +89,47 @@ where
         self.sample_seeded(sess, Some(1), shape, seed)
     }
 }
+
+/// Stratified sample of row indices
+pub trait PlacementStratifiedSample<S: Session, T, SeedT, O> {
+    fn stratified_sample(&self, sess: &S, count: usize, label: &T, seed: &SeedT) -> O;
+}
+
+modelled_kernel! {
+    PlacementStratifiedSample::stratified_sample, StratifiedSampleOp{count: usize},
+    [
+        (HostPlacement, (HostUint64Tensor, HostSeed) -> HostRing64Tensor => [runtime] Self::host_kernel),
+    ]
+}
+
+/// Bootstrap resample of row indices
+pub trait PlacementBootstrap<S: Session, ShapeT, SeedT, O> {
+    fn bootstrap(&self, sess: &S, size: usize, shape: &ShapeT, seed: &SeedT) -> O;
+}
+
+modelled_kernel! {
+    PlacementBootstrap::bootstrap, BootstrapOp{size: usize},
+    [
+        (HostPlacement, (HostShape, HostSeed) -> HostRing64Tensor => [runtime] Self::host_kernel),
+    ]
+}
+
+/// K-fold cross-validation row indices
+pub trait PlacementKFold<S: Session, ShapeT, SeedT, O> {
+    fn k_fold(
+        &self,
+        sess: &S,
+        k: usize,
+        fold: usize,
+        test: bool,
+        shape: &ShapeT,
+        seed: &SeedT,
+    ) -> O;
+}
+
+modelled_kernel! {
+    PlacementKFold::k_fold, KFoldOp{k: usize, fold: usize, test: bool},
+    [
+        (HostPlacement, (HostShape, HostSeed) -> HostRing64Tensor => [runtime] Self::host_kernel),
+    ]
+}
@@ -154,6 +154,51 @@ modelled_kernel! {
     ]
 }
 
+/// Stacking along a new axis
+pub trait PlacementStack<S: Session, TS, O> {
+    fn stack(&self, sess: &S, axis: usize, xs: &[TS]) -> O;
+}
+
+modelled_kernel! {
+    PlacementStack::stack, StackOp{axis: usize},
+    [
+        (HostPlacement, vec[HostFloat32Tensor] -> HostFloat32Tensor => [runtime] Self::host_kernel),
+        (HostPlacement, vec[HostFloat64Tensor] -> HostFloat64Tensor => [runtime] Self::host_kernel),
+        (HostPlacement, vec[HostRing64Tensor] -> HostRing64Tensor => [runtime] Self::ring_kernel),
+        (HostPlacement, vec[HostRing128Tensor] -> HostRing128Tensor => [runtime] Self::ring_kernel),
+    ]
+}
+
+/// Repetition of a tensor along an axis
+pub trait PlacementTile<S: Session, T, O> {
+    fn tile(&self, sess: &S, axis: usize, reps: usize, x: &T) -> O;
+}
+
+modelled_kernel! {
+    PlacementTile::tile, TileOp{axis: usize, reps: usize},
+    [
+        (HostPlacement, (HostFloat32Tensor) -> HostFloat32Tensor => [runtime] Self::host_kernel),
+        (HostPlacement, (HostFloat64Tensor) -> HostFloat64Tensor => [runtime] Self::host_kernel),
+        (HostPlacement, (HostRing64Tensor) -> HostRing64Tensor => [runtime] Self::ring_kernel),
+        (HostPlacement, (HostRing128Tensor) -> HostRing128Tensor => [runtime] Self::ring_kernel),
+    ]
+}
+
+/// Constant padding of a tensor
+pub trait PlacementPad<S: Session, T, O> {
+    fn pad(&self, sess: &S, pad_width: Vec<usize>, pad_value: f64, x: &T) -> O;
+}
+
+modelled_kernel! {
+    PlacementPad::pad, PadOp{pad_width: Vec<usize>, pad_value: f64},
+    [
+        (HostPlacement, (HostFloat32Tensor) -> HostFloat32Tensor => [runtime] Self::host_kernel),
+        (HostPlacement, (HostFloat64Tensor) -> HostFloat64Tensor => [runtime] Self::host_kernel),
+        (HostPlacement, (HostRing64Tensor) -> HostRing64Tensor => [runtime] Self::ring_kernel),
+        (HostPlacement, (HostRing128Tensor) -> HostRing128Tensor => [runtime] Self::ring_kernel),
+    ]
+}
+
 pub trait PlacementTranspose<S: Session, T, O> {
     fn transpose(&self, sess: &S, x: &T) -> O;
 }
@@ -195,6 +195,31 @@ macro_rules! derive_execute_kernel {
 
     /* Ternary */
 
+    ($plc:ty, ($t0:ty, $t1:ty, $t2:ty) -> $u:ty, attributes[$($attr:ident$(: $prim_ty:ident)?),+] $k:path, $op:ident) => {{
+        $(
+            let $attr = $op.$attr.clone();
+        )+
+        let k: crate::kernels::TypedTernaryKernel<
+            _,
+            $plc,
+            $t0,
+            $t1,
+            $t2,
+            $u,
+        > = Box::new(move |sess, plc, x0, x1, x2| {
+            $k(sess, &plc, $($attr.clone()),+, x0, x1, x2)
+        });
+        crate::execution::kernel_helpers::ternary::<
+            _,
+            $t0,
+            $t1,
+            $t2,
+            $u,
+            $plc,
+            Box<_>,
+        >(k)
+    }};
+
     ($plc:ty, ($t0:ty, $t1:ty, $t2:ty) -> $u:ty, $k:path, $op:ident) => {
         crate::execution::kernel_helpers::ternary::<
             _,
@@ -853,6 +878,10 @@ macro_rules! modelled_kernel {
             }
         }
 
+        impl crate::kernels::KernelPlacements for $op {
+            const KERNEL_PLACEMENTS: &'static [crate::computation::PlacementTy] = &[$(<$plc>::TY,)+];
+        }
+
         $(
             #[cfg(feature = "sync_execute")]
             impl $trait<
@@ -1138,6 +1167,10 @@ macro_rules! modelled_kernel {
             }
         }
 
+        impl crate::kernels::KernelPlacements for $op {
+            const KERNEL_PLACEMENTS: &'static [crate::computation::PlacementTy] = &[$(<$plc>::TY,)+];
+        }
+
         $(
             #[cfg(feature = "sync_execute")]
             impl $trait<
@@ -1443,6 +1476,10 @@ macro_rules! modelled_kernel {
             }
         }
 
+        impl crate::kernels::KernelPlacements for $op {
+            const KERNEL_PLACEMENTS: &'static [crate::computation::PlacementTy] = &[$(<$plc>::TY,)+];
+        }
+
         $(
             #[cfg(feature = "sync_execute")]
             impl $trait<
@@ -1772,6 +1809,10 @@ macro_rules! modelled_kernel {
             }
         }
 
+        impl crate::kernels::KernelPlacements for $op {
+            const KERNEL_PLACEMENTS: &'static [crate::computation::PlacementTy] = &[$(<$plc>::TY,)+];
+        }
+
         $(
             #[cfg(feature = "sync_execute")]
             impl $trait<
@@ -2116,6 +2157,10 @@ macro_rules! modelled_kernel {
             }
         }
 
+        impl crate::kernels::KernelPlacements for $op {
+            const KERNEL_PLACEMENTS: &'static [crate::computation::PlacementTy] = &[$(<$plc>::TY,)+];
+        }
+
         $(
             #[cfg(feature = "sync_execute")]
             impl $trait<
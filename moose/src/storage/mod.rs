@@ -8,6 +8,7 @@ pub mod filesystem;
 pub mod local;
 
 pub trait SyncStorage {
+    /// Store `val` under `key`, overwriting any value previously saved under it.
     fn save(&self, key: &str, session_id: &SessionId, val: &Value) -> Result<()>;
 
     fn load(
@@ -21,6 +22,7 @@ pub trait SyncStorage {
 
 #[async_trait]
 pub trait AsyncStorage {
+    /// Store `val` under `key`, overwriting any value previously saved under it.
     async fn save(&self, key: &str, session_id: &SessionId, val: &Value) -> Result<()>;
 
     async fn load(
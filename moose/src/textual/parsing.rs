@@ -331,6 +331,39 @@ impl<'a, E: 'a + ParseError<&'a str> + ContextError<&'a str>> FromTextual<'a, E>
     }
 }
 
+impl<'a, E: 'a + ParseError<&'a str> + ContextError<&'a str>> FromTextual<'a, E> for PadOp {
+    fn from_textual(input: &'a str) -> IResult<&'a str, Operator, E> {
+        let (input, (pad_width, pad_value)) = delimited(
+            ws(tag("{")),
+            permutation((
+                attributes_member("pad_width", vector(parse_int)),
+                attributes_member("pad_value", double),
+            )),
+            ws(tag("}")),
+        )(input)?;
+        let (input, sig) = operator_signature(1)(input)?;
+        Ok((
+            input,
+            PadOp {
+                sig,
+                pad_width,
+                pad_value,
+            }
+            .into(),
+        ))
+    }
+}
+
+impl<'a, E: 'a + ParseError<&'a str> + ContextError<&'a str>> FromTextual<'a, E>
+    for WeightedCrossEntropyOp
+{
+    fn from_textual(input: &'a str) -> IResult<&'a str, Operator, E> {
+        let (input, weights) = attributes_single("weights", vector(double))(input)?;
+        let (input, sig) = operator_signature(2)(input)?;
+        Ok((input, WeightedCrossEntropyOp { sig, weights }.into()))
+    }
+}
+
 impl<'a, E: 'a + ParseError<&'a str> + ContextError<&'a str>> FromTextual<'a, E> for DeriveSeedOp {
     fn from_textual(input: &'a str) -> IResult<&'a str, Operator, E> {
         let (input, sync_key) = attributes_single(
@@ -1252,6 +1285,84 @@ impl ToTextual for Operator {
             Mirror(op) => op.to_textual(),
             Maximum(op) => op.to_textual(),
             Argmax(op) => op.to_textual(),
+            CosineSimilarity(op) => op.to_textual(),
+            Minimum(op) => op.to_textual(),
+            Prod(op) => op.to_textual(),
+            LookupTable(op) => op.to_textual(),
+            CountDistinct(op) => op.to_textual(),
+            Var(op) => op.to_textual(),
+            Std(op) => op.to_textual(),
+            Jaccard(op) => op.to_textual(),
+            Clip(op) => op.to_textual(),
+            PrecisionRecall(op) => op.to_textual(),
+            Pow(op) => op.to_textual(),
+            F1(op) => op.to_textual(),
+            Ceil(op) => op.to_textual(),
+            Floor(op) => op.to_textual(),
+            Round(op) => op.to_textual(),
+            Mcc(op) => op.to_textual(),
+            Reciprocal(op) => op.to_textual(),
+            RocAuc(op) => op.to_textual(),
+            Cumsum(op) => op.to_textual(),
+            BrierScore(op) => op.to_textual(),
+            Tile(op) => op.to_textual(),
+            LogLoss(op) => op.to_textual(),
+            Flip(op) => op.to_textual(),
+            Calibration(op) => op.to_textual(),
+            Pad(op) => op.to_textual(),
+            WeightedCrossEntropy(op) => op.to_textual(),
+            Gather(op) => op.to_textual(),
+            FocalLoss(op) => op.to_textual(),
+            Where(op) => op.to_textual(),
+            HuberLoss(op) => op.to_textual(),
+            GreaterEqual(op) => op.to_textual(),
+            NotEqual(op) => op.to_textual(),
+            Mse(op) => op.to_textual(),
+            Mae(op) => op.to_textual(),
+            Stack(op) => op.to_textual(),
+            R2(op) => op.to_textual(),
+            ExplainedVariance(op) => op.to_textual(),
+            Not(op) => op.to_textual(),
+            NormalizeProb(op) => op.to_textual(),
+            Rotate(op) => op.to_textual(),
+            TemperatureScale(op) => op.to_textual(),
+            LabelSmoothing(op) => op.to_textual(),
+            Mixup(op) => op.to_textual(),
+            ShrDim(op) => op.to_textual(),
+            StratifiedSample(op) => op.to_textual(),
+            Bootstrap(op) => op.to_textual(),
+            Popcount(op) => op.to_textual(),
+            KFold(op) => op.to_textual(),
+            MovingStd(op) => op.to_textual(),
+            RankNorm(op) => op.to_textual(),
+            PolyFeatures(op) => op.to_textual(),
+            StandardScalerFit(op) => op.to_textual(),
+            MinMaxScalerFit(op) => op.to_textual(),
+            PcaProject(op) => op.to_textual(),
+            Whiten(op) => op.to_textual(),
+            CentroidDist(op) => op.to_textual(),
+            AssignClusters(op) => op.to_textual(),
+            Silhouette(op) => op.to_textual(),
+            Knn(op) => op.to_textual(),
+            KnnVote(op) => op.to_textual(),
+            LinearPredict(op) => op.to_textual(),
+            LogisticPredict(op) => op.to_textual(),
+            TreeApply(op) => op.to_textual(),
+            Square(op) => op.to_textual(),
+            ForestApply(op) => op.to_textual(),
+            Mod(op) => op.to_textual(),
+            GbdtApply(op) => op.to_textual(),
+            GaussianNb(op) => op.to_textual(),
+            IsolationScore(op) => op.to_textual(),
+            Mahalanobis(op) => op.to_textual(),
+            Dbscan(op) => op.to_textual(),
+            ClosestPair(op) => op.to_textual(),
+            Tfidf(op) => op.to_textual(),
+            NgramHash(op) => op.to_textual(),
+            BagOfWords(op) => op.to_textual(),
+            SparseToDense(op) => op.to_textual(),
+            DenseToSparseIndices(op) => op.to_textual(),
+            DenseToSparseValues(op) => op.to_textual(),
         }
     }
 }
@@ -1280,6 +1391,9 @@ macro_rules! op_with_axis_to_textual {
 
 op_with_axis_to_textual!(MeanOp);
 op_with_axis_to_textual!(SumOp);
+op_with_axis_to_textual!(ProdOp);
+op_with_axis_to_textual!(MseOp);
+op_with_axis_to_textual!(MaeOp);
 op_with_axis_to_textual!(SqueezeOp);
 
 impl ToTextual for RingFixedpointMeanOp {
@@ -1314,6 +1428,45 @@ impl ToTextual for RingFixedpointMeanOp {
     }
 }
 
+macro_rules! op_with_axis_and_ddof_to_textual {
+    ($op:tt) => {
+        impl ToTextual for $op {
+            fn to_textual(&self) -> String {
+                match self {
+                    $op {
+                        sig,
+                        axis: Some(a),
+                        ddof,
+                    } => {
+                        format!(
+                            "{}{{axis = {}, ddof = {}}}: {}",
+                            self.short_name(),
+                            a,
+                            ddof,
+                            sig.to_textual()
+                        )
+                    }
+                    $op {
+                        sig,
+                        axis: None,
+                        ddof,
+                    } => {
+                        format!(
+                            "{}{{ddof = {}}}: {}",
+                            self.short_name(),
+                            ddof,
+                            sig.to_textual()
+                        )
+                    }
+                }
+            }
+        }
+    };
+}
+
+op_with_axis_and_ddof_to_textual!(VarOp);
+op_with_axis_and_ddof_to_textual!(StdOp);
+
 impl ToTextual for SampleOp {
     fn to_textual(&self) -> String {
         match self {
@@ -1649,6 +1802,8 @@ use_debug_to_textual!(Vec<u32>);
 use_debug_to_textual!(Vec<usize>);
 use_debug_to_textual!(u64);
 use_debug_to_textual!(bool);
+use_debug_to_textual!(f64);
+use_debug_to_textual!(Vec<f64>);
 use_debug_to_textual!(RawShape);
 
 impl ToTextual for SliceInfo {
@@ -2058,6 +2213,38 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_clip() -> Result<(), anyhow::Error> {
+        let input = "y = Clip{min = -0.5, max = 2.0}: (HostFloat64Tensor) -> HostFloat64Tensor (x) @Host(alice)";
+        let (_, op) = parse_assignment::<(&str, ErrorKind)>(input)?;
+        assert_eq!(
+            op.kind,
+            Operator::Clip(ClipOp {
+                sig: Signature::unary(Ty::HostFloat64Tensor, Ty::HostFloat64Tensor),
+                min: -0.5,
+                max: 2.0,
+            })
+        );
+        assert_eq!(op.to_textual(), input);
+        Ok(())
+    }
+
+    #[test]
+    fn test_pad() -> Result<(), anyhow::Error> {
+        let input = "y = Pad{pad_width = [1, 0, 0, 2], pad_value = 0.5}: (HostFloat64Tensor) -> HostFloat64Tensor (x) @Host(alice)";
+        let (_, op) = parse_assignment::<(&str, ErrorKind)>(input)?;
+        assert_eq!(
+            op.kind,
+            Operator::Pad(PadOp {
+                sig: Signature::unary(Ty::HostFloat64Tensor, Ty::HostFloat64Tensor),
+                pad_width: vec![1, 0, 0, 2],
+                pad_value: 0.5,
+            })
+        );
+        assert_eq!(op.to_textual(), input);
+        Ok(())
+    }
+
     #[test]
     fn test_underscore() -> Result<(), anyhow::Error> {
         let (_, op) = parse_assignment::<(&str, ErrorKind)>(
@@ -6,6 +6,7 @@ use moose::prelude::*;
 use moose::storage::local::LocalAsyncStorage;
 use moose::tokio;
 use std::sync::Arc;
+use std::time::Duration;
 use structopt::StructOpt;
 use tonic::transport::Server;
 
@@ -27,6 +28,14 @@ struct Opt {
     /// Expected identity of choreographer; `certs` must be specified
     choreographer: Option<String>,
 
+    #[structopt(env, long)]
+    /// Number of seconds after which unfinished sessions are aborted
+    launch_timeout: Option<u64>,
+
+    #[structopt(env, long)]
+    /// Number of seconds for which results of completed sessions are kept
+    result_ttl: Option<u64>,
+
     #[structopt(long)]
     /// Report telemetry to Jaeger
     telemetry: bool,
@@ -56,12 +65,18 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     };
 
     let networking_server = networking.new_server();
-    let choreography = GrpcChoreography::new(
+    let mut choreography = GrpcChoreography::new(
         own_identity,
         opt.choreographer,
         Box::new(move |session_id| networking.new_session(session_id)),
         Box::new(|| Arc::new(LocalAsyncStorage::default())),
     );
+    if let Some(seconds) = opt.launch_timeout {
+        choreography = choreography.with_launch_timeout(Duration::from_secs(seconds));
+    }
+    if let Some(seconds) = opt.result_ttl {
+        choreography = choreography.with_result_ttl(Duration::from_secs(seconds));
+    }
 
     let mut server = Server::builder();
 